@@ -259,7 +259,11 @@ async fn data_analysis_example() -> Result<()> {
 
     for event in &events {
         if let Some(code) = &event.event_action.event_code {
-            *event_codes.entry(format!("{:?}", code)).or_insert(0) += 1;
+            let label = match code.parsed() {
+                Some(description) => format!("{:?}", description),
+                None => format!("Unmapped code {}", code.raw()),
+            };
+            *event_codes.entry(label).or_insert(0) += 1;
         }
     }
 
@@ -276,10 +280,14 @@ async fn data_analysis_example() -> Result<()> {
     let mut countries = HashMap::new();
 
     for event in &events {
-        if let Some(geography) = &event.action_geography {
-            if let Some(country) = &geography.country_code {
-                *countries.entry(format!("{:?}", country)).or_insert(0) += 1;
-            }
+        if let Some(geography) = &event.action_geography
+            && let Some(country) = &geography.country_code
+        {
+            let label = match country.parsed() {
+                Some(zone) => format!("{:?}", zone),
+                None => format!("Unmapped code {}", country.raw()),
+            };
+            *countries.entry(label).or_insert(0) += 1;
         }
     }
 
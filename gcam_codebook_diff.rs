@@ -0,0 +1,78 @@
+//! GCAM Codebook Diff and Validation Tool
+//!
+//! Validates GCAM master codebook files and reports what changed between two of them:
+//! added, removed and changed variables, dictionaries, languages and dimension names.
+//! Exits with a non-zero status if any file breaks a structural rule, or, with
+//! `--strict`, if any file has warnings.
+
+use anyhow::Result;
+use models::gcam::{CodebookDiff, GCAMCodebookDatabase, Severity, validate_codebook_file};
+use std::env;
+use std::path::Path;
+
+fn main() -> Result<()> {
+    println!("=== GCAM Codebook Diff ===\n");
+
+    // Get command line arguments
+    let args: Vec<String> = env::args().collect();
+    let strict = args.iter().any(|arg| arg == "--strict");
+    let paths: Vec<&String> = args[1..].iter().filter(|arg| *arg != "--strict").collect();
+
+    if paths.is_empty() || paths.len() > 2 {
+        eprintln!("Usage: {} [--strict] <codebook> [new_codebook]", args[0]);
+        eprintln!("  codebook:      Codebook file to validate (or the old side of a diff)");
+        eprintln!("  new_codebook:  Codebook file to validate and compare against the first");
+        eprintln!("  --strict:      Treat warnings as failures");
+        std::process::exit(2);
+    }
+
+    let mut errors = 0;
+    let mut warnings = 0;
+    for path in &paths {
+        println!("🔍 Validating {}", path);
+        if !Path::new(path.as_str()).exists() {
+            eprintln!("❌ ERROR: Codebook file '{}' not found!", path);
+            std::process::exit(2);
+        }
+
+        let violations = validate_codebook_file(path.as_str())?;
+        for violation in &violations {
+            match violation.severity() {
+                Severity::Error => {
+                    errors += 1;
+                    println!("  ❌ {}", violation);
+                }
+                Severity::Warning => {
+                    warnings += 1;
+                    println!("  ⚠️  {}", violation);
+                }
+            }
+        }
+        if violations.is_empty() {
+            println!("  ✅ No violations");
+        }
+        println!();
+    }
+
+    if let [old_path, new_path] = paths[..] {
+        let old = GCAMCodebookDatabase::from_path(old_path)?;
+        let new = GCAMCodebookDatabase::from_path(new_path)?;
+        println!(
+            "📊 Comparing {} ({}) -> {} ({})\n",
+            old_path,
+            old.version().short(),
+            new_path,
+            new.version().short()
+        );
+        print!("{}", CodebookDiff::between(&old, &new)?);
+        println!();
+    }
+
+    println!("Errors: {}, warnings: {}", errors, warnings);
+    if errors > 0 || (strict && warnings > 0) {
+        println!("❌ Validation failed");
+        std::process::exit(1);
+    }
+    println!("✅ Validation passed");
+    Ok(())
+}
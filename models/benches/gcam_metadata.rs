@@ -0,0 +1,108 @@
+//! Memory cost of GCAM enrichment
+//!
+//! Enriches a synthetic V2GCAM field holding every codebook variable, the way a long
+//! GKG record looks, and counts the bytes allocated. The "copied" column is what the
+//! same entries cost when each one carries its own copy of the codebook entry, as
+//! enrichment used to do.
+//!
+//! ```bash
+//! cargo bench --bench gcam_metadata
+//! ```
+
+use models::gcam::{EnrichedGCAMEntry, GCAMCodebookDatabase, GCAMCodebookEntry};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+struct CountingAllocator;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Bytes allocated while running `f`, with its result
+fn measure<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATED.load(Ordering::Relaxed);
+    let result = f();
+    (result, ALLOCATED.load(Ordering::Relaxed) - before)
+}
+
+fn main() -> anyhow::Result<()> {
+    const RECORDS: usize = 20;
+
+    let db = GCAMCodebookDatabase::new_temp()?;
+    let gcam: Vec<(String, f32)> = db
+        .list_variables(usize::MAX)?
+        .into_iter()
+        .enumerate()
+        .map(|(i, key)| (key, i as f32))
+        .collect();
+
+    let start = Instant::now();
+    let (shared, shared_bytes) = measure(|| {
+        (0..RECORDS)
+            .map(|_| db.enrich_gcam_entries(gcam.clone()))
+            .collect::<anyhow::Result<Vec<Vec<EnrichedGCAMEntry>>>>()
+    });
+    let shared = shared?;
+    let shared_time = start.elapsed();
+
+    let start = Instant::now();
+    let (copied, copied_bytes) = measure(|| {
+        shared
+            .iter()
+            .map(|record| {
+                record
+                    .iter()
+                    .map(|entry| {
+                        (
+                            entry.key.clone(),
+                            entry.value,
+                            entry.metadata.as_deref().cloned(),
+                        )
+                    })
+                    .collect::<Vec<(String, f32, Option<GCAMCodebookEntry>)>>()
+            })
+            .collect::<Vec<_>>()
+    });
+    let copied_time = start.elapsed();
+
+    let values = RECORDS * gcam.len();
+    println!(
+        "{} records x {} GCAM values ({} enriched entries)",
+        RECORDS,
+        gcam.len(),
+        values
+    );
+    println!(
+        "shared metadata: {:>12} bytes ({:>6} per value) in {:?}",
+        shared_bytes,
+        shared_bytes / values,
+        shared_time
+    );
+    println!(
+        "copied metadata: {:>12} bytes ({:>6} per value) in {:?}",
+        copied_bytes,
+        copied_bytes / values,
+        copied_time
+    );
+    println!(
+        "reduction:       {:.1}x",
+        copied_bytes as f64 / shared_bytes.max(1) as f64
+    );
+
+    drop(copied);
+    Ok(())
+}
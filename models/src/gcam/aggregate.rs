@@ -0,0 +1,543 @@
+//! GCAM aggregation across documents
+//!
+//! `GCAMAggregate` folds a stream of GKG records into word-count-weighted GCAM
+//! averages per group, e.g. LIWC anxiety for coverage of Nigeria by day:
+//!
+//! ```rust,no_run
+//! use models::gcam::{GCAMAggregate, GroupBy, TimeBucket};
+//! # use models::types::gkg_table::GKGTable;
+//!
+//! # fn run(records: Vec<GKGTable>) -> anyhow::Result<()> {
+//! let mut aggregate =
+//!     GCAMAggregate::new(vec![GroupBy::Time(TimeBucket::Day), GroupBy::LocationCountry])
+//!         .with_dimensions([(9, 45)]);
+//! aggregate.extend_records(&records);
+//! aggregate.write_csv(std::io::stdout())?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! GCAM only lists the dimensions a document matched, so a group's density for a
+//! dimension is its total matches over the word count of every document in the group,
+//! which is the word-count-weighted mean of the per-document densities. Scores are
+//! averaged over the documents that have them, weighted by their word counts.
+
+use crate::gcam::analysis::DimensionMeasurement;
+use crate::gcam::lookup::GCAMCodebookEntry;
+use crate::types::gkg_table::GKGTable;
+use crate::types::lookup_types::country::CountryZone;
+use anyhow::Result;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::io::Write;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeBucket {
+    Day,
+    Hour,
+}
+
+/// A key to group documents by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GroupBy {
+    /// Publication date, truncated to the bucket
+    Time(TimeBucket),
+    /// FIPS code of the outlet's country, from `source_common_name`
+    SourceCountry,
+    /// FIPS code of the country of the record's primary location
+    LocationCountry,
+    /// Every V2 enhanced theme of the record; a record counts once per theme
+    Theme,
+}
+
+impl GroupBy {
+    /// Column name in exported tables
+    pub fn column(&self) -> &'static str {
+        match self {
+            GroupBy::Time(TimeBucket::Day) => "day",
+            GroupBy::Time(TimeBucket::Hour) => "hour",
+            GroupBy::SourceCountry => "source_country",
+            GroupBy::LocationCountry => "location_country",
+            GroupBy::Theme => "theme",
+        }
+    }
+}
+
+impl fmt::Display for GroupBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.column())
+    }
+}
+
+/// Sums for one dimension within one group
+#[derive(Debug, Clone, Default)]
+struct DimensionTotals {
+    documents: u64,
+    matches: f64,
+    score_sum: f64,
+    score_weight: f64,
+    metadata: Option<Arc<GCAMCodebookEntry>>,
+}
+
+/// Sums for one group
+#[derive(Debug, Clone, Default)]
+struct GroupTotals {
+    documents: u64,
+    word_count: f64,
+    dimensions: BTreeMap<(u32, u32), DimensionTotals>,
+}
+
+/// One row of the tidy output: a group, a dimension and its averages
+#[derive(Debug, Clone, PartialEq)]
+pub struct GCAMAggregateRow {
+    /// Values of the grouping keys, in the order they were configured; empty when a
+    /// record had no value for that key
+    pub group: Vec<String>,
+    pub dictionary_id: u32,
+    pub dimension_id: u32,
+    pub dictionary: Option<String>,
+    pub dimension_name: Option<String>,
+    /// Documents in the group
+    pub documents: u64,
+    /// Documents in the group that measured this dimension
+    pub matched_documents: u64,
+    /// Total word count of the group
+    pub word_count: f64,
+    /// Word-count-weighted match density
+    pub density: Option<f64>,
+    /// Word-count-weighted average score
+    pub score: Option<f64>,
+}
+
+/// Word-count-weighted GCAM averages grouped by time, country and theme
+#[derive(Debug, Clone)]
+pub struct GCAMAggregate {
+    group_by: Vec<GroupBy>,
+    dimensions: Option<BTreeSet<(u32, u32)>>,
+    source_countries: HashMap<String, CountryZone>,
+    groups: BTreeMap<Vec<String>, GroupTotals>,
+}
+
+impl GCAMAggregate {
+    pub fn new(group_by: Vec<GroupBy>) -> Self {
+        Self {
+            group_by,
+            dimensions: None,
+            source_countries: HashMap::new(),
+            groups: BTreeMap::new(),
+        }
+    }
+
+    /// Only aggregate these `(dictionary_id, dimension_id)` pairs
+    pub fn with_dimensions(mut self, dimensions: impl IntoIterator<Item = (u32, u32)>) -> Self {
+        self.dimensions = Some(dimensions.into_iter().collect());
+        self
+    }
+
+    /// Country for an outlet whose domain does not give one away, e.g. `punchng.com`
+    pub fn with_source_country(mut self, source: impl Into<String>, country: CountryZone) -> Self {
+        self.source_countries
+            .insert(source.into().to_lowercase(), country);
+        self
+    }
+
+    pub fn group_by(&self) -> &[GroupBy] {
+        &self.group_by
+    }
+
+    pub fn from_records<'a>(
+        group_by: Vec<GroupBy>,
+        records: impl IntoIterator<Item = &'a GKGTable>,
+    ) -> Self {
+        let mut aggregate = Self::new(group_by);
+        aggregate.extend_records(records);
+        aggregate
+    }
+
+    pub fn extend_records<'a>(&mut self, records: impl IntoIterator<Item = &'a GKGTable>) {
+        for record in records {
+            self.add_record(record);
+        }
+    }
+
+    pub fn add_record(&mut self, record: &GKGTable) {
+        let analysis = record.gcam_analysis();
+        let word_count = analysis
+            .word_count
+            .map(f64::from)
+            .filter(|&wc| wc > 0.0)
+            .unwrap_or(record.tone.word_count as f64);
+
+        let measured: Vec<&DimensionMeasurement> = analysis
+            .dimensions()
+            .filter(|dimension| {
+                self.dimensions.as_ref().is_none_or(|wanted| {
+                    wanted.contains(&(dimension.dictionary_id, dimension.dimension_id))
+                })
+            })
+            .collect();
+
+        for group in self.groups_of(record) {
+            let totals = self.groups.entry(group).or_default();
+            totals.documents += 1;
+            totals.word_count += word_count;
+
+            for dimension in &measured {
+                let dimension_totals = totals
+                    .dimensions
+                    .entry((dimension.dictionary_id, dimension.dimension_id))
+                    .or_default();
+                dimension_totals.documents += 1;
+                if let Some(count) = dimension.match_count {
+                    dimension_totals.matches += count as f64;
+                }
+                if let Some(score) = dimension.score {
+                    dimension_totals.score_sum += score as f64 * word_count;
+                    dimension_totals.score_weight += word_count;
+                }
+                if dimension_totals.metadata.is_none() {
+                    dimension_totals.metadata = dimension.metadata.clone();
+                }
+            }
+        }
+    }
+
+    /// Every combination of key values the record belongs to
+    fn groups_of(&self, record: &GKGTable) -> Vec<Vec<String>> {
+        let mut groups: Vec<Vec<String>> = vec![Vec::with_capacity(self.group_by.len())];
+        for key in &self.group_by {
+            let values = self.key_values(*key, record);
+            groups = groups
+                .into_iter()
+                .flat_map(|group| {
+                    values.iter().map(move |value| {
+                        let mut group = group.clone();
+                        group.push(value.clone());
+                        group
+                    })
+                })
+                .collect();
+        }
+        groups
+    }
+
+    fn key_values(&self, key: GroupBy, record: &GKGTable) -> Vec<String> {
+        match key {
+            GroupBy::Time(TimeBucket::Day) => vec![record.date.format("%Y-%m-%d").to_string()],
+            GroupBy::Time(TimeBucket::Hour) => {
+                vec![record.date.format("%Y-%m-%dT%H:00Z").to_string()]
+            }
+            GroupBy::SourceCountry => {
+                let country = self
+                    .source_countries
+                    .get(&record.source_common_name.to_lowercase())
+                    .copied()
+                    .or_else(|| record.source_country());
+                vec![country_label(country)]
+            }
+            GroupBy::LocationCountry => vec![
+                record
+                    .primary_location()
+                    .and_then(|location| location.country_code.as_ref())
+                    .map(|code| code.raw().to_string())
+                    .unwrap_or_default(),
+            ],
+            GroupBy::Theme => {
+                let themes: BTreeSet<&str> = if record.v2_enhanced_themes.is_empty() {
+                    record.v1_themes.iter().map(String::as_str).collect()
+                } else {
+                    record
+                        .v2_enhanced_themes
+                        .iter()
+                        .map(|theme| theme.name.as_str())
+                        .collect()
+                };
+                if themes.is_empty() {
+                    vec![String::new()]
+                } else {
+                    themes.into_iter().map(str::to_string).collect()
+                }
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// Number of documents counted in a group
+    pub fn documents(&self, group: &[&str]) -> u64 {
+        self.group(group).map_or(0, |totals| totals.documents)
+    }
+
+    fn group(&self, group: &[&str]) -> Option<&GroupTotals> {
+        let key: Vec<String> = group.iter().map(|value| value.to_string()).collect();
+        self.groups.get(&key)
+    }
+
+    /// Weighted density of one dimension in one group; zero if no document matched it
+    pub fn density(&self, group: &[&str], dictionary_id: u32, dimension_id: u32) -> Option<f64> {
+        let totals = self.group(group)?;
+        if totals.word_count <= 0.0 {
+            return None;
+        }
+        let matches = totals
+            .dimensions
+            .get(&(dictionary_id, dimension_id))
+            .map_or(0.0, |dimension| dimension.matches);
+        Some(matches / totals.word_count)
+    }
+
+    /// Weighted average score of one dimension in one group
+    pub fn score(&self, group: &[&str], dictionary_id: u32, dimension_id: u32) -> Option<f64> {
+        let dimension = self
+            .group(group)?
+            .dimensions
+            .get(&(dictionary_id, dimension_id))?;
+        (dimension.score_weight > 0.0).then(|| dimension.score_sum / dimension.score_weight)
+    }
+
+    /// One row per group and measured dimension, ordered by group then dimension
+    pub fn rows(&self) -> Vec<GCAMAggregateRow> {
+        let mut rows = Vec::new();
+        for (group, totals) in &self.groups {
+            for (&(dictionary_id, dimension_id), dimension) in &totals.dimensions {
+                rows.push(GCAMAggregateRow {
+                    group: group.clone(),
+                    dictionary_id,
+                    dimension_id,
+                    dictionary: dimension
+                        .metadata
+                        .as_ref()
+                        .map(|meta| meta.dictionary.to_string()),
+                    dimension_name: dimension
+                        .metadata
+                        .as_ref()
+                        .map(|meta| meta.dimension_name.clone()),
+                    documents: totals.documents,
+                    matched_documents: dimension.documents,
+                    word_count: totals.word_count,
+                    density: (totals.word_count > 0.0)
+                        .then(|| dimension.matches / totals.word_count),
+                    score: (dimension.score_weight > 0.0)
+                        .then(|| dimension.score_sum / dimension.score_weight),
+                });
+            }
+        }
+        rows
+    }
+
+    /// Column names of `write_csv`
+    pub fn columns(&self) -> Vec<&'static str> {
+        let mut columns: Vec<&'static str> = self.group_by.iter().map(GroupBy::column).collect();
+        columns.extend([
+            "dictionary_id",
+            "dimension_id",
+            "dictionary",
+            "dimension",
+            "documents",
+            "matched_documents",
+            "word_count",
+            "density",
+            "score",
+        ]);
+        columns
+    }
+
+    /// Write the rows as a tidy CSV table; missing values are empty cells
+    pub fn write_csv<W: Write>(&self, writer: W) -> Result<()> {
+        let mut csv_writer = csv::Writer::from_writer(writer);
+        csv_writer.write_record(self.columns())?;
+        for row in self.rows() {
+            let mut record = row.group.clone();
+            record.extend([
+                row.dictionary_id.to_string(),
+                row.dimension_id.to_string(),
+                row.dictionary.unwrap_or_default(),
+                row.dimension_name.unwrap_or_default(),
+                row.documents.to_string(),
+                row.matched_documents.to_string(),
+                row.word_count.to_string(),
+                row.density.map(|v| v.to_string()).unwrap_or_default(),
+                row.score.map(|v| v.to_string()).unwrap_or_default(),
+            ]);
+            csv_writer.write_record(&record)?;
+        }
+        csv_writer.flush()?;
+        Ok(())
+    }
+}
+
+fn country_label(country: Option<CountryZone>) -> String {
+    country
+        .map(|zone| {
+            zone.fips_code()
+                .map(str::to_string)
+                .unwrap_or_else(|| zone.name().to_string())
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gcam::memory_database::GCAMCodebookDatabase;
+    use csv::StringRecord;
+
+    const CODEBOOK: &str = "Variable\tDictionaryID\tDimensionID\tType\tLanguageCode\tDictionaryHumanName\tDimensionHumanName\tDictionaryCitation
+c9.45\t9\t45\tWORDCOUNT\teng\tLinguistic Inquiry and Word Count (LIWC)\tAnxiety\tPennebaker et al. (2001)
+c10.1\t10\t1\tWORDCOUNT\teng\tSentiWordNet 3.0\tPositive\tEsuli et al. (2010)
+v10.1\t10\t1\tSCOREDVALUE\teng\tSentiWordNet 3.0\tPositive (Scored Value)\tEsuli et al. (2010)
+";
+
+    fn record(date: &str, source: &str, locations: &str, themes: &str, gcam: &str) -> StringRecord {
+        let id = format!("{}-1", date);
+        StringRecord::from(vec![
+            id.as_str(),
+            date,
+            "1",
+            source,
+            "https://example.com/article",
+            "",
+            "",
+            "",
+            themes,
+            "",
+            locations,
+            "",
+            "",
+            "",
+            "",
+            "0,0,0,0,0,0,0",
+            "",
+            gcam,
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+        ])
+    }
+
+    fn records() -> Vec<GKGTable> {
+        let db = GCAMCodebookDatabase::from_contents(CODEBOOK, "test").unwrap();
+        let abuja = "4#Abuja, Federal Capital Territory, Nigeria#NI#NI11##9.08#7.53#-2253354#120";
+        let nairobi = "4#Nairobi, Nairobi Area, Kenya#KE#KE05##-1.28#36.81#-126131#40";
+        [
+            record(
+                "20240501090000",
+                "dailytrust.com.ng",
+                abuja,
+                "PROTEST,10;ELECTION,50",
+                "wc:100,c9.45:5,c10.1:10,v10.1:0.5",
+            ),
+            record(
+                "20240501150000",
+                "punchng.com",
+                &format!("{};{}", nairobi, abuja),
+                "PROTEST,5",
+                "wc:300,c10.1:30,v10.1:0.1",
+            ),
+            record(
+                "20240502090000",
+                "nation.africa",
+                nairobi,
+                "",
+                "wc:200,c9.45:2",
+            ),
+        ]
+        .into_iter()
+        .map(|record| GKGTable::try_from_with_gcam_db(record, &db).unwrap())
+        .collect()
+    }
+
+    #[test]
+    fn test_source_country_and_primary_location() {
+        let records = records();
+        assert_eq!(records[0].source_country(), CountryZone::from_fips("NI"));
+        assert_eq!(records[1].source_country(), None);
+        assert_eq!(
+            records[1]
+                .primary_location()
+                .and_then(|location| location.country_code.as_ref())
+                .map(|code| code.raw()),
+            Some("KE")
+        );
+    }
+
+    #[test]
+    fn test_gcam_aggregate_weighted_by_word_count() {
+        let records = records();
+        let aggregate = GCAMAggregate::from_records(
+            vec![GroupBy::Time(TimeBucket::Day), GroupBy::SourceCountry],
+            &records,
+        );
+        let group = ["2024-05-01", "NI"];
+        assert_eq!(aggregate.documents(&group), 1);
+        assert_eq!(aggregate.density(&group, 9, 45), Some(0.05));
+        assert_eq!(aggregate.documents(&["2024-05-01", ""]), 1);
+
+        let overridden = GCAMAggregate::new(vec![GroupBy::SourceCountry])
+            .with_source_country("PunchNG.com", CountryZone::from_fips("NI").unwrap());
+        let mut overridden = overridden;
+        overridden.extend_records(&records);
+        assert_eq!(overridden.documents(&["NI"]), 2);
+
+        let mut aggregate = GCAMAggregate::new(vec![GroupBy::Time(TimeBucket::Day)]);
+        aggregate.extend_records(&records);
+        let day = ["2024-05-01"];
+        assert_eq!(aggregate.documents(&day), 2);
+        // (10 + 30) / (100 + 300), not the mean of 0.1 and 0.1
+        assert_eq!(aggregate.density(&day, 10, 1), Some(0.1));
+        // The second article did not match anxiety but its words still count
+        assert_eq!(aggregate.density(&day, 9, 45), Some(5.0 / 400.0));
+        // (0.5 * 100 + 0.1 * 300) / 400
+        let score = aggregate.score(&day, 10, 1).unwrap();
+        assert!((score - 0.2).abs() < 1e-6);
+        assert_eq!(aggregate.score(&day, 9, 45), None);
+    }
+
+    #[test]
+    fn test_gcam_aggregate_groups_and_export() {
+        let records = records();
+        let aggregate =
+            GCAMAggregate::from_records(vec![GroupBy::LocationCountry, GroupBy::Theme], &records);
+        let mut filtered = GCAMAggregate::new(vec![GroupBy::LocationCountry, GroupBy::Theme])
+            .with_dimensions([(9, 45)]);
+        filtered.extend_records(&records);
+
+        assert_eq!(aggregate.documents(&["NI", "ELECTION"]), 1);
+        assert_eq!(aggregate.documents(&["NI", "PROTEST"]), 1);
+        assert_eq!(aggregate.documents(&["KE", "PROTEST"]), 1);
+        assert_eq!(aggregate.documents(&["KE", ""]), 1);
+
+        let rows = filtered.rows();
+        assert!(rows.iter().all(|row| row.dictionary_id == 9));
+        let nigeria = rows
+            .iter()
+            .find(|row| row.group == vec!["NI", "PROTEST"])
+            .unwrap();
+        assert_eq!(nigeria.dimension_name.as_deref(), Some("Anxiety"));
+        assert_eq!(nigeria.matched_documents, 1);
+
+        let mut output = Vec::new();
+        filtered.write_csv(&mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        let mut lines = output.lines();
+        assert_eq!(
+            lines.next(),
+            Some(
+                "location_country,theme,dictionary_id,dimension_id,dictionary,dimension,documents,matched_documents,word_count,density,score"
+            )
+        );
+        assert!(output.contains(
+            "NI,PROTEST,9,45,Linguistic Inquiry and Word Count (LIWC),Anxiety,1,1,100,0.05,"
+        ));
+        assert_eq!(lines.count(), rows.len());
+    }
+}
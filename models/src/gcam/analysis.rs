@@ -0,0 +1,369 @@
+//! Typed interpretation of GCAM values
+//!
+//! A V2GCAM field mixes three kinds of number:
+//!
+//! - `wc`: the document's word count
+//! - `cX.Y`: how many words matched dimension Y of dictionary X
+//! - `vX.Y`: the average dictionary score of the words that matched dimension Y
+//!
+//! Raw match counts grow with article length, so `GCAMAnalysis` divides them by `wc`
+//! into densities, pairs each count with its score where the dictionary has one, and
+//! groups dimensions into per-dictionary vectors that can be compared across articles.
+
+use crate::gcam::lookup::{Dictionary, EnrichedGCAMEntry, GCAMCodebookEntry, MeasurementType};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// What a GCAM key measures, read from the key itself
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum GCAMVariable {
+    /// `wc`
+    WordCount,
+    /// `cX.Y`
+    MatchCount {
+        dictionary_id: u32,
+        dimension_id: u32,
+    },
+    /// `vX.Y`
+    Score {
+        dictionary_id: u32,
+        dimension_id: u32,
+    },
+}
+
+impl GCAMVariable {
+    pub fn parse(key: &str) -> Option<Self> {
+        if key == "wc" {
+            return Some(GCAMVariable::WordCount);
+        }
+        let kind = key.chars().next()?;
+        let (dictionary_id, dimension_id) = key.get(1..)?.split_once('.')?;
+        let dictionary_id = dictionary_id.parse().ok()?;
+        let dimension_id = dimension_id.parse().ok()?;
+        match kind {
+            'c' => Some(GCAMVariable::MatchCount {
+                dictionary_id,
+                dimension_id,
+            }),
+            'v' => Some(GCAMVariable::Score {
+                dictionary_id,
+                dimension_id,
+            }),
+            _ => None,
+        }
+    }
+
+    /// `(dictionary_id, dimension_id)`, or `None` for `wc`
+    pub fn dimension(&self) -> Option<(u32, u32)> {
+        match *self {
+            GCAMVariable::WordCount => None,
+            GCAMVariable::MatchCount {
+                dictionary_id,
+                dimension_id,
+            }
+            | GCAMVariable::Score {
+                dictionary_id,
+                dimension_id,
+            } => Some((dictionary_id, dimension_id)),
+        }
+    }
+
+    /// The codebook measurement type for this kind of variable
+    pub fn measurement_type(&self) -> MeasurementType {
+        match self {
+            GCAMVariable::WordCount | GCAMVariable::MatchCount { .. } => MeasurementType::WordCount,
+            GCAMVariable::Score { .. } => MeasurementType::ScoredValue,
+        }
+    }
+}
+
+impl fmt::Display for GCAMVariable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GCAMVariable::WordCount => write!(f, "wc"),
+            GCAMVariable::MatchCount {
+                dictionary_id,
+                dimension_id,
+            } => write!(f, "c{}.{}", dictionary_id, dimension_id),
+            GCAMVariable::Score {
+                dictionary_id,
+                dimension_id,
+            } => write!(f, "v{}.{}", dictionary_id, dimension_id),
+        }
+    }
+}
+
+impl EnrichedGCAMEntry {
+    /// What this entry's value measures, from its key
+    pub fn variable(&self) -> Option<GCAMVariable> {
+        GCAMVariable::parse(&self.key)
+    }
+}
+
+/// One GCAM dimension of a document, with its count and score paired up
+#[derive(Debug, Clone, PartialEq)]
+pub struct DimensionMeasurement {
+    pub dictionary_id: u32,
+    pub dimension_id: u32,
+    /// Words matching the dimension (`cX.Y`)
+    pub match_count: Option<f32>,
+    /// Matches per document word (`cX.Y / wc`)
+    pub density: Option<f64>,
+    /// Average score of the matching words (`vX.Y`)
+    pub score: Option<f32>,
+    /// Codebook entry of the count, or of the score when there is no count
+    pub metadata: Option<Arc<GCAMCodebookEntry>>,
+}
+
+impl DimensionMeasurement {
+    fn new(dictionary_id: u32, dimension_id: u32) -> Self {
+        Self {
+            dictionary_id,
+            dimension_id,
+            match_count: None,
+            density: None,
+            score: None,
+            metadata: None,
+        }
+    }
+
+    pub fn dimension_name(&self) -> Option<&str> {
+        self.metadata
+            .as_ref()
+            .map(|meta| meta.dimension_name.as_str())
+    }
+
+    pub fn dictionary(&self) -> Option<&Dictionary> {
+        self.metadata.as_ref().map(|meta| &meta.dictionary)
+    }
+}
+
+/// The dimensions one dictionary measured in a document, ordered by dimension ID
+#[derive(Debug, Clone, PartialEq)]
+pub struct DictionaryScores {
+    pub dictionary_id: u32,
+    pub dimensions: Vec<DimensionMeasurement>,
+}
+
+impl DictionaryScores {
+    pub fn dictionary(&self) -> Option<&Dictionary> {
+        self.dimensions
+            .iter()
+            .find_map(|dimension| dimension.dictionary())
+    }
+
+    /// `(dimension_id, density)` for every dimension with a match count
+    pub fn densities(&self) -> Vec<(u32, f64)> {
+        self.dimensions
+            .iter()
+            .filter_map(|dimension| Some((dimension.dimension_id, dimension.density?)))
+            .collect()
+    }
+
+    /// `(dimension_id, score)` for every dimension with a scored value
+    pub fn scores(&self) -> Vec<(u32, f32)> {
+        self.dimensions
+            .iter()
+            .filter_map(|dimension| Some((dimension.dimension_id, dimension.score?)))
+            .collect()
+    }
+}
+
+/// GCAM values of one document, interpreted
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GCAMAnalysis {
+    /// `wc`, the document's word count
+    pub word_count: Option<f32>,
+    dimensions: BTreeMap<(u32, u32), DimensionMeasurement>,
+}
+
+impl GCAMAnalysis {
+    /// Interpret a record's GCAM entries; keys that are not `wc`, `cX.Y` or `vX.Y` are skipped
+    pub fn from_entries<'a>(entries: impl IntoIterator<Item = &'a EnrichedGCAMEntry>) -> Self {
+        let mut analysis = GCAMAnalysis::default();
+
+        for entry in entries {
+            let Some(variable) = entry.variable() else {
+                continue;
+            };
+            let Some((dictionary_id, dimension_id)) = variable.dimension() else {
+                analysis.word_count = Some(entry.value);
+                continue;
+            };
+            let dimension = analysis
+                .dimensions
+                .entry((dictionary_id, dimension_id))
+                .or_insert_with(|| DimensionMeasurement::new(dictionary_id, dimension_id));
+            match variable {
+                GCAMVariable::MatchCount { .. } => {
+                    dimension.match_count = Some(entry.value);
+                    if entry.metadata.is_some() {
+                        dimension.metadata = entry.metadata.clone();
+                    }
+                }
+                _ => {
+                    dimension.score = Some(entry.value);
+                    if dimension.metadata.is_none() {
+                        dimension.metadata = entry.metadata.clone();
+                    }
+                }
+            }
+        }
+
+        if let Some(word_count) = analysis.word_count.filter(|&wc| wc > 0.0) {
+            for dimension in analysis.dimensions.values_mut() {
+                dimension.density = dimension
+                    .match_count
+                    .map(|count| count as f64 / word_count as f64);
+            }
+        }
+
+        analysis
+    }
+
+    pub fn dimension(
+        &self,
+        dictionary_id: u32,
+        dimension_id: u32,
+    ) -> Option<&DimensionMeasurement> {
+        self.dimensions.get(&(dictionary_id, dimension_id))
+    }
+
+    /// Every measured dimension, ordered by dictionary and dimension ID
+    pub fn dimensions(&self) -> impl Iterator<Item = &DimensionMeasurement> {
+        self.dimensions.values()
+    }
+
+    /// Matches per word for a dimension; `None` without a count or a non-zero `wc`
+    pub fn density(&self, dictionary_id: u32, dimension_id: u32) -> Option<f64> {
+        self.dimension(dictionary_id, dimension_id)?.density
+    }
+
+    pub fn score(&self, dictionary_id: u32, dimension_id: u32) -> Option<f32> {
+        self.dimension(dictionary_id, dimension_id)?.score
+    }
+
+    /// The dimensions measured for one dictionary ID
+    pub fn dictionary_scores(&self, dictionary_id: u32) -> Option<DictionaryScores> {
+        let dimensions: Vec<DimensionMeasurement> = self
+            .dimensions
+            .range((dictionary_id, 0)..=(dictionary_id, u32::MAX))
+            .map(|(_, dimension)| dimension.clone())
+            .collect();
+        (!dimensions.is_empty()).then_some(DictionaryScores {
+            dictionary_id,
+            dimensions,
+        })
+    }
+
+    /// Score vectors for every dictionary ID present, keyed by dictionary ID
+    pub fn by_dictionary(&self) -> BTreeMap<u32, DictionaryScores> {
+        let mut scores: BTreeMap<u32, DictionaryScores> = BTreeMap::new();
+        for dimension in self.dimensions.values() {
+            scores
+                .entry(dimension.dictionary_id)
+                .or_insert_with(|| DictionaryScores {
+                    dictionary_id: dimension.dictionary_id,
+                    dimensions: Vec::new(),
+                })
+                .dimensions
+                .push(dimension.clone());
+        }
+        scores
+    }
+
+    /// Score vectors for the dictionary IDs that carry `dictionary` (needs enriched entries)
+    pub fn for_dictionary(&self, dictionary: &Dictionary) -> Vec<DictionaryScores> {
+        self.by_dictionary()
+            .into_values()
+            .filter(|scores| scores.dictionary() == Some(dictionary))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gcam::field::{GCAMField, GCAMSelection};
+    use crate::gcam::memory_database::GCAMCodebookDatabase;
+    use anyhow::Result;
+
+    #[test]
+    fn test_gcam_variable_parse() {
+        assert_eq!(GCAMVariable::parse("wc"), Some(GCAMVariable::WordCount));
+        let count = GCAMVariable::parse("c10.2").unwrap();
+        assert_eq!(count.dimension(), Some((10, 2)));
+        assert_eq!(count.measurement_type(), MeasurementType::WordCount);
+        assert_eq!(count.to_string(), "c10.2");
+        let score = GCAMVariable::parse("v10.2").unwrap();
+        assert_eq!(score.measurement_type(), MeasurementType::ScoredValue);
+        assert_eq!(score.to_string(), "v10.2");
+
+        assert_eq!(GCAMVariable::parse("x1.1"), None);
+        assert_eq!(GCAMVariable::parse("c1"), None);
+        assert_eq!(GCAMVariable::parse("cA.1"), None);
+        assert_eq!(GCAMVariable::parse(""), None);
+    }
+
+    #[test]
+    fn test_gcam_analysis_densities_and_scores() -> Result<()> {
+        let db = GCAMCodebookDatabase::from_contents(
+            "Variable\tDictionaryID\tDimensionID\tType\tLanguageCode\tDictionaryHumanName\tDimensionHumanName\tDictionaryCitation
+c10.1\t10\t1\tWORDCOUNT\teng\tSentiWordNet 3.0\tPositive\tEsuli et al. (2010)
+c10.2\t10\t2\tWORDCOUNT\teng\tSentiWordNet 3.0\tNegative\tEsuli et al. (2010)
+v10.1\t10\t1\tSCOREDVALUE\teng\tSentiWordNet 3.0\tPositive (Scored Value)\tEsuli et al. (2010)
+c1.1\t1\t1\tWORDCOUNT\teng\tForest Values\tAESTHETIC\tBengston & Xu (1995)
+",
+            "test",
+        )?;
+        let field = GCAMField::new(
+            "wc:200,c10.1:20,c10.2:5,v10.1:0.25,c1.1:2,v99.1:3.5,junk:1",
+            Some(&db),
+            GCAMSelection::All,
+        );
+        let analysis = GCAMAnalysis::from_entries(field.entries());
+
+        assert_eq!(analysis.word_count, Some(200.0));
+        assert_eq!(analysis.density(10, 1), Some(0.1));
+        assert_eq!(analysis.score(10, 1), Some(0.25));
+        assert_eq!(analysis.density(10, 2), Some(0.025));
+        assert_eq!(analysis.score(10, 2), None);
+        assert_eq!(
+            analysis.dimension(10, 1).unwrap().dimension_name(),
+            Some("Positive")
+        );
+
+        // A score without a count or codebook entry is still kept
+        let orphan = analysis.dimension(99, 1).unwrap();
+        assert_eq!(orphan.score, Some(3.5));
+        assert_eq!(orphan.density, None);
+        assert!(orphan.metadata.is_none());
+
+        let sentiment = analysis.dictionary_scores(10).unwrap();
+        assert_eq!(sentiment.densities(), vec![(1, 0.1), (2, 0.025)]);
+        assert_eq!(sentiment.scores(), vec![(1, 0.25)]);
+        assert_eq!(
+            sentiment.dictionary(),
+            Some(&Dictionary::new("SentiWordNet 3.0"))
+        );
+
+        assert_eq!(analysis.by_dictionary().len(), 3);
+        assert_eq!(
+            analysis
+                .for_dictionary(&Dictionary::new("Forest Values"))
+                .len(),
+            1
+        );
+        assert!(analysis.dictionary_scores(2).is_none());
+
+        // No densities without a word count
+        let no_wc = GCAMAnalysis::from_entries(
+            GCAMField::new("c10.1:20", None, GCAMSelection::All).entries(),
+        );
+        assert_eq!(no_wc.density(10, 1), None);
+        assert_eq!(no_wc.dimension(10, 1).unwrap().match_count, Some(20.0));
+        Ok(())
+    }
+}
@@ -0,0 +1,551 @@
+//! GCAM codebook validation and diffing
+//!
+//! GDELT revises the GCAM master codebook from time to time. `validate_codebook`
+//! checks a codebook file's structure, and `CodebookDiff` reports what changed between
+//! two loaded codebooks: variables, dictionaries, languages and dimension names.
+//!
+//! Violations come in two severities. Errors break the structural rules loading relies
+//! on (a well-formed header, eight columns, unique variables, numeric IDs, a known
+//! measurement type); warnings flag data that loads but looks wrong, such as a variable
+//! name that disagrees with its own IDs. GDELT's own codebook has a few of those
+//! (`c41.1`-`c41.3` carry dictionary ID 40).
+
+use crate::gcam::lookup::{GCAMCodebookEntry, MeasurementType};
+use crate::gcam::memory_database::GCAMCodebookDatabase;
+use anyhow::{Context, Result};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::path::Path;
+
+/// The header every codebook file starts with
+pub const CODEBOOK_COLUMNS: [&str; 8] = [
+    "Variable",
+    "DictionaryID",
+    "DimensionID",
+    "Type",
+    "LanguageCode",
+    "DictionaryHumanName",
+    "DimensionHumanName",
+    "DictionaryCitation",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+/// A broken rule, with the 1-based line it was found on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CodebookViolation {
+    EmptyFile,
+    BadHeader {
+        found: String,
+    },
+    FieldCount {
+        line: usize,
+        found: usize,
+    },
+    InvalidUtf8 {
+        line: usize,
+    },
+    EmptyVariable {
+        line: usize,
+    },
+    DuplicateVariable {
+        line: usize,
+        variable: String,
+        first_line: usize,
+    },
+    InvalidDictionaryId {
+        line: usize,
+        value: String,
+    },
+    InvalidDimensionId {
+        line: usize,
+        value: String,
+    },
+    UnknownMeasurementType {
+        line: usize,
+        value: String,
+    },
+    /// The variable name does not match its type and IDs (`cX.Y` / `vX.Y`)
+    VariableMismatch {
+        line: usize,
+        variable: String,
+        expected: String,
+    },
+    EmptyDimensionName {
+        line: usize,
+        variable: String,
+    },
+}
+
+impl CodebookViolation {
+    pub fn severity(&self) -> Severity {
+        match self {
+            CodebookViolation::InvalidUtf8 { .. }
+            | CodebookViolation::VariableMismatch { .. }
+            | CodebookViolation::EmptyDimensionName { .. } => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity() == Severity::Error
+    }
+}
+
+impl fmt::Display for CodebookViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CodebookViolation::EmptyFile => write!(f, "codebook is empty"),
+            CodebookViolation::BadHeader { found } => {
+                write!(f, "line 1: unexpected header '{}'", found)
+            }
+            CodebookViolation::FieldCount { line, found } => {
+                write!(f, "line {}: expected 8 fields, found {}", line, found)
+            }
+            CodebookViolation::InvalidUtf8 { line } => {
+                write!(f, "line {}: contains bytes that are not valid UTF-8", line)
+            }
+            CodebookViolation::EmptyVariable { line } => {
+                write!(f, "line {}: empty variable name", line)
+            }
+            CodebookViolation::DuplicateVariable {
+                line,
+                variable,
+                first_line,
+            } => write!(
+                f,
+                "line {}: duplicate variable '{}' (first on line {})",
+                line, variable, first_line
+            ),
+            CodebookViolation::InvalidDictionaryId { line, value } => {
+                write!(
+                    f,
+                    "line {}: dictionary ID '{}' is not a number",
+                    line, value
+                )
+            }
+            CodebookViolation::InvalidDimensionId { line, value } => {
+                write!(f, "line {}: dimension ID '{}' is not a number", line, value)
+            }
+            CodebookViolation::UnknownMeasurementType { line, value } => {
+                write!(f, "line {}: unknown measurement type '{}'", line, value)
+            }
+            CodebookViolation::VariableMismatch {
+                line,
+                variable,
+                expected,
+            } => write!(
+                f,
+                "line {}: variable '{}' does not match its type and IDs (expected '{}')",
+                line, variable, expected
+            ),
+            CodebookViolation::EmptyDimensionName { line, variable } => {
+                write!(f, "line {}: '{}' has no dimension name", line, variable)
+            }
+        }
+    }
+}
+
+/// Check a codebook file's structure without loading it
+pub fn validate_codebook(content: &[u8]) -> Vec<CodebookViolation> {
+    let mut violations = Vec::new();
+    let mut lines = content.split(|&byte| byte == b'\n').enumerate();
+
+    match lines.next() {
+        Some((_, header)) if !header.iter().all(u8::is_ascii_whitespace) => {
+            let header = String::from_utf8_lossy(header);
+            let columns: Vec<&str> = header.trim_end_matches('\r').split('\t').collect();
+            if columns != CODEBOOK_COLUMNS {
+                violations.push(CodebookViolation::BadHeader {
+                    found: header.trim_end().to_string(),
+                });
+            }
+        }
+        _ => {
+            violations.push(CodebookViolation::EmptyFile);
+            return violations;
+        }
+    }
+
+    let mut first_seen: HashMap<String, usize> = HashMap::new();
+    for (index, raw_line) in lines {
+        let line = index + 1;
+        let text = match std::str::from_utf8(raw_line) {
+            Ok(text) => std::borrow::Cow::Borrowed(text),
+            Err(_) => {
+                violations.push(CodebookViolation::InvalidUtf8 { line });
+                String::from_utf8_lossy(raw_line)
+            }
+        };
+        let text = text.trim_end_matches('\r');
+        if text.trim().is_empty() {
+            continue;
+        }
+
+        let fields: Vec<&str> = text
+            .split('\t')
+            .map(|field| field.trim_matches('"'))
+            .collect();
+        if fields.len() != CODEBOOK_COLUMNS.len() {
+            violations.push(CodebookViolation::FieldCount {
+                line,
+                found: fields.len(),
+            });
+            if fields.len() < CODEBOOK_COLUMNS.len() {
+                continue;
+            }
+        }
+
+        let variable = fields[0];
+        if variable.is_empty() {
+            violations.push(CodebookViolation::EmptyVariable { line });
+        } else if let Some(&first_line) = first_seen.get(variable) {
+            violations.push(CodebookViolation::DuplicateVariable {
+                line,
+                variable: variable.to_string(),
+                first_line,
+            });
+        } else {
+            first_seen.insert(variable.to_string(), line);
+        }
+
+        let dictionary_id = fields[1].parse::<u32>();
+        if dictionary_id.is_err() {
+            violations.push(CodebookViolation::InvalidDictionaryId {
+                line,
+                value: fields[1].to_string(),
+            });
+        }
+        let dimension_id = fields[2].parse::<u32>();
+        if dimension_id.is_err() {
+            violations.push(CodebookViolation::InvalidDimensionId {
+                line,
+                value: fields[2].to_string(),
+            });
+        }
+
+        let prefix = match MeasurementType::from(fields[3]) {
+            MeasurementType::WordCount => Some('c'),
+            MeasurementType::ScoredValue => Some('v'),
+            MeasurementType::Other(value) => {
+                violations.push(CodebookViolation::UnknownMeasurementType { line, value });
+                None
+            }
+            _ => None,
+        };
+        if let (Some(prefix), Ok(dictionary_id), Ok(dimension_id)) =
+            (prefix, dictionary_id, dimension_id)
+        {
+            let expected = format!("{}{}.{}", prefix, dictionary_id, dimension_id);
+            if !variable.is_empty() && variable != expected {
+                violations.push(CodebookViolation::VariableMismatch {
+                    line,
+                    variable: variable.to_string(),
+                    expected,
+                });
+            }
+        }
+
+        if fields[6].trim().is_empty() {
+            violations.push(CodebookViolation::EmptyDimensionName {
+                line,
+                variable: variable.to_string(),
+            });
+        }
+    }
+
+    violations
+}
+
+pub fn validate_codebook_file<P: AsRef<Path>>(path: P) -> Result<Vec<CodebookViolation>> {
+    let path = path.as_ref();
+    let content = std::fs::read(path)
+        .with_context(|| format!("Failed to read GCAM codebook {}", path.display()))?;
+    Ok(validate_codebook(&content))
+}
+
+/// One column of a variable that differs between codebooks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    pub field: &'static str,
+    pub old: String,
+    pub new: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VariableChange {
+    pub variable: String,
+    pub changes: Vec<FieldChange>,
+}
+
+impl VariableChange {
+    fn between(old: &GCAMCodebookEntry, new: &GCAMCodebookEntry) -> Option<Self> {
+        let columns = [
+            (
+                "DictionaryID",
+                old.dictionary_id.to_string(),
+                new.dictionary_id.to_string(),
+            ),
+            (
+                "DimensionID",
+                old.dimension_id.to_string(),
+                new.dimension_id.to_string(),
+            ),
+            (
+                "Type",
+                old.measurement_type.to_string(),
+                new.measurement_type.to_string(),
+            ),
+            (
+                "LanguageCode",
+                old.language.to_string(),
+                new.language.to_string(),
+            ),
+            (
+                "DictionaryHumanName",
+                old.dictionary.to_string(),
+                new.dictionary.to_string(),
+            ),
+            (
+                "DimensionHumanName",
+                old.dimension_name.clone(),
+                new.dimension_name.clone(),
+            ),
+            (
+                "DictionaryCitation",
+                old.citation.clone(),
+                new.citation.clone(),
+            ),
+        ];
+        let changes: Vec<FieldChange> = columns
+            .into_iter()
+            .filter(|(_, old, new)| old != new)
+            .map(|(field, old, new)| FieldChange { field, old, new })
+            .collect();
+        (!changes.is_empty()).then(|| VariableChange {
+            variable: old.variable.clone(),
+            changes,
+        })
+    }
+
+    pub fn changed(&self, field: &str) -> Option<&FieldChange> {
+        self.changes.iter().find(|change| change.field == field)
+    }
+}
+
+/// Differences between two codebooks, each list sorted
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CodebookDiff {
+    pub added_variables: Vec<String>,
+    pub removed_variables: Vec<String>,
+    pub changed_variables: Vec<VariableChange>,
+    pub added_dictionaries: Vec<String>,
+    pub removed_dictionaries: Vec<String>,
+    pub added_languages: Vec<String>,
+    pub removed_languages: Vec<String>,
+}
+
+impl CodebookDiff {
+    pub fn between(old: &GCAMCodebookDatabase, new: &GCAMCodebookDatabase) -> Result<Self> {
+        let old_entries = by_variable(old.get_all_entries()?);
+        let new_entries = by_variable(new.get_all_entries()?);
+
+        let mut diff = CodebookDiff::default();
+        for (variable, old_entry) in &old_entries {
+            match new_entries.get(variable) {
+                Some(new_entry) => diff
+                    .changed_variables
+                    .extend(VariableChange::between(old_entry, new_entry)),
+                None => diff.removed_variables.push(variable.clone()),
+            }
+        }
+        diff.added_variables = new_entries
+            .keys()
+            .filter(|variable| !old_entries.contains_key(*variable))
+            .cloned()
+            .collect();
+
+        let (old_dictionaries, old_languages) = names(&old_entries);
+        let (new_dictionaries, new_languages) = names(&new_entries);
+        diff.added_dictionaries = difference(&new_dictionaries, &old_dictionaries);
+        diff.removed_dictionaries = difference(&old_dictionaries, &new_dictionaries);
+        diff.added_languages = difference(&new_languages, &old_languages);
+        diff.removed_languages = difference(&old_languages, &new_languages);
+        Ok(diff)
+    }
+
+    /// Variables whose dimension name changed, as `(variable, old, new)`
+    pub fn renamed_dimensions(&self) -> Vec<(&str, &str, &str)> {
+        self.changed_variables
+            .iter()
+            .filter_map(|change| {
+                let renamed = change.changed("DimensionHumanName")?;
+                Some((
+                    change.variable.as_str(),
+                    renamed.old.as_str(),
+                    renamed.new.as_str(),
+                ))
+            })
+            .collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self == &CodebookDiff::default()
+    }
+}
+
+impl fmt::Display for CodebookDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No differences");
+        }
+        let lists = [
+            ("Added variables", "+", &self.added_variables),
+            ("Removed variables", "-", &self.removed_variables),
+            ("Added dictionaries", "+", &self.added_dictionaries),
+            ("Removed dictionaries", "-", &self.removed_dictionaries),
+            ("Added languages", "+", &self.added_languages),
+            ("Removed languages", "-", &self.removed_languages),
+        ];
+        for (title, marker, items) in lists {
+            if !items.is_empty() {
+                writeln!(f, "{} ({}):", title, items.len())?;
+                for item in items {
+                    writeln!(f, "  {} {}", marker, item)?;
+                }
+            }
+        }
+        if !self.changed_variables.is_empty() {
+            writeln!(f, "Changed variables ({}):", self.changed_variables.len())?;
+            for change in &self.changed_variables {
+                writeln!(f, "  {}", change.variable)?;
+                for field in &change.changes {
+                    writeln!(f, "    {}: '{}' -> '{}'", field.field, field.old, field.new)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn by_variable(entries: Vec<GCAMCodebookEntry>) -> BTreeMap<String, GCAMCodebookEntry> {
+    entries
+        .into_iter()
+        .map(|entry| (entry.variable.clone(), entry))
+        .collect()
+}
+
+fn names(entries: &BTreeMap<String, GCAMCodebookEntry>) -> (BTreeSet<String>, BTreeSet<String>) {
+    let dictionaries = entries
+        .values()
+        .map(|entry| entry.dictionary.to_string())
+        .collect();
+    let languages = entries
+        .values()
+        .map(|entry| entry.language.to_string())
+        .collect();
+    (dictionaries, languages)
+}
+
+fn difference(left: &BTreeSet<String>, right: &BTreeSet<String>) -> Vec<String> {
+    left.difference(right).cloned().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEADER: &str = "Variable\tDictionaryID\tDimensionID\tType\tLanguageCode\tDictionaryHumanName\tDimensionHumanName\tDictionaryCitation";
+
+    #[test]
+    fn test_validate_codebook() {
+        let content = format!(
+            "{}\nc1.1\t1\t1\tWORDCOUNT\teng\tForest Values\tAESTHETIC\tcite\nc1.1\t1\t2\tWORDCOUNT\teng\tForest Values\tECONOMIC\tcite\nc1.3\tone\t3\tWORDCOUNT\teng\tForest Values\tLIFE\tcite\nc1.4\t1\t4\tRATIOVALUE\teng\tForest Values\tMORAL\tcite\nc41.1\t40\t1\tWORDCOUNT\teng\tCentral Bank\tPOSITIVE\tcite\nc1.5\t1\t5\tWORDCOUNT\n",
+            HEADER
+        );
+        let violations = validate_codebook(content.as_bytes());
+        for violation in &violations {
+            println!("{}", violation);
+        }
+
+        assert!(violations.contains(&CodebookViolation::DuplicateVariable {
+            line: 3,
+            variable: "c1.1".to_string(),
+            first_line: 2,
+        }));
+        assert!(
+            violations.contains(&CodebookViolation::InvalidDictionaryId {
+                line: 4,
+                value: "one".to_string(),
+            })
+        );
+        assert!(
+            violations.contains(&CodebookViolation::UnknownMeasurementType {
+                line: 5,
+                value: "RATIOVALUE".to_string(),
+            })
+        );
+        assert!(violations.contains(&CodebookViolation::FieldCount { line: 7, found: 4 }));
+
+        let mismatch = CodebookViolation::VariableMismatch {
+            line: 6,
+            variable: "c41.1".to_string(),
+            expected: "c40.1".to_string(),
+        };
+        assert!(violations.contains(&mismatch));
+        assert_eq!(mismatch.severity(), Severity::Warning);
+
+        let mut invalid = HEADER.as_bytes().to_vec();
+        invalid.extend(b"\nc1.1\t1\t1\tWORDCOUNT\thun\tForest Values\tM\xc1SODLAGOS\tcite\n");
+        assert_eq!(
+            validate_codebook(&invalid),
+            vec![CodebookViolation::InvalidUtf8 { line: 2 }]
+        );
+
+        assert_eq!(validate_codebook(b""), vec![CodebookViolation::EmptyFile]);
+        assert!(matches!(
+            validate_codebook(b"Variable,DictionaryID\n")[0],
+            CodebookViolation::BadHeader { .. }
+        ));
+    }
+
+    #[test]
+    fn test_codebook_diff() -> Result<()> {
+        let old = GCAMCodebookDatabase::from_contents(
+            &format!(
+                "{}\nc1.1\t1\t1\tWORDCOUNT\teng\tForest Values\tAESTHETIC\tcite\nc1.2\t1\t2\tWORDCOUNT\teng\tForest Values\tECONOMIC\tcite\nc2.1\t2\t1\tWORDCOUNT\tfra\tOpinion Observer\tPOSITIVE\tcite\n",
+                HEADER
+            ),
+            "old",
+        )?;
+        let new = GCAMCodebookDatabase::from_contents(
+            &format!(
+                "{}\nc1.1\t1\t1\tWORDCOUNT\teng\tForest Values\tBEAUTY\tnew cite\nc1.2\t1\t2\tWORDCOUNT\teng\tForest Values\tECONOMIC\tcite\nc3.1\t3\t1\tWORDCOUNT\tspa\tSentiWords\tPolarity\tcite\n",
+                HEADER
+            ),
+            "new",
+        )?;
+
+        let diff = CodebookDiff::between(&old, &new)?;
+        println!("{}", diff);
+        assert_eq!(diff.added_variables, vec!["c3.1"]);
+        assert_eq!(diff.removed_variables, vec!["c2.1"]);
+        assert_eq!(diff.added_dictionaries, vec!["SentiWords"]);
+        assert_eq!(diff.removed_dictionaries, vec!["Opinion Observer"]);
+        assert_eq!(diff.added_languages, vec!["spa"]);
+        assert_eq!(diff.removed_languages, vec!["fra"]);
+        assert_eq!(diff.changed_variables.len(), 1);
+        assert_eq!(diff.changed_variables[0].changes.len(), 2);
+        assert_eq!(
+            diff.renamed_dimensions(),
+            vec![("c1.1", "AESTHETIC", "BEAUTY")]
+        );
+
+        assert!(CodebookDiff::between(&old, &old)?.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,344 @@
+//! GCAM coverage across batches of GKG records
+//!
+//! `GKGTable::gcam_coverage_stats` covers a single record. `GCAMBatchCoverage` folds a
+//! whole batch into one report against a codebook: which variables occur and how often,
+//! which keys the codebook doesn't know, and how much of each dictionary and language
+//! the batch actually exercised. A rising share of unknown keys is the first sign that
+//! GDELT has moved on to a codebook revision we haven't loaded.
+//!
+//! Reports serialize with bincode like `GCAMCoverageStats`, so they can be stored per
+//! batch and compared later.
+
+use crate::gcam::GCAMCoverageStats;
+use crate::gcam::analysis::GCAMVariable;
+use crate::gcam::memory_database::{CodebookVersion, GCAMCodebookDatabase};
+use crate::types::gkg_table::GKGTable;
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// How much of one dictionary or language a batch used
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GroupCoverage {
+    pub name: String,
+    /// Variables the codebook defines for this group
+    pub codebook_variables: usize,
+    /// Distinct codebook variables that occurred in the batch
+    pub variables_seen: usize,
+    /// Total occurrences across all records
+    pub occurrences: u64,
+}
+
+impl GroupCoverage {
+    /// Share of the group's codebook variables seen at least once, in percent
+    pub fn coverage_percentage(&self) -> f64 {
+        if self.codebook_variables > 0 {
+            (self.variables_seen as f64 / self.codebook_variables as f64) * 100.0
+        } else {
+            0.0
+        }
+    }
+}
+
+/// GCAM coverage of a batch of GKG records against one codebook
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GCAMBatchCoverage {
+    pub codebook_version: CodebookVersion,
+    pub records: u64,
+    pub records_with_gcam: u64,
+    /// Entry totals summed over the batch, as `GKGTable::gcam_coverage_stats` counts them
+    pub totals: GCAMCoverageStats,
+    /// Occurrences of every variable the codebook knows, plus `wc`
+    pub variables: BTreeMap<String, u64>,
+    /// Occurrences of keys missing from the codebook
+    pub unknown_variables: BTreeMap<String, u64>,
+    /// Keyed by the dictionary ID the variable names carry (`GCAMCodebookEntry::dimension_key`)
+    pub dictionaries: BTreeMap<u32, GroupCoverage>,
+    /// Keyed by language code
+    pub languages: BTreeMap<String, GroupCoverage>,
+}
+
+impl GCAMBatchCoverage {
+    /// An empty report listing every dictionary and language in the codebook
+    pub fn new(codebook: &GCAMCodebookDatabase) -> Result<Self> {
+        let mut dictionaries: BTreeMap<u32, GroupCoverage> = BTreeMap::new();
+        let mut languages: BTreeMap<String, GroupCoverage> = BTreeMap::new();
+        for entry in codebook.get_all_entries()? {
+            let dictionary = dictionaries.entry(entry.dimension_key().0).or_default();
+            dictionary.name = entry.dictionary.to_string();
+            dictionary.codebook_variables += 1;
+
+            let language = entry.language.to_string();
+            let group = languages.entry(language.clone()).or_default();
+            group.name = language;
+            group.codebook_variables += 1;
+        }
+
+        Ok(Self {
+            codebook_version: codebook.version(),
+            records: 0,
+            records_with_gcam: 0,
+            totals: GCAMCoverageStats {
+                total_entries: 0,
+                entries_with_metadata: 0,
+                entries_without_metadata: 0,
+                coverage_percentage: 0.0,
+            },
+            variables: BTreeMap::new(),
+            unknown_variables: BTreeMap::new(),
+            dictionaries,
+            languages,
+        })
+    }
+
+    pub fn from_records<'a>(
+        codebook: &GCAMCodebookDatabase,
+        records: impl IntoIterator<Item = &'a GKGTable>,
+    ) -> Result<Self> {
+        let mut coverage = Self::new(codebook)?;
+        for record in records {
+            coverage.add_record(codebook, record)?;
+        }
+        Ok(coverage)
+    }
+
+    /// Count one record's GCAM entries; `codebook` must be the one the report was built for
+    pub fn add_record(&mut self, codebook: &GCAMCodebookDatabase, record: &GKGTable) -> Result<()> {
+        if codebook.version() != self.codebook_version {
+            return Err(anyhow!(
+                "Coverage report was built for codebook {}, not {}",
+                self.codebook_version.short(),
+                codebook.version().short()
+            ));
+        }
+
+        self.records += 1;
+        let entries = record.gcam();
+        if !entries.is_empty() {
+            self.records_with_gcam += 1;
+        }
+
+        for entry in entries {
+            self.totals.total_entries += 1;
+            let Some(metadata) = codebook.get_shared(&entry.key) else {
+                self.totals.entries_without_metadata += 1;
+                let counts = if GCAMVariable::parse(&entry.key) == Some(GCAMVariable::WordCount) {
+                    &mut self.variables
+                } else {
+                    &mut self.unknown_variables
+                };
+                *counts.entry(entry.key.clone()).or_default() += 1;
+                continue;
+            };
+            self.totals.entries_with_metadata += 1;
+
+            let count = self.variables.entry(entry.key.clone()).or_default();
+            let first_seen = *count == 0;
+            *count += 1;
+
+            let dictionary = self
+                .dictionaries
+                .entry(metadata.dimension_key().0)
+                .or_default();
+            let language = self
+                .languages
+                .entry(metadata.language.to_string())
+                .or_default();
+            for group in [dictionary, language] {
+                group.occurrences += 1;
+                if first_seen {
+                    group.variables_seen += 1;
+                }
+            }
+        }
+
+        self.totals.coverage_percentage = if self.totals.total_entries > 0 {
+            (self.totals.entries_with_metadata as f64 / self.totals.total_entries as f64) * 100.0
+        } else {
+            0.0
+        };
+        Ok(())
+    }
+
+    /// The most frequent keys missing from the codebook, most frequent first
+    pub fn top_unknown(&self, n: usize) -> Vec<(&str, u64)> {
+        let mut unknown: Vec<(&str, u64)> = self
+            .unknown_variables
+            .iter()
+            .map(|(key, count)| (key.as_str(), *count))
+            .collect();
+        unknown.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        unknown.truncate(n);
+        unknown
+    }
+
+    /// Share of all entries whose key is missing from the codebook, in percent
+    pub fn unknown_percentage(&self) -> f64 {
+        let unknown: u64 = self.unknown_variables.values().sum();
+        if self.totals.total_entries > 0 {
+            (unknown as f64 / self.totals.total_entries as f64) * 100.0
+        } else {
+            0.0
+        }
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        bincode::serialize(self).map_err(|e| anyhow!("Serialization failed: {}", e))
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        bincode::deserialize(bytes).map_err(|e| anyhow!("Deserialization failed: {}", e))
+    }
+}
+
+impl fmt::Display for GCAMBatchCoverage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "GCAM batch coverage against codebook {}: {} records ({} with GCAM)",
+            self.codebook_version.short(),
+            self.records,
+            self.records_with_gcam
+        )?;
+        writeln!(f, "{}", self.totals)?;
+        writeln!(
+            f,
+            "{} distinct variables, {} unknown ({:.2}% of entries)",
+            self.variables.len() + self.unknown_variables.len(),
+            self.unknown_variables.len(),
+            self.unknown_percentage()
+        )?;
+        for (key, count) in self.top_unknown(10) {
+            writeln!(f, "  unknown {}: {}", key, count)?;
+        }
+        writeln!(f, "Dictionaries:")?;
+        for (id, dictionary) in &self.dictionaries {
+            writeln!(
+                f,
+                "  {:>3} {}: {}/{} variables ({:.1}%), {} occurrences",
+                id,
+                dictionary.name,
+                dictionary.variables_seen,
+                dictionary.codebook_variables,
+                dictionary.coverage_percentage(),
+                dictionary.occurrences
+            )?;
+        }
+        writeln!(f, "Languages:")?;
+        for language in self.languages.values() {
+            writeln!(
+                f,
+                "  {}: {}/{} variables ({:.1}%), {} occurrences",
+                language.name,
+                language.variables_seen,
+                language.codebook_variables,
+                language.coverage_percentage(),
+                language.occurrences
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use csv::StringRecord;
+
+    const CODEBOOK: &str = "Variable\tDictionaryID\tDimensionID\tType\tLanguageCode\tDictionaryHumanName\tDimensionHumanName\tDictionaryCitation
+c1.1\t1\t1\tWORDCOUNT\teng\tForest Values\tAESTHETIC\tBengston & Xu (1995)
+c1.2\t1\t2\tWORDCOUNT\teng\tForest Values\tECONOMIC\tBengston & Xu (1995)
+c2.1\t2\t1\tWORDCOUNT\tfra\tOpinion Observer\tPOSITIVE\tLiu et al. (2005)
+";
+
+    fn record(gcam: &str) -> GKGTable {
+        let mut fields = vec![""; 27];
+        fields[0] = "20240501090000-1";
+        fields[1] = "20240501090000";
+        fields[2] = "1";
+        fields[3] = "example.com";
+        fields[4] = "https://example.com/article";
+        fields[15] = "0,0,0,0,0,0,0";
+        fields[17] = gcam;
+        GKGTable::try_from(StringRecord::from(fields)).unwrap()
+    }
+
+    #[test]
+    fn test_batch_coverage() -> Result<()> {
+        let db = GCAMCodebookDatabase::from_contents(CODEBOOK, "test")?;
+        let records = vec![
+            record("wc:100,c1.1:2,c99.1:1"),
+            record("wc:50,c1.1:1,c99.1:3,c98.2:1"),
+            record(""),
+        ];
+
+        let coverage = GCAMBatchCoverage::from_records(&db, &records)?;
+        println!("{}", coverage);
+        assert_eq!(coverage.records, 3);
+        assert_eq!(coverage.records_with_gcam, 2);
+        assert_eq!(coverage.totals.total_entries, 7);
+        assert_eq!(coverage.totals.entries_with_metadata, 2);
+        assert_eq!(coverage.variables.get("wc"), Some(&2));
+        assert_eq!(coverage.variables.get("c1.1"), Some(&2));
+        assert_eq!(coverage.top_unknown(1), vec![("c99.1", 2)]);
+        assert_eq!(coverage.unknown_variables.len(), 2);
+
+        let forest = &coverage.dictionaries[&1];
+        assert_eq!(forest.name, "Forest Values");
+        assert_eq!(
+            (
+                forest.variables_seen,
+                forest.codebook_variables,
+                forest.occurrences
+            ),
+            (1, 2, 2)
+        );
+        assert_eq!(forest.coverage_percentage(), 50.0);
+        assert_eq!(coverage.dictionaries[&2].variables_seen, 0);
+        assert_eq!(coverage.languages["eng"].occurrences, 2);
+        assert_eq!(coverage.languages["fra"].occurrences, 0);
+
+        let restored = GCAMBatchCoverage::from_bytes(&coverage.to_bytes()?)?;
+        assert_eq!(restored, coverage);
+        Ok(())
+    }
+
+    #[test]
+    fn test_batch_coverage_separates_dictionaries_sharing_an_id() -> Result<()> {
+        // The embedded codebook files c41.1-c41.3 under Martindale's DictionaryID 40
+        let db = GCAMCodebookDatabase::embedded();
+        let coverage = GCAMBatchCoverage::from_records(&db, &[record("wc:100,c40.1:2,c41.1:1")])?;
+
+        let martindale = &coverage.dictionaries[&40];
+        assert_eq!(
+            martindale.name,
+            "Martindale & Martindale's Temperament/Elements Dictionary"
+        );
+        assert_eq!(
+            (martindale.codebook_variables, martindale.variables_seen),
+            (8, 1)
+        );
+        let central_bank = &coverage.dictionaries[&41];
+        assert_eq!(
+            central_bank.name,
+            "Central Bank Financial Stability Report Sentiment"
+        );
+        assert_eq!(
+            (central_bank.codebook_variables, central_bank.variables_seen),
+            (3, 1)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_batch_coverage_rejects_other_codebook() -> Result<()> {
+        let db = GCAMCodebookDatabase::from_contents(CODEBOOK, "test")?;
+        let other = GCAMCodebookDatabase::from_contents(&CODEBOOK.replace("fra", "spa"), "other")?;
+
+        let mut coverage = GCAMBatchCoverage::new(&db)?;
+        assert!(coverage.add_record(&other, &record("c1.1:1")).is_err());
+        assert_eq!(coverage.records, 0);
+        Ok(())
+    }
+}
@@ -135,10 +135,15 @@ mod tests {
     fn test_database_creation_paths() {
         println!("\n=== Testing Database Creation with Different Paths ===");
 
-        // Test that path parameter is ignored for in-memory database
-        let db1 = GCAMCodebookDatabase::new("./some/path").expect("Failed to create database");
-        let db2 =
-            GCAMCodebookDatabase::new("/tmp/another/path").expect("Failed to create database");
+        // Directories (old sled locations) all give the embedded codebook
+        let dir1 = tempfile::tempdir().expect("Failed to create temp dir");
+        let dir2 = tempfile::tempdir().expect("Failed to create temp dir");
+        let db1 = GCAMCodebookDatabase::new(dir1.path()).expect("Failed to create database");
+        let db2 = GCAMCodebookDatabase::new(dir2.path()).expect("Failed to create database");
+        assert!(
+            GCAMCodebookDatabase::new("/tmp/another/path").is_err(),
+            "Missing paths should be rejected"
+        );
         let db3 = GCAMCodebookDatabase::new_temp().expect("Failed to create temp database");
 
         // All should have the same count since they use the same embedded data
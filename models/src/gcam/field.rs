@@ -0,0 +1,279 @@
+//! Lazily parsed V2GCAM field
+//!
+//! A GKG record's GCAM field can hold thousands of `key:value` pairs, and parsing and
+//! enriching all of them is the most expensive part of reading a record. `GCAMField`
+//! keeps the raw field and only parses it the first time the entries are asked for,
+//! and a `GCAMSelection` limits which dimensions are materialized at all.
+
+use crate::gcam::lookup::{Dictionary, EnrichedGCAMEntry, Language};
+use crate::gcam::memory_database::GCAMCodebookDatabase;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::sync::OnceLock;
+
+/// Which GCAM dimensions to materialize when a field is parsed
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum GCAMSelection {
+    /// Every `key:value` pair in the field
+    #[default]
+    All,
+    /// `wc` and the variables belonging to these dictionaries; other unknown variables
+    /// are dropped. `wc` is always kept since it is the denominator of every density.
+    Dictionaries(Vec<Dictionary>),
+    /// Only these variables, e.g. `wc` and `c9.1`
+    Variables(BTreeSet<String>),
+}
+
+impl GCAMSelection {
+    pub fn dictionaries(dictionaries: impl IntoIterator<Item = Dictionary>) -> Self {
+        GCAMSelection::Dictionaries(dictionaries.into_iter().collect())
+    }
+
+    pub fn variables<S: Into<String>>(variables: impl IntoIterator<Item = S>) -> Self {
+        GCAMSelection::Variables(variables.into_iter().map(Into::into).collect())
+    }
+
+    /// `wc` and every variable the codebook lists for one language
+    pub fn language(codebook: &GCAMCodebookDatabase, language: &Language) -> Self {
+        let mut variables: BTreeSet<String> = codebook
+            .get_by_language(language)
+            .into_iter()
+            .map(|entry| entry.variable.clone())
+            .collect();
+        variables.insert("wc".to_string());
+        GCAMSelection::Variables(variables)
+    }
+}
+
+/// The V2GCAM field of a GKG record, parsed on first access
+#[derive(Clone, Default)]
+pub struct GCAMField {
+    raw: String,
+    codebook: Option<GCAMCodebookDatabase>,
+    selection: GCAMSelection,
+    entries: OnceLock<Vec<EnrichedGCAMEntry>>,
+}
+
+impl GCAMField {
+    /// Wrap a raw V2GCAM field; nothing is parsed until `entries` is called
+    ///
+    /// Without a codebook the entries carry no metadata, and a dictionary selection
+    /// matches nothing but `wc`.
+    pub fn new(
+        raw: impl Into<String>,
+        codebook: Option<&GCAMCodebookDatabase>,
+        selection: GCAMSelection,
+    ) -> Self {
+        Self {
+            raw: raw.into(),
+            codebook: codebook.cloned(),
+            selection,
+            entries: OnceLock::new(),
+        }
+    }
+
+    /// The field as it appeared in the record
+    pub fn raw(&self) -> &str {
+        &self.raw
+    }
+
+    pub fn selection(&self) -> &GCAMSelection {
+        &self.selection
+    }
+
+    /// Change the selection, discarding anything already parsed
+    pub fn select(&mut self, selection: GCAMSelection) {
+        self.selection = selection;
+        self.entries = OnceLock::new();
+    }
+
+    /// True once the entries have been materialized
+    pub fn is_parsed(&self) -> bool {
+        self.entries.get().is_some()
+    }
+
+    /// True if the raw field holds no values, without parsing it
+    pub fn is_empty(&self) -> bool {
+        self.raw.is_empty()
+    }
+
+    /// The selected entries, parsing and enriching them on first call
+    pub fn entries(&self) -> &[EnrichedGCAMEntry] {
+        self.entries
+            .get_or_init(|| parse_gcam(&self.raw, self.codebook.as_ref(), &self.selection))
+    }
+}
+
+impl fmt::Debug for GCAMField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GCAMField")
+            .field("raw_len", &self.raw.len())
+            .field(
+                "codebook",
+                &self
+                    .codebook
+                    .as_ref()
+                    .map(|db| db.version().short().to_string()),
+            )
+            .field("selection", &self.selection)
+            .field("parsed", &self.entries.get().map(Vec::len))
+            .finish()
+    }
+}
+
+impl PartialEq for GCAMField {
+    /// Equal when the same raw field would yield the same entries
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+            && self.selection == other.selection
+            && self.codebook.as_ref().map(GCAMCodebookDatabase::version)
+                == other.codebook.as_ref().map(GCAMCodebookDatabase::version)
+    }
+}
+
+/// Parse `key:value,key:value,...`, keeping only the selected pairs
+pub(crate) fn parse_gcam(
+    s: &str,
+    codebook: Option<&GCAMCodebookDatabase>,
+    selection: &GCAMSelection,
+) -> Vec<EnrichedGCAMEntry> {
+    let mut entries = Vec::new();
+    for entry_str in s.split(',') {
+        let Some((key, value)) = entry_str.split_once(':') else {
+            continue;
+        };
+        if value.contains(':') {
+            continue;
+        }
+        if let GCAMSelection::Variables(variables) = selection
+            && !variables.contains(key)
+        {
+            continue;
+        }
+        let Ok(value) = value.parse::<f32>() else {
+            continue;
+        };
+
+        let metadata = codebook.and_then(|db| db.get_shared(key));
+        if let GCAMSelection::Dictionaries(dictionaries) = selection
+            && key != "wc"
+            && !metadata
+                .as_ref()
+                .is_some_and(|meta| dictionaries.contains(&meta.dictionary))
+        {
+            continue;
+        }
+        entries.push(EnrichedGCAMEntry::new(key.to_string(), value, metadata));
+    }
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    const CODEBOOK: &str = "Variable\tDictionaryID\tDimensionID\tType\tLanguageCode\tDictionaryHumanName\tDimensionHumanName\tDictionaryCitation
+wc\t0\t0\tWORDCOUNT\teng\tWord Count\tWord Count\tn/a
+c1.1\t1\t1\tWORDCOUNT\teng\tForest Values\tAESTHETIC\tBengston & Xu (1995)
+c9.1\t9\t1\tWORDCOUNT\teng\tLinguistic Inquiry and Word Count (LIWC)\tAnxiety\tPennebaker et al. (2001)
+";
+
+    const RAW: &str = "wc:125,c1.1:4,c9.1:2,v9.9:0.5,bad,c1.2:x";
+
+    #[test]
+    fn test_gcam_field_parses_on_first_access() -> Result<()> {
+        let db = GCAMCodebookDatabase::from_contents(CODEBOOK, "test")?;
+        let field = GCAMField::new(RAW, Some(&db), GCAMSelection::All);
+
+        assert!(!field.is_parsed());
+        assert!(!field.is_empty());
+        assert_eq!(field.raw(), RAW);
+
+        let keys: Vec<&str> = field.entries().iter().map(|e| e.key.as_str()).collect();
+        assert_eq!(keys, vec!["wc", "c1.1", "c9.1", "v9.9"]);
+        assert!(field.is_parsed());
+        assert_eq!(field.entries()[1].dimension_name(), Some("AESTHETIC"));
+        assert!(field.entries()[3].metadata.is_none());
+
+        assert!(
+            GCAMField::new("", None, GCAMSelection::All)
+                .entries()
+                .is_empty()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_gcam_field_selection() -> Result<()> {
+        let db = GCAMCodebookDatabase::from_contents(CODEBOOK, "test")?;
+
+        let liwc = GCAMField::new(
+            RAW,
+            Some(&db),
+            GCAMSelection::dictionaries([Dictionary::new(
+                "Linguistic Inquiry and Word Count (LIWC)",
+            )]),
+        );
+        let keys: Vec<&str> = liwc.entries().iter().map(|e| e.key.as_str()).collect();
+        assert_eq!(keys, vec!["wc", "c9.1"]);
+
+        let mut field = GCAMField::new(RAW, Some(&db), GCAMSelection::variables(["wc", "v9.9"]));
+        let keys: Vec<&str> = field.entries().iter().map(|e| e.key.as_str()).collect();
+        assert_eq!(keys, vec!["wc", "v9.9"]);
+
+        field.select(GCAMSelection::All);
+        assert!(!field.is_parsed());
+        assert_eq!(field.entries().len(), 4);
+
+        // Without a codebook nothing can be matched to a dictionary
+        let unenriched = GCAMField::new(
+            RAW,
+            None,
+            GCAMSelection::dictionaries([Dictionary::new("Forest Values")]),
+        );
+        let keys: Vec<&str> = unenriched
+            .entries()
+            .iter()
+            .map(|e| e.key.as_str())
+            .collect();
+        assert_eq!(keys, vec!["wc"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_dictionary_selection_keeps_word_count() -> Result<()> {
+        use crate::types::gkg_table::GKGTable;
+        use csv::StringRecord;
+
+        // The embedded codebook has no `wc` row
+        let db = GCAMCodebookDatabase::embedded();
+        assert!(db.get_shared("wc").is_none());
+
+        let mut fields = vec![""; 27];
+        fields[0] = "20240501090000-1";
+        fields[1] = "20240501090000";
+        fields[2] = "1";
+        fields[3] = "example.com";
+        fields[4] = "https://example.com/article";
+        fields[15] = "0,0,0,0,0,0,0";
+        fields[17] = "wc:200,c1.1:3,c5.1:10,c6.1:4";
+        let record = GKGTable::try_from_with_gcam_selection(
+            StringRecord::from(fields),
+            Some(&db),
+            GCAMSelection::dictionaries([
+                Dictionary::new("Linguistic Inquiry and Word Count (LIWC)"),
+                Dictionary::new("Loughran and McDonald Financial Sentiment Dictionaries"),
+            ]),
+        )?;
+
+        let keys: Vec<&str> = record.gcam().iter().map(|e| e.key.as_str()).collect();
+        assert_eq!(keys, vec!["wc", "c5.1", "c6.1"]);
+        let analysis = record.gcam_analysis();
+        assert_eq!(analysis.word_count, Some(200.0));
+        assert_eq!(analysis.density(5, 1), Some(0.05));
+        assert_eq!(analysis.density(6, 1), Some(0.02));
+        assert_eq!(analysis.density(1, 1), None);
+        Ok(())
+    }
+}
@@ -1,18 +1,24 @@
+use crate::gcam::analysis::GCAMVariable;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::fmt;
+use std::sync::Arc;
 
 /// Language codes used in GCAM data
+///
+/// German and Chinese use the ISO 639-2 terminology codes the codebook spells them with
+/// (`deu`, `zho`); the bibliographic codes (`ger`, `chi`) parse as the same languages.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Language {
     #[serde(rename = "eng")]
     English,
     #[serde(rename = "ara")]
     Arabic,
-    #[serde(rename = "chi")]
+    #[serde(rename = "zho", alias = "chi")]
     Chinese,
     #[serde(rename = "fra")]
     French,
-    #[serde(rename = "ger")]
+    #[serde(rename = "deu", alias = "ger")]
     German,
     #[serde(rename = "hin")]
     Hindi,
@@ -32,9 +38,9 @@ impl fmt::Display for Language {
         match self {
             Language::English => write!(f, "eng"),
             Language::Arabic => write!(f, "ara"),
-            Language::Chinese => write!(f, "chi"),
+            Language::Chinese => write!(f, "zho"),
             Language::French => write!(f, "fra"),
-            Language::German => write!(f, "ger"),
+            Language::German => write!(f, "deu"),
             Language::Hindi => write!(f, "hin"),
             Language::Japanese => write!(f, "jpn"),
             Language::Portuguese => write!(f, "por"),
@@ -50,9 +56,9 @@ impl From<&str> for Language {
         match s {
             "eng" => Language::English,
             "ara" => Language::Arabic,
-            "chi" => Language::Chinese,
+            "zho" | "chi" => Language::Chinese,
             "fra" => Language::French,
-            "ger" => Language::German,
+            "deu" | "ger" => Language::German,
             "hin" => Language::Hindi,
             "jpn" => Language::Japanese,
             "por" => Language::Portuguese,
@@ -63,158 +69,114 @@ impl From<&str> for Language {
     }
 }
 
-/// Dictionary names from the GCAM Master Codebook
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub enum Dictionary {
-    ForestValues,
-    GdeltGlobalKnowledgeGraphThemes,
-    GdeltGkgThemes,
-    GeneralInquirerV102,
-    LexicoderSentimentDictionary,
-    LexicoderTopicDictionaries,
-    LinguisticInquiryAndWordCount,
-    LoughranAndMcDonaldFinancialSentiment,
-    OpinionObserver,
-    RegressiveImageryDictionary,
-    RogetsThesaurus1911Edition,
-    SentiWordNet30,
-    SentiWords,
-    SubjectivityLexicon,
-    BodyBoundaryDictionary,
-    WordNetAffect10,
-    WordNetAffect11,
-    WordNetDomains32,
-    WordNet31LexicalCategories,
-    /// Catch-all for any other dictionary not explicitly defined
-    Other(String),
-}
+/// A GCAM dictionary, identified by its name in the codebook's DictionaryHumanName column
+///
+/// Any name the codebook uses is a dictionary; what is known about each one (its IDs,
+/// citation and languages) lives in the codebook's `DictionaryRegistry`.
+///
+/// This used to be an enum with one variant per known dictionary. It now serializes as
+/// the codebook name (`#[serde(transparent)]`) rather than as an enum, so
+/// `GCAMCodebookEntry` and `EnrichedGCAMEntry` values serialized before the change
+/// (variant index in bincode, variant name in JSON) don't read back as the same
+/// dictionary and have to be re-encoded from the codebook.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Dictionary(Cow<'static, str>);
 
-impl fmt::Display for Dictionary {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Dictionary::ForestValues => write!(f, "Forest Values"),
-            Dictionary::GdeltGlobalKnowledgeGraphThemes => {
-                write!(f, "GDELT Global Knowledge Graph Themes")
-            }
-            Dictionary::GdeltGkgThemes => write!(f, "GDELT GKG Themes"),
-            Dictionary::GeneralInquirerV102 => write!(
-                f,
-                "General Inquirer V1.02 (Harvard IV-4 Psychosocial Dictionary / NamenWirth & Weber's Lasswell Dictionary)"
-            ),
-            Dictionary::LexicoderSentimentDictionary => write!(f, "Lexicoder Sentiment Dictionary"),
-            Dictionary::LexicoderTopicDictionaries => write!(f, "Lexicoder Topic Dictionaries"),
-            Dictionary::LinguisticInquiryAndWordCount => {
-                write!(f, "Linguistic Inquiry and Word Count (LIWC)")
-            }
-            Dictionary::LoughranAndMcDonaldFinancialSentiment => {
-                write!(f, "Loughran and McDonald Financial Sentiment Dictionaries")
-            }
-            Dictionary::OpinionObserver => write!(f, "Opinion Observer"),
-            Dictionary::RegressiveImageryDictionary => write!(f, "Regressive Imagery Dictionary"),
-            Dictionary::RogetsThesaurus1911Edition => write!(f, "Roget's Thesaurus 1911 Edition"),
-            Dictionary::SentiWordNet30 => write!(f, "SentiWordNet 3.0"),
-            Dictionary::SentiWords => write!(f, "SentiWords"),
-            Dictionary::SubjectivityLexicon => write!(f, "Subjectivity Lexicon"),
-            Dictionary::BodyBoundaryDictionary => write!(f, "Body Boundary Dictionary"),
-            Dictionary::WordNetAffect10 => write!(f, "WordNet Affect 1.0"),
-            Dictionary::WordNetAffect11 => write!(f, "WordNet Affect 1.1"),
-            Dictionary::WordNetDomains32 => write!(f, "WordNet Domains 3.2"),
-            Dictionary::WordNet31LexicalCategories => write!(f, "WordNet 3.1 Lexical Categories"),
-            Dictionary::Other(name) => write!(f, "{}", name),
-        }
+impl Dictionary {
+    pub fn new(name: impl Into<String>) -> Self {
+        Dictionary(Cow::Owned(name.into()))
+    }
+
+    /// The dictionary as named in a codebook row, surrounding whitespace ignored
+    pub fn from_human_name(name: &str) -> Self {
+        Dictionary::new(name.trim())
+    }
+
+    pub fn name(&self) -> &str {
+        &self.0
     }
 }
 
+// The old enum variants, kept so existing code keeps compiling
+#[allow(non_upper_case_globals)]
 impl Dictionary {
-    pub fn from_human_name(name: &str) -> Self {
-        match name {
-            "Forest Values" => Dictionary::ForestValues,
-            "GDELT Global Knowledge Graph Themes" => Dictionary::GdeltGlobalKnowledgeGraphThemes,
-            "GDELT GKG Themes" => Dictionary::GdeltGkgThemes,
-            name if name.starts_with("General Inquirer V1.02") => Dictionary::GeneralInquirerV102,
-            "Lexicoder Sentiment Dictionary" => Dictionary::LexicoderSentimentDictionary,
-            "Lexicoder Topic Dictionaries" => Dictionary::LexicoderTopicDictionaries,
-            "Linguistic Inquiry and Word Count (LIWC)" => Dictionary::LinguisticInquiryAndWordCount,
-            "Loughran and McDonald Financial Sentiment Dictionaries" => {
-                Dictionary::LoughranAndMcDonaldFinancialSentiment
-            }
-            "Opinion Observer" => Dictionary::OpinionObserver,
-            "Regressive Imagery Dictionary" => Dictionary::RegressiveImageryDictionary,
-            "Roget's Thesaurus 1911 Edition" => Dictionary::RogetsThesaurus1911Edition,
-            "SentiWordNet 3.0" => Dictionary::SentiWordNet30,
-            "SentiWords" => Dictionary::SentiWords,
-            "Subjectivity Lexicon" => Dictionary::SubjectivityLexicon,
-            "Body Boundary Dictionary" => Dictionary::BodyBoundaryDictionary,
-            "WordNet Affect 1.0" => Dictionary::WordNetAffect10,
-            "WordNet Affect 1.1" => Dictionary::WordNetAffect11,
-            "WordNet Domains 3.2" => Dictionary::WordNetDomains32,
-            "WordNet 3.1 Lexical Categories" => Dictionary::WordNet31LexicalCategories,
-            // Handle all other dictionaries without warning
-            _ => Dictionary::Other(name.to_string()),
-        }
+    #[deprecated(note = "dictionaries are named by the codebook; use `Dictionary::new`")]
+    pub const ForestValues: Dictionary = Dictionary(Cow::Borrowed("Forest Values"));
+    #[deprecated(note = "dictionaries are named by the codebook; use `Dictionary::new`")]
+    pub const GdeltGlobalKnowledgeGraphThemes: Dictionary =
+        Dictionary(Cow::Borrowed("GDELT Global Knowledge Graph Themes"));
+    #[deprecated(note = "dictionaries are named by the codebook; use `Dictionary::new`")]
+    pub const GdeltGkgThemes: Dictionary = Dictionary(Cow::Borrowed("GDELT GKG Themes"));
+    #[deprecated(note = "dictionaries are named by the codebook; use `Dictionary::new`")]
+    pub const GeneralInquirerV102: Dictionary = Dictionary(Cow::Borrowed(
+        "General Inquirer V1.02 (IV-4 Harvard Psychosocial Dictionary / NamenWirth & Weber's Lasswell Dictionary)",
+    ));
+    #[deprecated(note = "dictionaries are named by the codebook; use `Dictionary::new`")]
+    pub const LexicoderSentimentDictionary: Dictionary =
+        Dictionary(Cow::Borrowed("Lexicoder Sentiment Dictionary"));
+    #[deprecated(note = "dictionaries are named by the codebook; use `Dictionary::new`")]
+    pub const LexicoderTopicDictionaries: Dictionary =
+        Dictionary(Cow::Borrowed("Lexicoder Topic Dictionaries"));
+    #[deprecated(note = "dictionaries are named by the codebook; use `Dictionary::new`")]
+    pub const LinguisticInquiryAndWordCount: Dictionary =
+        Dictionary(Cow::Borrowed("Linguistic Inquiry and Word Count (LIWC)"));
+    #[deprecated(note = "dictionaries are named by the codebook; use `Dictionary::new`")]
+    pub const LoughranAndMcDonaldFinancialSentiment: Dictionary = Dictionary(Cow::Borrowed(
+        "Loughran and McDonald Financial Sentiment Dictionaries",
+    ));
+    #[deprecated(note = "dictionaries are named by the codebook; use `Dictionary::new`")]
+    pub const OpinionObserver: Dictionary = Dictionary(Cow::Borrowed("Opinion Observer"));
+    #[deprecated(note = "dictionaries are named by the codebook; use `Dictionary::new`")]
+    pub const RegressiveImageryDictionary: Dictionary =
+        Dictionary(Cow::Borrowed("Regressive Imagery Dictionary"));
+    #[deprecated(note = "dictionaries are named by the codebook; use `Dictionary::new`")]
+    pub const RogetsThesaurus1911Edition: Dictionary =
+        Dictionary(Cow::Borrowed("Roget's Thesaurus 1911 Edition"));
+    #[deprecated(note = "dictionaries are named by the codebook; use `Dictionary::new`")]
+    pub const SentiWordNet30: Dictionary = Dictionary(Cow::Borrowed("SentiWordNet 3.0"));
+    #[deprecated(note = "dictionaries are named by the codebook; use `Dictionary::new`")]
+    pub const SentiWords: Dictionary = Dictionary(Cow::Borrowed("SentiWords"));
+    #[deprecated(note = "dictionaries are named by the codebook; use `Dictionary::new`")]
+    pub const SubjectivityLexicon: Dictionary = Dictionary(Cow::Borrowed("Subjectivity Lexicon"));
+    #[deprecated(note = "dictionaries are named by the codebook; use `Dictionary::new`")]
+    pub const BodyBoundaryDictionary: Dictionary =
+        Dictionary(Cow::Borrowed("Body Boundary Dictionary"));
+    #[deprecated(note = "dictionaries are named by the codebook; use `Dictionary::new`")]
+    pub const WordNetAffect10: Dictionary = Dictionary(Cow::Borrowed("WordNet Affect 1.0"));
+    #[deprecated(note = "dictionaries are named by the codebook; use `Dictionary::new`")]
+    pub const WordNetAffect11: Dictionary = Dictionary(Cow::Borrowed("WordNet Affect 1.1"));
+    #[deprecated(note = "dictionaries are named by the codebook; use `Dictionary::new`")]
+    pub const WordNetDomains32: Dictionary = Dictionary(Cow::Borrowed("WordNet Domains 3.2"));
+    #[deprecated(note = "dictionaries are named by the codebook; use `Dictionary::new`")]
+    pub const WordNet31LexicalCategories: Dictionary =
+        Dictionary(Cow::Borrowed("WordNet 3.1 Lexical Categories"));
+
+    /// Was the catch-all variant; any name is now a dictionary
+    #[deprecated(note = "use `Dictionary::new`")]
+    #[allow(non_snake_case)]
+    pub fn Other(name: String) -> Dictionary {
+        Dictionary::new(name)
     }
 
+    /// Citation from the embedded codebook
+    #[deprecated(note = "use `DictionaryRegistry::citation` of the codebook in use")]
     pub fn get_citation(&self) -> &'static str {
-        match self {
-            Dictionary::ForestValues => {
-                "Bengston, D, & Xu, Z. (1995). Changing national forest values: A content analysis. St. Paul, Minn.: North Central Forest Experiment Station, Forest Service, U.S. Dept. of Agriculture."
-            }
-            Dictionary::GdeltGlobalKnowledgeGraphThemes => {
-                "Kalev Hannes Leetaru. (2013). 'The GDELT Global Knowledge Graph (GKG)'. Available http://gdeltproject.org/"
-            }
-            Dictionary::GdeltGkgThemes => {
-                "Kalev Hannes Leetaru. (2013). 'The GDELT Global Knowledge Graph (GKG)'. Available http://gdeltproject.org/"
-            }
-            Dictionary::GeneralInquirerV102 => {
-                "Philip J. Stone, Robert F. Bales, Zvi Namenwirth, & Daniel M. Ogilvie (1962). The General Inquirer: A computer system for content analysis and retrieval based on the sentence as a unit of information. Behavioral Science, 7(4), 484-498"
-            }
-            Dictionary::LexicoderSentimentDictionary => {
-                "Lori Young and Stuart Soroka. 2012. Affective News: The Automated Coding of Sentiment in Political Texts, Political Communication 29: 205-231. Available at http://lexicoder.com/"
-            }
-            Dictionary::LexicoderTopicDictionaries => {
-                "Albugh, Quinn, Julie Sevenans and Stuart Soroka. 2013. Lexicoder Topic Dictionaries, June 2013 versions, McGill University, Montreal, Canada. Available at http://lexicoder.com/"
-            }
-            Dictionary::LinguisticInquiryAndWordCount => {
-                "Pennebaker, J. W., Booth, R. J., & Francis, M. E. (2007). Linguistic Inquiry and Word Count: LIWC [Computer software]. Austin, TX. Available at http://www.liwc.net/"
-            }
-            Dictionary::LoughranAndMcDonaldFinancialSentiment => {
-                "Tim Loughran and Bill McDonald, 2011, \"When is a Liability not a Liability,\" Journal of Finance, V66, pp. 35-65."
-            }
-            Dictionary::OpinionObserver => {
-                "Bing Liu, Minqing Hu and Junsheng Cheng. \"Opinion Observer: Analyzing and Comparing Opinions on the Web.\" Proceedings of the 14th International World Wide Web conference (WWW-2005), May 10-14, 2005, Chiba, Japan."
-            }
-            Dictionary::RegressiveImageryDictionary => {
-                "Martindale C. (1987). Narrative pattern analysis: A quantitative method for inferring the symbolic meaning of narratives. In Literary discourse: Aspects of cognitive and social psychological approaches Halasz L. (ed) pp167–181, Berlin: de Gruyter"
-            }
-            Dictionary::RogetsThesaurus1911Edition => {
-                "Peter Mark Roget. (1911). Roget's Thesaurus of English Words and Phrases. New York: TY Crowell Company."
-            }
-            Dictionary::SentiWordNet30 => {
-                "Andrea Esuli Stefano Baccianella and Fabrizio Sebastiani. (2010). Sentiwordnet 3.0: An enhanced lexical resource for sentiment analysis and opinion mining. In LREC."
-            }
-            Dictionary::SentiWords => {
-                "Guerini M., Gatti L. & Turchi M. \"Sentiment Analysis: How to Derive Prior Polarities from SentiWordNet\". In Proceedings of the 2013 Conference on Empirical Methods in Natural Language Processing (EMNLP'13), pp 1259-1269. Seattle, Washington, USA. 2013."
-            }
-            Dictionary::SubjectivityLexicon => {
-                "Theresa Wilson, Janyce Wiebe, and Paul Hoffmann (2005). Recognizing Contextual Polarity in Phrase-Level Sentiment Analysis. Proc. of HLT-EMNLP-2005."
-            }
-            Dictionary::BodyBoundaryDictionary => {
-                "Andrew Wilson. (2006). Development and application of a content analysis dictionary for body boundary research. Literary and Linguistic Computing, 21, 105-110."
-            }
-            Dictionary::WordNetAffect10 => {
-                "Carlo Strapparava and Alessandro Valitutti. \"WordNet-Affect: an Affective Extension of WordNet\", in Proceedings of the 4th International Conference on Language Resources and Evaluation (LREC 2004), Lisbon, May 2004, pp. 1083-1086."
-            }
-            Dictionary::WordNetAffect11 => {
-                "Carlo Strapparava and Alessandro Valitutti. \"WordNet-Affect: an Affective Extension of WordNet\", in Proceedings of the 4th International Conference on Language Resources and Evaluation (LREC 2004), Lisbon, May 2004, pp. 1083-1086."
-            }
-            Dictionary::WordNetDomains32 => {
-                "Bernardo Magnini and Gabriela Cavaglià. \"Integrating Subject Field Codes into WordNet\". In Gavrilidou M., Crayannis G., Markantonatu S., Piperidis S. and Stainhaouer G. (Eds.) Proceedings of LREC-2000, Second International Conference on Language Resources and Evaluation, Athens, Greece, 31 May – 2 June, 2000, pp. 1413-1418."
-            }
-            Dictionary::WordNet31LexicalCategories => {
-                "George A. Miller (1995). WordNet: A Lexical Database for English. Communications of the ACM Vol. 38, No. 11: 39-41."
-            }
-            Dictionary::Other(_) => "Citation not available for this dictionary.",
-        }
+        crate::gcam::memory_database::embedded_registry()
+            .citation(self)
+            .unwrap_or("Citation not available for this dictionary.")
+    }
+}
+
+impl fmt::Display for Dictionary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<&str> for Dictionary {
+    fn from(name: &str) -> Self {
+        Dictionary::from_human_name(name)
     }
 }
 
@@ -227,6 +189,9 @@ pub enum MeasurementType {
     Ratio,
     #[serde(rename = "SCORE")]
     Score,
+    /// Average dictionary score of the matched words (`vX.Y` variables)
+    #[serde(rename = "SCOREDVALUE")]
+    ScoredValue,
     Other(String),
 }
 
@@ -236,6 +201,7 @@ impl fmt::Display for MeasurementType {
             MeasurementType::WordCount => write!(f, "WORDCOUNT"),
             MeasurementType::Ratio => write!(f, "RATIO"),
             MeasurementType::Score => write!(f, "SCORE"),
+            MeasurementType::ScoredValue => write!(f, "SCOREDVALUE"),
             MeasurementType::Other(t) => write!(f, "{}", t),
         }
     }
@@ -247,6 +213,7 @@ impl From<&str> for MeasurementType {
             "WORDCOUNT" => MeasurementType::WordCount,
             "RATIO" => MeasurementType::Ratio,
             "SCORE" => MeasurementType::Score,
+            "SCOREDVALUE" => MeasurementType::ScoredValue,
             _ => MeasurementType::Other(s.to_string()),
         }
     }
@@ -274,6 +241,7 @@ pub struct GCAMCodebookEntry {
 }
 
 impl GCAMCodebookEntry {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         variable: String,
         dictionary_id: u32,
@@ -295,6 +263,19 @@ impl GCAMCodebookEntry {
             citation,
         }
     }
+
+    /// The `(dictionary, dimension)` pair the variable name encodes, e.g. `(41, 2)` for
+    /// `c41.2`, falling back to the DictionaryID and DimensionID columns for names
+    /// without one.
+    ///
+    /// The columns aren't always right: the shipped codebook files `c41.1`-`c41.3`
+    /// (Central Bank Financial Stability Report Sentiment) under DictionaryID 40, which
+    /// is Martindale's. GDELT records use the variable names, so those win.
+    pub fn dimension_key(&self) -> (u32, u32) {
+        GCAMVariable::parse(&self.variable)
+            .and_then(|variable| variable.dimension())
+            .unwrap_or((self.dictionary_id, self.dimension_id))
+    }
 }
 
 /// Enhanced GCAM entry that combines the original key-value with enriched metadata
@@ -304,12 +285,13 @@ pub struct EnrichedGCAMEntry {
     pub key: String,
     /// Original GCAM value
     pub value: f32,
-    /// Enriched metadata from codebook (if available)
-    pub metadata: Option<GCAMCodebookEntry>,
+    /// Enriched metadata from codebook (if available), shared with the codebook
+    /// rather than copied into every entry
+    pub metadata: Option<Arc<GCAMCodebookEntry>>,
 }
 
 impl EnrichedGCAMEntry {
-    pub fn new(key: String, value: f32, metadata: Option<GCAMCodebookEntry>) -> Self {
+    pub fn new(key: String, value: f32, metadata: Option<Arc<GCAMCodebookEntry>>) -> Self {
         Self {
             key,
             value,
@@ -1,13 +1,38 @@
 //! In-Memory GCAM Database Implementation
 //!
-//! This module provides a compile-time generated, in-memory GCAM database
-//! that replaces the previous sled-based implementation. The database is
-//! populated at compile time from the GCAM CSV file using macros.
+//! This module provides an in-memory GCAM codebook database that replaces the
+//! previous sled-based implementation. Each `GCAMCodebookDatabase` is an
+//! instance over one codebook: either the copy of `GCAM-MASTER-CODEBOOK-fixed.csv`
+//! embedded at compile time (the default), or any codebook file loaded at runtime.
+//!
+//! ## Versions
+//!
+//! Codebooks are identified by a `CodebookVersion`, the SHA-256 of the codebook
+//! file exactly as it was read, so it matches `sha256sum` on the same file. Entries
+//! added afterwards with `insert_entry` fold into the hash, so an edited codebook
+//! never reports the version of the file it started from. Several versions can be
+//! loaded side by side; databases created from the same source share their data and
+//! only copy it when one of them is modified.
+//!
+//! ```rust,no_run
+//! use models::gcam::GCAMCodebookDatabase;
+//!
+//! # fn main() -> anyhow::Result<()> {
+//! let current = GCAMCodebookDatabase::new_temp()?;
+//! let previous = GCAMCodebookDatabase::from_path("GCAM-MASTER-CODEBOOK-2015.csv")?;
+//! println!("{} vs {}", current.version(), previous.version());
+//!
+//! // Refuse to run an analysis against anything but the pinned codebook
+//! previous.require_version("3f2a9c0d1e7b")?;
+//! # Ok(())
+//! # }
+//! ```
 //!
 //! ## Testing
 //!
-//! Database tests are ignored by default to improve test performance since they
-//! require loading the embedded CSV data. To run them explicitly:
+//! Database tests that need the embedded codebook are ignored by default to improve
+//! test performance since they require loading the embedded CSV data. To run them
+//! explicitly:
 //!
 //! ```bash
 //! # Run only database tests (ignored by default)
@@ -23,141 +48,380 @@
 use crate::gcam::lookup::{
     Dictionary, EnrichedGCAMEntry, GCAMCodebookEntry, Language, MeasurementType,
 };
-use anyhow::Result;
-use std::collections::BTreeMap;
-use std::sync::OnceLock;
-
-/// Macro to generate the in-memory GCAM database from CSV data
-macro_rules! generate_gcam_database {
-    ($csv_content:expr) => {{
-        let mut variable_map: BTreeMap<String, GCAMCodebookEntry> = BTreeMap::new();
-        let mut dictionary_map: BTreeMap<u32, GCAMCodebookEntry> = BTreeMap::new();
-
-        let mut lines = $csv_content.lines();
-
-        // Skip header line
-        if let Some(_header) = lines.next() {
-            for line in lines {
-                if line.trim().is_empty() {
-                    continue;
-                }
+use crate::gcam::registry::DictionaryRegistry;
+use anyhow::{Context, Result, anyhow};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::path::Path;
+use std::sync::{Arc, OnceLock, RwLock, RwLockReadGuard};
+
+/// The codebook compiled into the crate
+const EMBEDDED_CODEBOOK: &str = include_str!("../../../GCAM-MASTER-CODEBOOK-fixed.csv");
+
+/// SHA-256 content hash identifying a codebook, as lowercase hex
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct CodebookVersion(pub String);
+
+impl CodebookVersion {
+    /// Hash the raw bytes of a codebook file
+    pub fn of(content: &[u8]) -> Self {
+        CodebookVersion(to_hex(&Sha256::digest(content)))
+    }
 
-                let fields: Vec<&str> = line.split('\t').collect();
-                if fields.len() < 8 {
-                    continue; // Skip malformed lines
-                }
+    pub fn hash(&self) -> &str {
+        &self.0
+    }
+
+    /// The first 12 hex digits, enough to tell versions apart in logs and reports
+    pub fn short(&self) -> &str {
+        &self.0[..self.0.len().min(12)]
+    }
+
+    /// True if `expected` is this full hash or a prefix of it (at least 8 digits)
+    pub fn matches(&self, expected: &str) -> bool {
+        let expected = expected.trim().to_lowercase();
+        expected.len() >= 8 && self.0.starts_with(&expected)
+    }
+
+    /// The version after `entry` has been inserted into this codebook
+    fn with_entry(&self, entry: &GCAMCodebookEntry) -> Self {
+        let mut hasher = Sha256::new();
+        hasher.update(self.0.as_bytes());
+        hasher.update(b"\n");
+        hasher.update(
+            format!(
+                "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+                entry.variable,
+                entry.dictionary_id,
+                entry.dimension_id,
+                entry.measurement_type,
+                entry.language,
+                entry.dictionary,
+                entry.dimension_name,
+                entry.citation
+            )
+            .as_bytes(),
+        );
+        CodebookVersion(to_hex(&hasher.finalize()))
+    }
+}
+
+impl fmt::Display for CodebookVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Parse the tab-separated codebook format (header line, then one variable per line)
+fn parse_codebook(content: &str) -> Vec<GCAMCodebookEntry> {
+    let mut entries = Vec::new();
+    let mut lines = content.lines();
+
+    // Skip header line
+    if let Some(_header) = lines.next() {
+        for line in lines {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split('\t').collect();
+            if fields.len() < 8 {
+                continue; // Skip malformed lines
+            }
+
+            let variable = fields[0].trim_matches('"').to_string();
+            let dictionary_id: u32 = match fields[1].trim_matches('"').parse() {
+                Ok(id) => id,
+                Err(_) => continue, // Skip invalid dictionary IDs
+            };
+            // Default to 0 if parsing fails
+            let dimension_id: u32 = fields[2].trim_matches('"').parse().unwrap_or(0);
+            let measurement_type = MeasurementType::from(fields[3].trim_matches('"'));
+            let language = Language::from(fields[4].trim_matches('"'));
+            let dictionary = Dictionary::from_human_name(fields[5].trim_matches('"'));
+            let dimension_name = fields[6].trim_matches('"').to_string();
+            let citation = fields[7].trim_matches('"').to_string();
+
+            entries.push(GCAMCodebookEntry {
+                variable,
+                dictionary_id,
+                dimension_id,
+                measurement_type,
+                language,
+                dictionary,
+                dimension_name,
+                citation,
+            });
+        }
+    }
+
+    entries
+}
+
+/// One loaded codebook, shared between databases until one of them modifies it
+///
+/// A GCAM dimension is identified by the `(dictionary, dimension)` pair its variable
+/// names carry (see `GCAMCodebookEntry::dimension_key`); most have one word-count
+/// variable (`cX.Y`), and some also a scored value (`vX.Y`). Every index points at the
+/// same shared entries.
+#[derive(Debug, Clone)]
+struct Codebook {
+    version: CodebookVersion,
+    /// Where the codebook came from, for diagnostics
+    source: String,
+    variable_map: BTreeMap<String, Arc<GCAMCodebookEntry>>,
+    /// Variables of each dimension, ordered by variable name
+    dimension_index: BTreeMap<(u32, u32), Vec<Arc<GCAMCodebookEntry>>>,
+    /// Dictionary IDs carrying each dictionary name
+    dictionary_index: HashMap<Dictionary, BTreeSet<u32>>,
+    /// Dictionary IDs with dimensions in each language
+    language_index: HashMap<Language, BTreeSet<u32>>,
+}
+
+impl Codebook {
+    /// Parse a codebook file; bytes that are not UTF-8 are replaced rather than rejected,
+    /// so GDELT's original codebook loads as well as the cleaned copy
+    fn parse(content: &[u8], source: String) -> Self {
+        let mut codebook = Codebook {
+            version: CodebookVersion::of(content),
+            source,
+            variable_map: BTreeMap::new(),
+            dimension_index: BTreeMap::new(),
+            dictionary_index: HashMap::new(),
+            language_index: HashMap::new(),
+        };
+        for entry in parse_codebook(&String::from_utf8_lossy(content)) {
+            codebook.add(entry);
+        }
+        codebook
+    }
 
-                // Parse CSV fields - adjust indices based on actual CSV structure
-                let variable = fields[0].trim_matches('"').to_string();
-                let dictionary_id: u32 = match fields[1].trim_matches('"').parse() {
-                    Ok(id) => id,
-                    Err(_) => continue, // Skip invalid dictionary IDs
-                };
-                let dimension_id: u32 = match fields[2].trim_matches('"').parse() {
-                    Ok(id) => id,
-                    Err(_) => 0, // Default to 0 if parsing fails
-                };
-                let measurement_type = MeasurementType::from(fields[3].trim_matches('"'));
-                let language = Language::from(fields[4].trim_matches('"'));
-                let dictionary_name = fields[5].trim_matches('"');
-                let dimension_name = fields[6].trim_matches('"').to_string();
-                let citation = fields[7].trim_matches('"').to_string();
-
-                let dictionary = Dictionary::from_human_name(dictionary_name);
-
-                let entry = GCAMCodebookEntry {
-                    variable: variable.clone(),
-                    dictionary_id,
-                    dimension_id,
-                    measurement_type,
-                    language,
-                    dictionary,
-                    dimension_name,
-                    citation,
-                };
-
-                variable_map.insert(variable, entry.clone());
-                dictionary_map.insert(dictionary_id, entry);
+    fn add(&mut self, entry: GCAMCodebookEntry) {
+        let entry = Arc::new(entry);
+        if let Some(previous) = self
+            .variable_map
+            .insert(entry.variable.clone(), entry.clone())
+        {
+            let key = previous.dimension_key();
+            if let Some(variables) = self.dimension_index.get_mut(&key) {
+                variables.retain(|existing| existing.variable != previous.variable);
+                if variables.is_empty() {
+                    self.dimension_index.remove(&key);
+                }
             }
         }
 
-        (variable_map, dictionary_map)
-    }};
+        let (dictionary_id, dimension_id) = entry.dimension_key();
+        let variables = self
+            .dimension_index
+            .entry((dictionary_id, dimension_id))
+            .or_default();
+        let position = variables.partition_point(|existing| existing.variable < entry.variable);
+        variables.insert(position, entry.clone());
+
+        // Stale IDs left behind by replaced entries are filtered out when querying
+        self.dictionary_index
+            .entry(entry.dictionary.clone())
+            .or_default()
+            .insert(dictionary_id);
+        self.language_index
+            .entry(entry.language.clone())
+            .or_default()
+            .insert(dictionary_id);
+    }
+
+    /// Every variable of a dictionary ID, by dimension
+    fn dictionary_entries(
+        &self,
+        dictionary_id: u32,
+    ) -> impl Iterator<Item = &Arc<GCAMCodebookEntry>> {
+        self.dimension_index
+            .range((dictionary_id, 0)..=(dictionary_id, u32::MAX))
+            .flat_map(|(_, variables)| variables)
+    }
+
+    fn entries_for_ids<'a>(
+        &'a self,
+        ids: Option<&'a BTreeSet<u32>>,
+    ) -> impl Iterator<Item = &'a Arc<GCAMCodebookEntry>> {
+        ids.into_iter()
+            .flatten()
+            .flat_map(|&dictionary_id| self.dictionary_entries(dictionary_id))
+    }
+}
+
+/// Dictionaries of the embedded codebook, for lookups that have no database at hand
+pub(crate) fn embedded_registry() -> &'static DictionaryRegistry {
+    static REGISTRY: OnceLock<DictionaryRegistry> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        DictionaryRegistry::from_entries(embedded_codebook().variable_map.values().map(Arc::as_ref))
+    })
 }
 
-/// Global static database instances
-static VARIABLE_MAP: OnceLock<BTreeMap<String, GCAMCodebookEntry>> = OnceLock::new();
-static DICTIONARY_MAP: OnceLock<BTreeMap<u32, GCAMCodebookEntry>> = OnceLock::new();
-
-/// Initialize the global database maps
-fn init_database() -> (
-    &'static BTreeMap<String, GCAMCodebookEntry>,
-    &'static BTreeMap<u32, GCAMCodebookEntry>,
-) {
-    let variable_map = VARIABLE_MAP.get_or_init(|| {
-        let csv_content = include_str!("../../../GCAM-MASTER-CODEBOOK-fixed.csv");
-        let (var_map, _) = generate_gcam_database!(csv_content);
-        var_map
-    });
-
-    let dictionary_map = DICTIONARY_MAP.get_or_init(|| {
-        let csv_content = include_str!("../../../GCAM-MASTER-CODEBOOK-fixed.csv");
-        let (_, dict_map) = generate_gcam_database!(csv_content);
-        dict_map
-    });
-
-    (variable_map, dictionary_map)
+/// The embedded codebook, parsed once and shared by every database that uses it
+static EMBEDDED: OnceLock<Arc<Codebook>> = OnceLock::new();
+
+fn embedded_codebook() -> Arc<Codebook> {
+    EMBEDDED
+        .get_or_init(|| {
+            Arc::new(Codebook::parse(
+                EMBEDDED_CODEBOOK.as_bytes(),
+                "embedded GCAM-MASTER-CODEBOOK-fixed.csv".to_string(),
+            ))
+        })
+        .clone()
 }
 
 /// In-memory GCAM Codebook Database
-pub struct GCAMCodebookDatabase;
+pub struct GCAMCodebookDatabase {
+    codebook: RwLock<Arc<Codebook>>,
+}
 
 impl GCAMCodebookDatabase {
-    /// Create a new GCAM Codebook Database (now just returns an empty struct)
-    pub fn new<P: AsRef<std::path::Path>>(_db_path: P) -> Result<Self> {
-        // Initialize the global maps (this is a no-op if already initialized)
-        init_database();
-        Ok(Self {})
+    fn with_codebook(codebook: Arc<Codebook>) -> Self {
+        Self {
+            codebook: RwLock::new(codebook),
+        }
     }
 
-    /// Create an in-memory database for testing (same as new now)
+    fn read(&self) -> RwLockReadGuard<'_, Arc<Codebook>> {
+        self.codebook
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Open a GCAM Codebook Database
+    ///
+    /// If `db_path` is a codebook file it is loaded. A directory (where the old sled
+    /// database lived) gives the embedded codebook with a warning, and a path that
+    /// doesn't exist is an error.
+    pub fn new<P: AsRef<Path>>(db_path: P) -> Result<Self> {
+        let db_path = db_path.as_ref();
+        if db_path.is_file() {
+            Self::from_path(db_path)
+        } else if db_path.is_dir() {
+            log::warn!(
+                "{} is a directory, not a GCAM codebook; using the embedded codebook",
+                db_path.display()
+            );
+            Ok(Self::embedded())
+        } else {
+            Err(anyhow!(
+                "GCAM codebook {} does not exist",
+                db_path.display()
+            ))
+        }
+    }
+
+    /// Create a database over the embedded codebook
     pub fn new_temp() -> Result<Self> {
-        init_database();
-        Ok(Self {})
+        Ok(Self::embedded())
     }
 
-    /// Insert a GCAM codebook entry (no-op for static database)
-    pub fn insert_entry(&self, _entry: &GCAMCodebookEntry) -> Result<()> {
-        // This is a no-op since the database is pre-populated at compile time
-        Ok(())
+    /// The codebook compiled into the crate
+    pub fn embedded() -> Self {
+        Self::with_codebook(embedded_codebook())
     }
 
-    /// Get a GCAM codebook entry by dictionary ID
-    pub fn get_by_dictionary_id(&self, dictionary_id: u32) -> Result<Option<GCAMCodebookEntry>> {
-        let (_, dictionary_map) = init_database();
+    /// Load a codebook file in the GCAM master codebook format
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read(path)
+            .with_context(|| format!("Failed to read GCAM codebook {}", path.display()))?;
+        Self::from_bytes(&content, path.display().to_string())
+    }
 
-        // log::debug!(
-        //     "looking up dictionary_id {} with key '{}'",
-        //     dictionary_id,
-        //     dictionary_id
-        // );
+    /// Load a codebook from its text; `source` is only used in diagnostics
+    pub fn from_contents(content: &str, source: impl Into<String>) -> Result<Self> {
+        Self::from_bytes(content.as_bytes(), source)
+    }
 
-        if let Some(entry) = dictionary_map.get(&dictionary_id) {
-            // log::debug!(
-            //     "Successfully found entry for dictionary_id {}: variable '{}'",
-            //     dictionary_id,
-            //     entry.variable
-            // );
-            Ok(Some(entry.clone()))
+    /// Load a codebook from the raw bytes of a codebook file
+    pub fn from_bytes(content: &[u8], source: impl Into<String>) -> Result<Self> {
+        let codebook = Codebook::parse(content, source.into());
+        if codebook.variable_map.is_empty() {
+            return Err(anyhow!(
+                "No GCAM codebook entries found in {}",
+                codebook.source
+            ));
+        }
+        Ok(Self::with_codebook(Arc::new(codebook)))
+    }
+
+    /// Replace this database's contents with the codebook at `path`
+    pub fn load_path<P: AsRef<Path>>(&self, path: P) -> Result<usize> {
+        let loaded = Self::from_path(path)?;
+        let codebook = loaded.read().clone();
+        let count = codebook.variable_map.len();
+        *self
+            .codebook
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = codebook;
+        Ok(count)
+    }
+
+    /// Content hash of the codebook this database was loaded from
+    pub fn version(&self) -> CodebookVersion {
+        self.read().version.clone()
+    }
+
+    /// Where the codebook was loaded from
+    pub fn source(&self) -> String {
+        self.read().source.clone()
+    }
+
+    /// Fail unless this is the codebook version `expected` (a full hash or a prefix of it)
+    pub fn require_version(&self, expected: &str) -> Result<()> {
+        let version = self.version();
+        if version.matches(expected) {
+            Ok(())
         } else {
-            // log::debug!("No entry found for dictionary_id {}", dictionary_id);
-            Ok(None)
+            Err(anyhow!(
+                "GCAM codebook version mismatch: expected {}, loaded {} from {}",
+                expected,
+                version,
+                self.source()
+            ))
         }
     }
 
+    /// Insert a GCAM codebook entry
+    ///
+    /// Databases sharing the same codebook are unaffected; this one gets its own copy
+    /// and a new version.
+    pub fn insert_entry(&self, entry: &GCAMCodebookEntry) -> Result<()> {
+        let mut guard = self
+            .codebook
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let codebook = Arc::make_mut(&mut guard);
+        codebook.version = codebook.version.with_entry(entry);
+        codebook.add(entry.clone());
+        Ok(())
+    }
+
+    /// Get the first dimension of a dictionary ID
+    ///
+    /// Useful for the fields every dimension of a dictionary shares (name, language,
+    /// citation); use `list_dimensions` for all of them.
+    pub fn get_by_dictionary_id(&self, dictionary_id: u32) -> Result<Option<GCAMCodebookEntry>> {
+        Ok(self
+            .read()
+            .dictionary_entries(dictionary_id)
+            .next()
+            .map(|entry| GCAMCodebookEntry::clone(entry)))
+    }
+
     /// Get a GCAM codebook entry by variable name
     pub fn get_by_variable(&self, variable: &str) -> Result<Option<GCAMCodebookEntry>> {
-        let (variable_map, _) = init_database();
+        let codebook = self.read();
+        let variable_map = &codebook.variable_map;
 
         // log::debug!("Looking up variable '{}'", variable);
 
@@ -167,7 +431,7 @@ impl GCAMCodebookDatabase {
             //     variable,
             //     entry.dictionary_id
             // );
-            Ok(Some(entry.clone()))
+            Ok(Some(GCAMCodebookEntry::clone(entry)))
         } else {
             // log::debug!("No entry found for variable '{}'", variable);
             Ok(None)
@@ -176,26 +440,105 @@ impl GCAMCodebookDatabase {
 
     /// Get all entries for a specific dictionary
     pub fn get_by_dictionary(&self, dictionary: &Dictionary) -> Result<Vec<GCAMCodebookEntry>> {
-        let (variable_map, _) = init_database();
+        Ok(self
+            .get_shared_by_dictionary(dictionary)
+            .iter()
+            .map(|entry| GCAMCodebookEntry::clone(entry))
+            .collect())
+    }
 
-        let entries: Vec<GCAMCodebookEntry> = variable_map
-            .values()
+    /// Shared entries for a dictionary, ordered by dictionary and dimension ID
+    pub fn get_shared_by_dictionary(&self, dictionary: &Dictionary) -> Vec<Arc<GCAMCodebookEntry>> {
+        let codebook = self.read();
+        codebook
+            .entries_for_ids(codebook.dictionary_index.get(dictionary))
             .filter(|entry| &entry.dictionary == dictionary)
             .cloned()
-            .collect();
+            .collect()
+    }
 
-        Ok(entries)
+    /// Shared entries in a language, ordered by dictionary and dimension ID
+    pub fn get_by_language(&self, language: &Language) -> Vec<Arc<GCAMCodebookEntry>> {
+        let codebook = self.read();
+        codebook
+            .entries_for_ids(codebook.language_index.get(language))
+            .filter(|entry| &entry.language == language)
+            .cloned()
+            .collect()
+    }
+
+    /// The dictionary IDs that carry a dictionary name, one per language version
+    pub fn dictionary_ids(&self, dictionary: &Dictionary) -> Vec<u32> {
+        let codebook = self.read();
+        codebook
+            .dictionary_index
+            .get(dictionary)
+            .into_iter()
+            .flatten()
+            .copied()
+            .filter(|&id| {
+                codebook
+                    .dictionary_entries(id)
+                    .any(|entry| &entry.dictionary == dictionary)
+            })
+            .collect()
+    }
+
+    /// Every dictionary the codebook lists, with its IDs and citation
+    pub fn dictionary_registry(&self) -> DictionaryRegistry {
+        DictionaryRegistry::from_entries(self.read().variable_map.values().map(Arc::as_ref))
+    }
+
+    /// Every variable of a dictionary ID, ordered by dimension
+    pub fn list_dimensions(&self, dictionary_id: u32) -> Vec<Arc<GCAMCodebookEntry>> {
+        self.read()
+            .dictionary_entries(dictionary_id)
+            .cloned()
+            .collect()
+    }
+
+    /// The variables measuring one dimension (a `cX.Y` word count and possibly a `vX.Y` score)
+    pub fn get_dimension(
+        &self,
+        dictionary_id: u32,
+        dimension_id: u32,
+    ) -> Vec<Arc<GCAMCodebookEntry>> {
+        self.read()
+            .dimension_index
+            .get(&(dictionary_id, dimension_id))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Resolve the variable for a dimension and measurement type
+    pub fn resolve_variable(
+        &self,
+        dictionary_id: u32,
+        dimension_id: u32,
+        measurement_type: &MeasurementType,
+    ) -> Option<Arc<GCAMCodebookEntry>> {
+        self.read()
+            .dimension_index
+            .get(&(dictionary_id, dimension_id))?
+            .iter()
+            .find(|entry| &entry.measurement_type == measurement_type)
+            .cloned()
     }
 
     /// Get all entries in the database
     pub fn get_all_entries(&self) -> Result<Vec<GCAMCodebookEntry>> {
-        let (variable_map, _) = init_database();
-        Ok(variable_map.values().cloned().collect())
+        let codebook = self.read();
+        let variable_map = &codebook.variable_map;
+        Ok(variable_map
+            .values()
+            .map(|entry| GCAMCodebookEntry::clone(entry))
+            .collect())
     }
 
     /// Get the total count of entries
     pub fn count(&self) -> usize {
-        let (variable_map, _) = init_database();
+        let codebook = self.read();
+        let variable_map = &codebook.variable_map;
         variable_map.len()
     }
 
@@ -205,17 +548,17 @@ impl GCAMCodebookDatabase {
         Ok(())
     }
 
+    /// Get the shared codebook entry for a variable without copying it
+    pub fn get_shared(&self, variable: &str) -> Option<Arc<GCAMCodebookEntry>> {
+        self.read().variable_map.get(variable).cloned()
+    }
+
     /// Enrich a GCAM entry with metadata
+    ///
+    /// The metadata is the codebook's own `Arc`, so enriching thousands of values
+    /// costs a reference count each rather than a copy of every citation.
     pub fn enrich_gcam_entry(&self, key: &str, value: f32) -> Result<EnrichedGCAMEntry> {
-        let metadata = self.get_by_variable(key)?;
-
-        // log::debug!(
-        //     "Enriching GCAM entry '{}' with value {}: metadata found = {}",
-        //     key,
-        //     value,
-        //     metadata.is_some()
-        // );
-
+        let metadata = self.get_shared(key);
         Ok(EnrichedGCAMEntry::new(key.to_string(), value, metadata))
     }
 
@@ -232,34 +575,44 @@ impl GCAMCodebookDatabase {
 
     /// Get database diagnostics
     pub fn get_diagnostics(&self) -> Result<String> {
-        let (variable_map, dictionary_map) = init_database();
+        let codebook = self.read();
 
         Ok(format!(
             "GCAM Database Diagnostics:\n\
              - Total variables: {}\n\
-             - Total dictionary entries: {}\n\
+             - Total dimensions: {}\n\
+             - Total dictionaries: {}\n\
+             - Total languages: {}\n\
              - Database type: In-memory BTreeMap\n\
-             - Source: Embedded CSV data",
-            variable_map.len(),
-            dictionary_map.len()
+             - Source: {}\n\
+             - Version: {}",
+            codebook.variable_map.len(),
+            codebook.dimension_index.len(),
+            codebook.dictionary_index.len(),
+            codebook.language_index.len(),
+            codebook.source,
+            codebook.version
         ))
     }
 
     /// Check if a variable exists in the database
     pub fn has_variable(&self, variable: &str) -> Result<bool> {
-        let (variable_map, _) = init_database();
+        let codebook = self.read();
+        let variable_map = &codebook.variable_map;
         Ok(variable_map.contains_key(variable))
     }
 
     /// List the first N variables in the database
     pub fn list_variables(&self, limit: usize) -> Result<Vec<String>> {
-        let (variable_map, _) = init_database();
+        let codebook = self.read();
+        let variable_map = &codebook.variable_map;
         Ok(variable_map.keys().take(limit).cloned().collect())
     }
 
     /// Get statistics about dictionaries
     pub fn get_dictionary_stats(&self) -> Result<Vec<(String, usize)>> {
-        let (variable_map, _) = init_database();
+        let codebook = self.read();
+        let variable_map = &codebook.variable_map;
         let mut stats: BTreeMap<String, usize> = BTreeMap::new();
 
         for entry in variable_map.values() {
@@ -268,13 +621,14 @@ impl GCAMCodebookDatabase {
         }
 
         let mut result: Vec<(String, usize)> = stats.into_iter().collect();
-        result.sort_by(|a, b| b.1.cmp(&a.1)); // Sort by count descending
+        result.sort_by_key(|(_, count)| std::cmp::Reverse(*count)); // Sort by count descending
         Ok(result)
     }
 
     /// Debug raw contents of the database
     pub fn debug_raw_contents(&self) -> Result<String> {
-        let (variable_map, _) = init_database();
+        let codebook = self.read();
+        let variable_map = &codebook.variable_map;
 
         let mut output = String::new();
         output.push_str("Raw Database Contents (first 10 entries):\n");
@@ -295,7 +649,8 @@ impl GCAMCodebookDatabase {
 
     /// Test the lookup chain for a specific variable
     pub fn test_lookup_chain(&self, variable: &str) -> Result<String> {
-        let (variable_map, dictionary_map) = init_database();
+        let codebook = self.read();
+        let variable_map = &codebook.variable_map;
 
         let mut output = String::new();
         output.push_str(&format!("Lookup chain test for variable '{}':\n", variable));
@@ -305,9 +660,13 @@ impl GCAMCodebookDatabase {
         if let Some(entry) = variable_map.get(variable) {
             output.push_str(&format!("FOUND (dictionary_id: {})\n", entry.dictionary_id));
 
-            // Step 2: Check dictionary map
-            output.push_str("2. Dictionary map lookup: ");
-            if dictionary_map.contains_key(&entry.dictionary_id) {
+            // Step 2: Check dimension index
+            output.push_str("2. Dimension index lookup: ");
+            if codebook
+                .dimension_index
+                .get(&entry.dimension_key())
+                .is_some_and(|variables| variables.iter().any(|v| v.variable == variable))
+            {
                 output.push_str("FOUND\n");
                 output.push_str(&format!("3. Entry details: {:#?}\n", entry));
             } else {
@@ -315,7 +674,7 @@ impl GCAMCodebookDatabase {
             }
         } else {
             output.push_str("NOT FOUND\n");
-            output.push_str("2. Dictionary map lookup: SKIPPED (variable not found)\n");
+            output.push_str("2. Dimension index lookup: SKIPPED (variable not found)\n");
 
             // Show similar variables
             let similar: Vec<&String> = variable_map
@@ -333,24 +692,31 @@ impl GCAMCodebookDatabase {
     }
 }
 
-/// GCAM Codebook Parser (now a no-op since data is embedded)
+impl Clone for GCAMCodebookDatabase {
+    /// The clone shares the codebook until either side inserts an entry
+    fn clone(&self) -> Self {
+        Self::with_codebook(self.read().clone())
+    }
+}
+
+/// GCAM Codebook Parser
 pub struct GCAMCodebookParser;
 
 impl GCAMCodebookParser {
-    /// Parse and populate database (no-op for embedded database)
-    pub fn parse_and_populate<P: AsRef<std::path::Path>>(
-        _csv_path: P,
-        _database: &GCAMCodebookDatabase,
+    /// Load the codebook at `csv_path` into `database`, replacing what it held
+    pub fn parse_and_populate<P: AsRef<Path>>(
+        csv_path: P,
+        database: &GCAMCodebookDatabase,
     ) -> Result<usize> {
-        // Initialize the database to ensure it's loaded
-        let (variable_map, _) = init_database();
-        Ok(variable_map.len())
+        database.load_path(csv_path)
     }
 
-    /// Parse CSV file (no-op for embedded database)
-    pub fn parse_csv<P: AsRef<std::path::Path>>(_csv_path: P) -> Result<Vec<GCAMCodebookEntry>> {
-        let (variable_map, _) = init_database();
-        Ok(variable_map.values().cloned().collect())
+    /// Parse a codebook file into its entries
+    pub fn parse_csv<P: AsRef<Path>>(csv_path: P) -> Result<Vec<GCAMCodebookEntry>> {
+        let csv_path = csv_path.as_ref();
+        let content = std::fs::read(csv_path)
+            .with_context(|| format!("Failed to read GCAM codebook {}", csv_path.display()))?;
+        Ok(parse_codebook(&String::from_utf8_lossy(&content)))
     }
 }
 
@@ -358,6 +724,217 @@ impl GCAMCodebookParser {
 mod tests {
     use super::*;
     use anyhow::Result;
+    use std::io::Write;
+
+    const HEADER: &str = "Variable\tDictionaryID\tDimensionID\tType\tLanguageCode\tDictionaryHumanName\tDimensionHumanName\tDictionaryCitation";
+
+    fn sample_codebook(dimension: &str) -> String {
+        format!(
+            "{}\nwc\t0\t0\tWORDCOUNT\teng\tWord Count\tWord Count\tn/a\nc1.1\t1\t1\tWORDCOUNT\teng\tForest Values\t{}\tBengston & Xu (1995)\nc1.2\t1\t2\tWORDCOUNT\teng\tForest Values\tECONOMIC/UTILITARIAN\tBengston & Xu (1995)\nbroken line\n",
+            HEADER, dimension
+        )
+    }
+
+    #[test]
+    fn test_codebook_versions_coexist() -> Result<()> {
+        let v1 = GCAMCodebookDatabase::from_contents(&sample_codebook("AESTHETIC"), "v1")?;
+        let v2 = GCAMCodebookDatabase::from_contents(&sample_codebook("BEAUTY"), "v2")?;
+
+        assert_eq!(v1.count(), 3);
+        assert_eq!(
+            v1.get_by_variable("c1.1")?.unwrap().dimension_name,
+            "AESTHETIC"
+        );
+        assert_eq!(
+            v2.get_by_variable("c1.1")?.unwrap().dimension_name,
+            "BEAUTY"
+        );
+
+        assert_ne!(v1.version(), v2.version());
+        assert_eq!(
+            v1.version(),
+            CodebookVersion::of(sample_codebook("AESTHETIC").as_bytes())
+        );
+        assert_eq!(v1.version().hash().len(), 64);
+        assert!(v1.require_version(v1.version().short()).is_ok());
+        assert!(v1.require_version(v2.version().hash()).is_err());
+        // Too short to pin anything
+        assert!(v1.require_version(&v1.version().hash()[..4]).is_err());
+
+        assert!(GCAMCodebookDatabase::from_contents(HEADER, "empty").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_entry_copies_on_write() -> Result<()> {
+        let original = GCAMCodebookDatabase::from_contents(&sample_codebook("AESTHETIC"), "v1")?;
+        let copy = original.clone();
+        let version = original.version();
+
+        let mut entry = original.get_by_variable("c1.2")?.unwrap();
+        entry.variable = "c1.3".to_string();
+        entry.dimension_id = 3;
+        copy.insert_entry(&entry)?;
+
+        assert!(copy.has_variable("c1.3")?);
+        assert!(!original.has_variable("c1.3")?);
+        assert_eq!(original.version(), version);
+        assert_ne!(copy.version(), version);
+        Ok(())
+    }
+
+    #[test]
+    fn test_enrichment_shares_metadata() -> Result<()> {
+        let db = GCAMCodebookDatabase::from_contents(&sample_codebook("AESTHETIC"), "v1")?;
+
+        let entries = db.enrich_gcam_entries(vec![
+            ("c1.1".to_string(), 2.0),
+            ("c1.1".to_string(), 3.0),
+            ("c9.9".to_string(), 1.0),
+        ])?;
+        let first = entries[0].metadata.as_ref().unwrap();
+        let second = entries[1].metadata.as_ref().unwrap();
+        assert!(Arc::ptr_eq(first, second));
+        assert!(Arc::ptr_eq(first, &db.get_shared("c1.1").unwrap()));
+        assert_eq!(entries[0].dimension_name(), Some("AESTHETIC"));
+        assert_eq!(
+            entries[0].dictionary_name().as_deref(),
+            Some("Forest Values")
+        );
+        assert!(entries[2].metadata.is_none());
+
+        let round_trip = EnrichedGCAMEntry::from_bytes(&entries[0].to_bytes()?)?;
+        assert_eq!(round_trip, entries[0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_composite_indexes() -> Result<()> {
+        let content = format!(
+            "{}v1.2\t1\t2\tSCOREDVALUE\teng\tForest Values\tECONOMIC (Scored Value)\tBengston & Xu (1995)\nc7.1\t7\t1\tWORDCOUNT\tfra\tForest Values\tESTHETIQUE\tBengston & Xu (1995)\n",
+            sample_codebook("AESTHETIC")
+        );
+        let db = GCAMCodebookDatabase::from_contents(&content, "test")?;
+
+        // Every dimension of the dictionary survives, not just the last one inserted
+        let dimensions: Vec<String> = db
+            .list_dimensions(1)
+            .iter()
+            .map(|entry| entry.variable.clone())
+            .collect();
+        assert_eq!(dimensions, vec!["c1.1", "c1.2", "v1.2"]);
+        assert_eq!(db.get_by_dictionary_id(1)?.unwrap().variable, "c1.1");
+
+        let dimension = db.get_dimension(1, 2);
+        assert_eq!(dimension.len(), 2);
+        assert_eq!(
+            db.resolve_variable(1, 2, &MeasurementType::from("SCOREDVALUE"))
+                .unwrap()
+                .variable,
+            "v1.2"
+        );
+        assert_eq!(
+            db.resolve_variable(1, 2, &MeasurementType::WordCount)
+                .unwrap()
+                .variable,
+            "c1.2"
+        );
+        assert!(
+            db.resolve_variable(1, 9, &MeasurementType::WordCount)
+                .is_none()
+        );
+
+        assert_eq!(
+            db.dictionary_ids(&Dictionary::new("Forest Values")),
+            vec![1, 7]
+        );
+        assert_eq!(
+            db.get_by_dictionary(&Dictionary::new("Forest Values"))?
+                .len(),
+            4
+        );
+        let french: Vec<String> = db
+            .get_by_language(&Language::from("fra"))
+            .iter()
+            .map(|entry| entry.variable.clone())
+            .collect();
+        assert_eq!(french, vec!["c7.1"]);
+
+        // Re-inserting a variable replaces it in every index
+        let mut moved = db.get_by_variable("c1.2")?.unwrap();
+        moved.language = Language::from("fra");
+        db.insert_entry(&moved)?;
+        let dimension = db.get_dimension(1, 2);
+        assert_eq!(dimension.len(), 2);
+        assert_eq!(dimension[0].language, Language::from("fra"));
+        let french: Vec<String> = db
+            .get_by_language(&Language::from("fra"))
+            .iter()
+            .map(|entry| entry.variable.clone())
+            .collect();
+        assert_eq!(french, vec!["c1.2", "c7.1"]);
+        assert_eq!(db.count(), 5);
+        Ok(())
+    }
+
+    #[test]
+    fn test_dimension_index_follows_variable_names() -> Result<()> {
+        // The embedded codebook files c41.1-c41.3 under DictionaryID 40 (Martindale's)
+        let db = GCAMCodebookDatabase::embedded();
+        let martindale =
+            Dictionary::new("Martindale & Martindale's Temperament/Elements Dictionary");
+
+        let dimensions = db.list_dimensions(40);
+        assert_eq!(dimensions.len(), 8);
+        assert!(
+            dimensions
+                .iter()
+                .all(|entry| entry.dictionary == martindale)
+        );
+
+        let dimension = db.get_dimension(40, 1);
+        assert_eq!(dimension.len(), 1);
+        assert_eq!(dimension[0].variable, "c40.1");
+
+        let central_bank = db
+            .resolve_variable(41, 1, &MeasurementType::WordCount)
+            .unwrap();
+        assert_eq!(central_bank.variable, "c41.1");
+        assert_eq!(central_bank.dictionary_id, 40);
+        assert_eq!(db.list_dimensions(41).len(), 3);
+        assert!(
+            db.test_lookup_chain("c41.1")?
+                .contains("2. Dimension index lookup: FOUND")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_codebook_from_path() -> Result<()> {
+        let mut file = tempfile::NamedTempFile::new()?;
+        file.write_all(sample_codebook("AESTHETIC").as_bytes())?;
+
+        let db = GCAMCodebookDatabase::new(file.path())?;
+        assert_eq!(db.count(), 3);
+        assert_eq!(db.source(), file.path().display().to_string());
+
+        let other = GCAMCodebookDatabase::from_contents(&sample_codebook("BEAUTY"), "v2")?;
+        assert_eq!(
+            GCAMCodebookParser::parse_and_populate(file.path(), &other)?,
+            3
+        );
+        assert_eq!(other.version(), db.version());
+        assert_eq!(GCAMCodebookParser::parse_csv(file.path())?.len(), 3);
+
+        assert!(GCAMCodebookDatabase::from_path("/nonexistent/codebook.csv").is_err());
+        assert!(GCAMCodebookDatabase::new("/nonexistent/codebook.csv").is_err());
+
+        // A legacy database directory falls back to the embedded codebook
+        let dir = tempfile::tempdir()?;
+        let legacy = GCAMCodebookDatabase::new(dir.path())?;
+        assert_eq!(legacy.version(), GCAMCodebookDatabase::embedded().version());
+        Ok(())
+    }
 
     #[test]
     #[ignore]
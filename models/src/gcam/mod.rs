@@ -8,8 +8,16 @@
 //!
 //! ## Module Structure
 //!
+//! - `aggregate`: Word-count-weighted GCAM averages across documents, grouped by time, country or theme
+//! - `analysis`: Typed GCAM values: word-count densities, paired scores, per-dictionary vectors
+//! - `codebook_diff`: Structural validation of codebook files and diffs between codebook versions
+//! - `coverage`: GCAM coverage of whole batches of records, to detect codebook drift
+//! - `field`: Lazily parsed V2GCAM field with dictionary/variable selection
 //! - `lookup`: Core types, enums, and data structures for GCAM
-//! - `memory_database`: In-memory BTreeMap database for fast codebook lookups
+//! - `memory_database`: In-memory BTreeMap database for fast codebook lookups, over the
+//!   embedded codebook or one loaded at runtime and identified by content hash
+//! - `multilingual`: Language-specific GCAM dimensions mapped to their English equivalents
+//! - `registry`: The codebook's dictionaries with their IDs, languages and citations
 //! - `enhanced_gkg`: Enhanced GKG table with enriched GCAM entries
 //!
 //! ## Example Usage
@@ -21,9 +29,9 @@
 //!
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! // Setup database
-//! let db = GCAMCodebookDatabase::new("./gcam_db")?;
+//! let db = GCAMCodebookDatabase::new_temp()?;
 //! let count = GCAMCodebookParser::parse_and_populate("GCAM-MASTER-CODEBOOK.csv", &db)?;
-//! println!("Loaded {} GCAM codebook entries", count);
+//! println!("Loaded {} GCAM codebook entries, version {}", count, db.version().short());
 //!
 //! // Debug enrichment if entries appear to have no metadata
 //! let debug_info = debug_gcam_enrichment(&db, "c1.1")?;
@@ -34,7 +42,7 @@
 //! let gkg_table = GKGTable::try_from_with_gcam_db(csv_record, &db)?;
 //!
 //! // Access enriched data
-//! let forest_entries = gkg_table.gcam_by_dictionary(&Dictionary::ForestValues);
+//! let forest_entries = gkg_table.gcam_by_dictionary(&Dictionary::new("Forest Values"));
 //! let stats = gkg_table.gcam_coverage_stats();
 //!
 //! // Check if entries are properly enriched
@@ -60,13 +68,29 @@
 //!
 //! Use the `debug_gcam_enrichment()` function to diagnose issues.
 
+pub mod aggregate;
+pub mod analysis;
+pub mod codebook_diff;
+pub mod coverage;
 pub mod debug_test;
+pub mod field;
 pub mod lookup;
 pub mod memory_database;
+pub mod multilingual;
+pub mod registry;
 
 // Re-export commonly used types for convenience
+pub use aggregate::{GCAMAggregate, GCAMAggregateRow, GroupBy, TimeBucket};
+pub use analysis::{DictionaryScores, DimensionMeasurement, GCAMAnalysis, GCAMVariable};
+pub use codebook_diff::{
+    CodebookDiff, CodebookViolation, Severity, validate_codebook, validate_codebook_file,
+};
+pub use coverage::{GCAMBatchCoverage, GroupCoverage};
+pub use field::{GCAMField, GCAMSelection};
 pub use lookup::{Dictionary, EnrichedGCAMEntry, GCAMCodebookEntry, Language, MeasurementType};
-pub use memory_database::{GCAMCodebookDatabase, GCAMCodebookParser};
+pub use memory_database::{CodebookVersion, GCAMCodebookDatabase, GCAMCodebookParser};
+pub use multilingual::{CrossLanguageMap, DimensionEquivalent, EmotionProfile, ProfileDimension};
+pub use registry::{DictionaryInfo, DictionaryRegistry};
 
 /// Debug helper function to diagnose GCAM enrichment issues
 pub fn debug_gcam_enrichment(db: &GCAMCodebookDatabase, test_key: &str) -> anyhow::Result<String> {
@@ -88,7 +112,7 @@ pub fn debug_gcam_enrichment(db: &GCAMCodebookDatabase, test_key: &str) -> anyho
 /// by testing the exact enrichment process that GKGTable uses.
 pub fn verify_gcam_enrichment(db_path: &str, test_keys: &[&str]) -> anyhow::Result<String> {
     let mut output = String::new();
-    output.push_str(&format!("=== GCAM Enrichment Verification ===\n"));
+    output.push_str("=== GCAM Enrichment Verification ===\n");
     output.push_str(&format!("Database path: {}\n\n", db_path));
 
     // Try to open the database
@@ -196,19 +220,21 @@ pub fn verify_gcam_enrichment(db_path: &str, test_keys: &[&str]) -> anyhow::Resu
 
 /// Convenient function to populate a GCAM database from a CSV file
 ///
-/// This function creates or overwrites the database and populates it with the GCAM Master Codebook.
-/// Returns the number of entries loaded.
+/// The codebook is checked, then written to `db_path`, where `GCAMCodebookDatabase::new`
+/// (and so `verify_gcam_enrichment`) loads it. A directory at `db_path`, left over from
+/// the old sled database, is replaced. Returns the number of entries loaded.
 ///
 /// # Arguments
 /// * `csv_path` - Path to the GCAM Master Codebook CSV file
-/// * `db_path` - Path where the database should be created
+/// * `db_path` - Path the codebook should be written to
 ///
 /// # Example
 /// ```rust,no_run
-/// use models::gcam::populate_gcam_database;
+/// use models::gcam::{GCAMCodebookDatabase, populate_gcam_database};
 ///
 /// let count = populate_gcam_database("GCAM-MASTER-CODEBOOK.csv", "./gcam_db")?;
 /// println!("Loaded {} GCAM entries", count);
+/// let db = GCAMCodebookDatabase::new("./gcam_db")?;
 /// # Ok::<(), anyhow::Error>(())
 /// ```
 pub fn populate_gcam_database<P1, P2>(csv_path: P1, db_path: P2) -> anyhow::Result<usize>
@@ -227,29 +253,29 @@ where
         ));
     }
 
-    // Remove existing database if it exists
-    if db_path.exists() {
+    // Parse before touching the existing database, so a bad file leaves it in place
+    let db = GCAMCodebookDatabase::from_path(csv_path)
+        .map_err(|e| anyhow::anyhow!("Failed to populate database: {}", e))?;
+
+    // Remove an old sled database directory
+    if db_path.is_dir() {
         std::fs::remove_dir_all(db_path)
             .map_err(|e| anyhow::anyhow!("Failed to remove existing database: {}", e))?;
     }
+    if let Some(parent) = db_path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::copy(csv_path, db_path)
+        .map_err(|e| anyhow::anyhow!("Failed to write database {}: {}", db_path.display(), e))?;
 
-    // Create new database
-    let db = GCAMCodebookDatabase::new(db_path)
-        .map_err(|e| anyhow::anyhow!("Failed to create database: {}", e))?;
-
-    // Parse and populate
-    let count = GCAMCodebookParser::parse_and_populate(csv_path, &db)
-        .map_err(|e| anyhow::anyhow!("Failed to populate database: {}", e))?;
-
-    // Flush to ensure data is written
-    db.flush()
-        .map_err(|e| anyhow::anyhow!("Failed to flush database: {}", e))?;
-
-    Ok(count)
+    Ok(db.count())
 }
 
 /// GCAM coverage statistics
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct GCAMCoverageStats {
     pub total_entries: usize,
     pub entries_with_metadata: usize,
@@ -304,3 +330,49 @@ impl From<GCAMEntry> for EnrichedGCAMEntry {
         Self::from_simple(simple.key, simple.value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    const CODEBOOK: &str = "Variable\tDictionaryID\tDimensionID\tType\tLanguageCode\tDictionaryHumanName\tDimensionHumanName\tDictionaryCitation
+c1.1\t1\t1\tWORDCOUNT\teng\tForest Values\tAESTHETIC\tBengston & Xu (1995)
+c1.2\t1\t2\tWORDCOUNT\teng\tForest Values\tECONOMIC\tBengston & Xu (1995)
+";
+
+    #[test]
+    fn test_populate_then_verify() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let csv_path = dir.path().join("codebook.csv");
+        std::fs::write(&csv_path, CODEBOOK)?;
+
+        // An old sled database directory is replaced by the codebook
+        let db_path = dir.path().join("gcam_db");
+        std::fs::create_dir(&db_path)?;
+        std::fs::write(db_path.join("conf"), "sled")?;
+
+        assert_eq!(populate_gcam_database(&csv_path, &db_path)?, 2);
+        let db = GCAMCodebookDatabase::new(&db_path)?;
+        assert_eq!(db.count(), 2);
+        assert_eq!(
+            db.version(),
+            GCAMCodebookDatabase::from_contents(CODEBOOK, "")?.version()
+        );
+
+        let report = verify_gcam_enrichment(db_path.to_str().unwrap(), &["c1.1"])?;
+        assert!(
+            report.contains("Database opened successfully"),
+            "{}",
+            report
+        );
+        assert!(!report.contains("Failed to open database"), "{}", report);
+
+        // A bad codebook leaves the populated one alone
+        let empty = dir.path().join("empty.csv");
+        std::fs::write(&empty, "")?;
+        assert!(populate_gcam_database(&empty, &db_path).is_err());
+        assert_eq!(GCAMCodebookDatabase::new(&db_path)?.count(), 2);
+        Ok(())
+    }
+}
@@ -0,0 +1,386 @@
+//! Multilingual GCAM
+//!
+//! For translated articles GCAM runs its English dictionaries over the machine
+//! translation, and the language-specific editions of some dictionaries (the
+//! non-English Hedonometer, Spanish ANEW, the French and Spanish Hogenraad motive
+//! dictionaries, ...) over the original text. Those editions get their own dictionary
+//! IDs, so a French article's happiness lands in `c24.3` where an English one's lands
+//! in `c21.1`.
+//!
+//! `CrossLanguageMap` links each language-specific dimension to the English dimension
+//! of the same name in the dictionary it was adapted from, where the codebook gives
+//! both the same name. `EmotionProfile` uses it to report every record against the
+//! English dimensions, preferring the native-language measurement when there is one.
+
+use crate::gcam::analysis::GCAMAnalysis;
+use crate::gcam::lookup::{Dictionary, GCAMCodebookEntry, Language, MeasurementType};
+use crate::gcam::memory_database::GCAMCodebookDatabase;
+use crate::types::gkg_table::GKGTable;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+/// Language-specific dictionary IDs and the English dictionary each was adapted from
+pub const DICTIONARY_EQUIVALENTS: [(u32, u32); 11] = [
+    (22, 19), // Spanish ANEW -> ANEW 2010
+    (23, 20), // ML-SENTICON (Non-English) -> ML-SENTICON (English)
+    (24, 21), // Hedonometer (Non-English) -> Hedonometer (English)
+    (28, 8),  // Regressive Imagery Dictionary, French edition
+    (29, 8),  // Russian edition
+    (30, 8),  // Hungarian edition
+    (31, 8),  // Swedish edition
+    (32, 8),  // Portuguese edition
+    (33, 8),  // German edition
+    (36, 35), // Hogenraad's Motive Dictionary, French version
+    (37, 35), // Spanish version
+];
+
+/// A language-specific dimension and its English equivalent
+#[derive(Debug, Clone, PartialEq)]
+pub struct DimensionEquivalent {
+    pub language: Language,
+    /// `(dictionary_id, dimension_id)` of the language-specific dimension
+    pub native: (u32, u32),
+    /// `(dictionary_id, dimension_id)` of the English dimension
+    pub english: (u32, u32),
+}
+
+/// Language-specific GCAM dimensions mapped to their English equivalents
+#[derive(Debug, Clone, Default)]
+pub struct CrossLanguageMap {
+    equivalents: HashMap<(u32, u32), DimensionEquivalent>,
+    /// Word count entries of the English dictionaries in `DICTIONARY_EQUIVALENTS`
+    targets: BTreeMap<(u32, u32), Arc<GCAMCodebookEntry>>,
+}
+
+impl CrossLanguageMap {
+    /// Match dimensions by name (ignoring case) within each pair of equivalent dictionaries
+    pub fn new(codebook: &GCAMCodebookDatabase) -> Self {
+        let mut map = CrossLanguageMap::default();
+        for (native_id, english_id) in DICTIONARY_EQUIVALENTS {
+            let english: HashMap<String, Arc<GCAMCodebookEntry>> =
+                word_counts(codebook, english_id)
+                    .map(|entry| (entry.dimension_name.to_lowercase(), entry))
+                    .collect();
+            for entry in english.values() {
+                map.targets
+                    .insert((entry.dictionary_id, entry.dimension_id), entry.clone());
+            }
+
+            for entry in word_counts(codebook, native_id) {
+                let Some(target) = english.get(&entry.dimension_name.to_lowercase()) else {
+                    continue;
+                };
+                let native = (entry.dictionary_id, entry.dimension_id);
+                map.equivalents.insert(
+                    native,
+                    DimensionEquivalent {
+                        language: entry.language.clone(),
+                        native,
+                        english: (target.dictionary_id, target.dimension_id),
+                    },
+                );
+            }
+        }
+        map
+    }
+
+    /// The English dimension a language-specific dimension corresponds to
+    pub fn equivalent(&self, dictionary_id: u32, dimension_id: u32) -> Option<(u32, u32)> {
+        self.equivalents
+            .get(&(dictionary_id, dimension_id))
+            .map(|equivalent| equivalent.english)
+    }
+
+    /// The mapped dimensions of one language, ordered by dictionary and dimension ID
+    pub fn equivalents_for(&self, language: &Language) -> Vec<&DimensionEquivalent> {
+        let mut equivalents: Vec<&DimensionEquivalent> = self
+            .equivalents
+            .values()
+            .filter(|equivalent| &equivalent.language == language)
+            .collect();
+        equivalents.sort_by_key(|equivalent| equivalent.native);
+        equivalents
+    }
+
+    /// Languages with at least one mapped dimension
+    pub fn languages(&self) -> Vec<Language> {
+        let mut languages: Vec<Language> = Vec::new();
+        for equivalent in self.equivalents.values() {
+            if !languages.contains(&equivalent.language) {
+                languages.push(equivalent.language.clone());
+            }
+        }
+        languages.sort_by_key(|language| language.to_string());
+        languages
+    }
+
+    pub fn len(&self) -> usize {
+        self.equivalents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.equivalents.is_empty()
+    }
+
+    /// A record's emotion profile, in the language from its translation info
+    pub fn profile(&self, record: &GKGTable) -> EmotionProfile {
+        self.profile_for(record.source_language(), &record.gcam_analysis())
+    }
+
+    /// Express a document's measurements against the English dimensions
+    ///
+    /// English dictionaries contribute what they measured (on the translation, for a
+    /// translated article); a native-language dimension of `language` then replaces
+    /// the value of the English dimension it maps to.
+    pub fn profile_for(&self, language: Language, analysis: &GCAMAnalysis) -> EmotionProfile {
+        let mut dimensions = BTreeMap::new();
+        for measurement in analysis.dimensions() {
+            let key = (measurement.dictionary_id, measurement.dimension_id);
+            if let Some(target) = self.targets.get(&key) {
+                dimensions.insert(
+                    key,
+                    ProfileDimension::new(target, measurement.density, measurement.score, None),
+                );
+            }
+        }
+
+        for measurement in analysis.dimensions() {
+            let key = (measurement.dictionary_id, measurement.dimension_id);
+            let Some(equivalent) = self.equivalents.get(&key) else {
+                continue;
+            };
+            if equivalent.language != language {
+                continue;
+            }
+            if let Some(target) = self.targets.get(&equivalent.english) {
+                dimensions.insert(
+                    equivalent.english,
+                    ProfileDimension::new(
+                        target,
+                        measurement.density,
+                        measurement.score,
+                        Some(key),
+                    ),
+                );
+            }
+        }
+
+        EmotionProfile {
+            language,
+            dimensions,
+        }
+    }
+}
+
+fn word_counts(
+    codebook: &GCAMCodebookDatabase,
+    dictionary_id: u32,
+) -> impl Iterator<Item = Arc<GCAMCodebookEntry>> {
+    codebook
+        .list_dimensions(dictionary_id)
+        .into_iter()
+        .filter(|entry| entry.measurement_type == MeasurementType::WordCount)
+}
+
+/// One English dimension of an emotion profile
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfileDimension {
+    pub dictionary_id: u32,
+    pub dimension_id: u32,
+    pub dictionary: Dictionary,
+    pub dimension_name: String,
+    pub density: Option<f64>,
+    pub score: Option<f32>,
+    /// The language-specific dimension the values came from, if not the English one
+    pub native: Option<(u32, u32)>,
+}
+
+impl ProfileDimension {
+    fn new(
+        target: &GCAMCodebookEntry,
+        density: Option<f64>,
+        score: Option<f32>,
+        native: Option<(u32, u32)>,
+    ) -> Self {
+        Self {
+            dictionary_id: target.dictionary_id,
+            dimension_id: target.dimension_id,
+            dictionary: target.dictionary.clone(),
+            dimension_name: target.dimension_name.clone(),
+            density,
+            score,
+            native,
+        }
+    }
+}
+
+/// A document's emotion dimensions, keyed by their English dictionary and dimension IDs
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmotionProfile {
+    /// The document's source language
+    pub language: Language,
+    dimensions: BTreeMap<(u32, u32), ProfileDimension>,
+}
+
+impl EmotionProfile {
+    pub fn dimension(&self, dictionary_id: u32, dimension_id: u32) -> Option<&ProfileDimension> {
+        self.dimensions.get(&(dictionary_id, dimension_id))
+    }
+
+    pub fn dimensions(&self) -> impl Iterator<Item = &ProfileDimension> {
+        self.dimensions.values()
+    }
+
+    pub fn density(&self, dictionary_id: u32, dimension_id: u32) -> Option<f64> {
+        self.dimension(dictionary_id, dimension_id)?.density
+    }
+
+    pub fn score(&self, dictionary_id: u32, dimension_id: u32) -> Option<f32> {
+        self.dimension(dictionary_id, dimension_id)?.score
+    }
+
+    /// Dimensions measured by a native-language dictionary rather than an English one
+    pub fn native_dimensions(&self) -> impl Iterator<Item = &ProfileDimension> {
+        self.dimensions()
+            .filter(|dimension| dimension.native.is_some())
+    }
+
+    pub fn len(&self) -> usize {
+        self.dimensions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dimensions.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gcam::field::GCAMSelection;
+    use anyhow::Result;
+    use csv::StringRecord;
+
+    const CODEBOOK: &str = "Variable\tDictionaryID\tDimensionID\tType\tLanguageCode\tDictionaryHumanName\tDimensionHumanName\tDictionaryCitation
+c8.3\t8\t3\tWORDCOUNT\teng\tRegressive Imagery Dictionary\tEMOTIONS/ANXIETY\tMartindale (1975)
+c21.1\t21\t1\tWORDCOUNT\teng\tHedonometer (English)\tHappiness\tDodds et al. (2015)
+v21.1\t21\t1\tSCOREDVALUE\teng\tHedonometer (English)\tHappiness (Scored Value)\tDodds et al. (2015)
+c24.1\t24\t1\tWORDCOUNT\tara\tHedonometer (Non-English)\tHappiness\tDodds et al. (2015)
+v24.1\t24\t1\tSCOREDVALUE\tara\tHedonometer (Non-English)\tHappiness (Scored Value)\tDodds et al. (2015)
+c24.3\t24\t3\tWORDCOUNT\tfra\tHedonometer (Non-English)\tHappiness\tDodds et al. (2015)
+v24.3\t24\t3\tSCOREDVALUE\tfra\tHedonometer (Non-English)\tHappiness (Scored Value)\tDodds et al. (2015)
+c28.4\t28\t4\tWORDCOUNT\tfra\tRegressive Imagery Dictionary French Edition (Partial Vocab Expansion)\tEMOTIONS/ANXIETE\tHogenraad (2003)
+";
+
+    fn record(gcam: &str, translation: &str) -> StringRecord {
+        let mut fields = vec![""; 27];
+        fields[0] = "20240501090000-T1";
+        fields[1] = "20240501090000";
+        fields[2] = "1";
+        fields[3] = "example.sn";
+        fields[4] = "https://example.sn/article";
+        fields[15] = "0,0,0,0,0,0,0";
+        fields[17] = gcam;
+        fields[26] = translation;
+        StringRecord::from(fields)
+    }
+
+    #[test]
+    fn test_cross_language_map() -> Result<()> {
+        let db = GCAMCodebookDatabase::from_contents(CODEBOOK, "test")?;
+        let map = CrossLanguageMap::new(&db);
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.equivalent(24, 3), Some((21, 1)));
+        assert_eq!(map.equivalent(24, 1), Some((21, 1)));
+        // The French RID translates its dimension names, so the codebook gives no match
+        assert_eq!(map.equivalent(28, 4), None);
+        assert_eq!(map.languages(), vec![Language::Arabic, Language::French]);
+        assert_eq!(map.equivalents_for(&Language::French)[0].native, (24, 3));
+
+        let selection = GCAMSelection::language(&db, &Language::French);
+        assert_eq!(
+            selection,
+            GCAMSelection::variables(["wc", "c24.3", "v24.3", "c28.4"])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_source_language_uses_codebook_codes() -> Result<()> {
+        let codebook = format!(
+            "{}c25.1\t25\t1\tWORDCOUNT\tdeu\tHedonometer (Non-English)\tHappiness\tDodds et al. (2015)\n",
+            CODEBOOK
+        );
+        let db = GCAMCodebookDatabase::from_contents(&codebook, "test")?;
+        // Codebook language codes parse to the named variants and round-trip unchanged
+        let c25 = db.get_shared("c25.1").unwrap();
+        assert_eq!(c25.language, Language::German);
+        assert_eq!(c25.language.to_string(), "deu");
+        for code in ["deu", "zho"] {
+            assert_eq!(Language::from(code).to_string(), code);
+        }
+        assert_eq!(Language::from("chi"), Language::Chinese);
+        let json = serde_json::to_string(&Language::German)?;
+        assert_eq!(json, "\"deu\"");
+        assert_eq!(
+            serde_json::from_str::<Language>("\"ger\"")?,
+            Language::German
+        );
+
+        // Bibliographic codes in the translation info match the codebook's `deu` rows
+        let german = GKGTable::try_from_with_gcam_db(
+            record("wc:100,c21.1:8,c25.1:4", "srclc:ger;eng:GT-GER 1.0"),
+            &db,
+        )?;
+        assert_eq!(german.source_language(), Language::German);
+        let native: Vec<&str> = german
+            .gcam_in_source_language()
+            .iter()
+            .map(|entry| entry.key.as_str())
+            .collect();
+        assert_eq!(native, vec!["c25.1"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_emotion_profile() -> Result<()> {
+        let db = GCAMCodebookDatabase::from_contents(CODEBOOK, "test")?;
+        let map = CrossLanguageMap::new(&db);
+
+        let french = GKGTable::try_from_with_gcam_db(
+            record(
+                "wc:200,c8.3:4,c21.1:8,v21.1:5.9,c24.3:10,v24.3:6.1,c28.4:3",
+                "srclc:fra;eng:GT-FRA 1.0",
+            ),
+            &db,
+        )?;
+        assert_eq!(french.source_language(), Language::French);
+        let native: Vec<&str> = french
+            .gcam_in_source_language()
+            .iter()
+            .map(|entry| entry.key.as_str())
+            .collect();
+        assert_eq!(native, vec!["c24.3", "v24.3", "c28.4"]);
+
+        let profile = map.profile(&french);
+        assert_eq!(profile.language, Language::French);
+        assert_eq!(profile.len(), 2);
+        assert_eq!(profile.density(21, 1), Some(0.05));
+        assert_eq!(profile.score(21, 1), Some(6.1));
+        assert_eq!(profile.dimension(21, 1).unwrap().native, Some((24, 3)));
+        assert_eq!(profile.density(8, 3), Some(0.02));
+        assert_eq!(profile.native_dimensions().count(), 1);
+
+        let english = GKGTable::try_from_with_gcam_db(record("wc:100,c21.1:8,v21.1:5.9", ""), &db)?;
+        assert_eq!(english.source_language(), Language::English);
+        let profile = map.profile(&english);
+        assert_eq!(profile.density(21, 1), Some(0.08));
+        assert_eq!(profile.dimension(21, 1).unwrap().native, None);
+        assert_eq!(
+            profile.dimension(21, 1).unwrap().dimension_name,
+            "Happiness"
+        );
+        Ok(())
+    }
+}
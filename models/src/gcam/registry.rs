@@ -0,0 +1,232 @@
+//! GCAM dictionaries as a codebook describes them
+//!
+//! The codebook names a dictionary on every row, next to its GCAM DictionaryID and
+//! citation. `DictionaryRegistry` collects those rows into one entry per dictionary, so
+//! a dictionary GDELT adds is known as soon as a codebook listing it is loaded.
+//!
+//! A dictionary's ID is the one its variable names carry (`c24.3` belongs to dictionary
+//! 24), which GDELT keeps stable across codebook revisions. It is normally the same as
+//! the DictionaryID column, but where the two disagree the variable name wins: the
+//! shipped codebook files `c41.x` under DictionaryID 40, next to Martindale's `c40.x`.
+
+use crate::gcam::lookup::{Dictionary, GCAMCodebookEntry, Language};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// One dictionary of the codebook
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DictionaryInfo {
+    pub dictionary: Dictionary,
+    /// IDs its variable names carry, ascending; normally exactly one
+    pub ids: Vec<u32>,
+    /// Citation from the codebook, empty if no row gives one
+    pub citation: String,
+    /// Languages of its dimensions, by code
+    pub languages: Vec<Language>,
+    /// Codebook variables (`cX.Y` and `vX.Y`) belonging to it
+    pub variables: usize,
+}
+
+impl DictionaryInfo {
+    /// The dictionary's ID: the lowest DictionaryID listed for it
+    pub fn id(&self) -> u32 {
+        self.ids[0]
+    }
+
+    pub fn name(&self) -> &str {
+        self.dictionary.name()
+    }
+}
+
+/// Every dictionary of a codebook, by name and by ID
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DictionaryRegistry {
+    dictionaries: BTreeMap<Dictionary, DictionaryInfo>,
+    ids: BTreeMap<u32, Vec<Dictionary>>,
+}
+
+impl DictionaryRegistry {
+    pub fn from_entries<'a>(entries: impl IntoIterator<Item = &'a GCAMCodebookEntry>) -> Self {
+        let mut registry = DictionaryRegistry::default();
+        for entry in entries {
+            let info = registry
+                .dictionaries
+                .entry(entry.dictionary.clone())
+                .or_insert_with(|| DictionaryInfo {
+                    dictionary: entry.dictionary.clone(),
+                    ids: Vec::new(),
+                    citation: String::new(),
+                    languages: Vec::new(),
+                    variables: 0,
+                });
+            info.variables += 1;
+            let (id, _) = entry.dimension_key();
+            if let Err(index) = info.ids.binary_search(&id) {
+                info.ids.insert(index, id);
+                registry
+                    .ids
+                    .entry(id)
+                    .or_default()
+                    .push(entry.dictionary.clone());
+            }
+            if info.citation.is_empty() {
+                info.citation = entry.citation.trim().to_string();
+            }
+            if !info.languages.contains(&entry.language) {
+                info.languages.push(entry.language.clone());
+            }
+        }
+
+        for info in registry.dictionaries.values_mut() {
+            info.languages.sort_by_key(|language| language.to_string());
+        }
+        // A DictionaryID shared by several names lists the dictionary with most variables first
+        for names in registry.ids.values_mut() {
+            names.sort_by_key(|name| std::cmp::Reverse(registry.dictionaries[name].variables));
+        }
+        registry
+    }
+
+    pub fn get(&self, dictionary: &Dictionary) -> Option<&DictionaryInfo> {
+        self.dictionaries.get(dictionary)
+    }
+
+    pub fn by_name(&self, name: &str) -> Option<&DictionaryInfo> {
+        self.get(&Dictionary::from_human_name(name))
+    }
+
+    /// The dictionary filed under a DictionaryID
+    pub fn by_id(&self, id: u32) -> Option<&DictionaryInfo> {
+        self.all_by_id(id).into_iter().next()
+    }
+
+    /// Every dictionary filed under a DictionaryID; more than one means the codebook
+    /// files two dictionaries under one ID
+    pub fn all_by_id(&self, id: u32) -> Vec<&DictionaryInfo> {
+        self.ids
+            .get(&id)
+            .into_iter()
+            .flatten()
+            .filter_map(|name| self.dictionaries.get(name))
+            .collect()
+    }
+
+    pub fn id(&self, dictionary: &Dictionary) -> Option<u32> {
+        self.get(dictionary).map(DictionaryInfo::id)
+    }
+
+    pub fn citation(&self, dictionary: &Dictionary) -> Option<&str> {
+        self.get(dictionary)
+            .map(|info| info.citation.as_str())
+            .filter(|citation| !citation.is_empty())
+    }
+
+    /// Every dictionary, ordered by ID
+    pub fn iter(&self) -> impl Iterator<Item = &DictionaryInfo> {
+        self.ids
+            .iter()
+            .flat_map(|(id, names)| names.iter().map(move |name| (id, name)))
+            .filter_map(|(id, name)| {
+                let info = self.dictionaries.get(name)?;
+                (info.id() == *id).then_some(info)
+            })
+    }
+
+    pub fn len(&self) -> usize {
+        self.dictionaries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dictionaries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gcam::memory_database::GCAMCodebookDatabase;
+    use anyhow::Result;
+
+    const CODEBOOK: &str = "Variable\tDictionaryID\tDimensionID\tType\tLanguageCode\tDictionaryHumanName\tDimensionHumanName\tDictionaryCitation
+c1.1\t1\t1\tWORDCOUNT\teng\tForest Values\tAESTHETIC\tBengston & Xu (1995)
+c1.2\t1\t2\tWORDCOUNT\teng\tForest Values\tECONOMIC\tBengston & Xu (1995)
+c24.1\t24\t1\tWORDCOUNT\tara\tHedonometer (Non-English)\tHappiness\tDodds et al. (2015)
+v24.1\t24\t1\tSCOREDVALUE\tara\tHedonometer (Non-English)\tHappiness (Scored Value)\tDodds et al. (2015)
+c24.3\t24\t3\tWORDCOUNT\tfra\tHedonometer (Non-English)\tHappiness\tDodds et al. (2015)
+c40.1\t40\t1\tWORDCOUNT\teng\tMartindale & Martindale's Temperament/Elements Dictionary\tAir\t
+c40.2\t40\t2\tWORDCOUNT\teng\tMartindale & Martindale's Temperament/Elements Dictionary\tEarth\t
+c41.1\t40\t1\tWORDCOUNT\teng\tCentral Bank Financial Stability Report Sentiment\tPositive\tCorrea et al. (2017)
+";
+
+    #[test]
+    fn test_dictionary_registry() -> Result<()> {
+        let db = GCAMCodebookDatabase::from_contents(CODEBOOK, "test")?;
+        let registry = db.dictionary_registry();
+        assert_eq!(registry.len(), 4);
+
+        let hedonometer = registry.by_name("Hedonometer (Non-English)").unwrap();
+        assert_eq!(hedonometer.id(), 24);
+        assert_eq!(hedonometer.variables, 3);
+        assert_eq!(
+            hedonometer.languages,
+            vec![Language::Arabic, Language::French]
+        );
+        assert_eq!(
+            registry.citation(&hedonometer.dictionary),
+            Some("Dodds et al. (2015)")
+        );
+        assert_eq!(registry.by_id(1).unwrap().name(), "Forest Values");
+        assert!(registry.by_id(2).is_none());
+
+        // No citation column for Martindale, and a second dictionary misfiled under its
+        // DictionaryID takes the ID of its variable names instead
+        let martindale = registry.by_id(40).unwrap();
+        assert!(martindale.name().starts_with("Martindale"));
+        assert_eq!(registry.citation(&martindale.dictionary), None);
+        assert_eq!(registry.all_by_id(40).len(), 1);
+        assert_eq!(
+            registry.by_id(41).unwrap().name(),
+            "Central Bank Financial Stability Report Sentiment"
+        );
+
+        let ids: Vec<u32> = registry.iter().map(DictionaryInfo::id).collect();
+        assert_eq!(ids, vec![1, 24, 40, 41]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_embedded_registry_ids_are_unique() {
+        let registry = GCAMCodebookDatabase::embedded().dictionary_registry();
+        let mut seen = std::collections::BTreeSet::new();
+        for info in registry.iter() {
+            assert_eq!(info.ids.len(), 1, "{} has IDs {:?}", info.name(), info.ids);
+            assert!(seen.insert(info.id()), "ID {} is shared", info.id());
+            assert_eq!(registry.all_by_id(info.id()).len(), 1);
+        }
+        assert_eq!(seen.len(), registry.len());
+        assert!(registry.by_id(40).unwrap().name().starts_with("Martindale"));
+        assert_eq!(
+            registry.by_id(41).unwrap().name(),
+            "Central Bank Financial Stability Report Sentiment"
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_deprecated_dictionary_names() {
+        assert_eq!(
+            Dictionary::LinguisticInquiryAndWordCount,
+            Dictionary::new("Linguistic Inquiry and Word Count (LIWC)")
+        );
+        assert_eq!(Dictionary::Other("Custom".to_string()).name(), "Custom");
+        assert!(
+            Dictionary::ForestValues
+                .get_citation()
+                .starts_with("Bengston")
+        );
+        assert_eq!(
+            Dictionary::new("Unknown").get_citation(),
+            "Citation not available for this dictionary."
+        );
+    }
+}
@@ -0,0 +1,565 @@
+//! GeoNames dump loader
+//!
+//! Reads the tab-separated "geoname" table format used by `allCountries.txt`,
+//! `cities500.txt`, `cities15000.txt` and the per-country files from
+//! <https://download.geonames.org/export/dump/>.
+//!
+//! A GDELT `FeatureID` does not say which gazetteer it comes from: numeric ids are
+//! usually GNS UFIs (negative or positive) or GNIS ids, and ADM1-level features carry
+//! an ADM1 code instead of a number. A number that happens to exist in a GeoNames dump
+//! is therefore no proof that it names the same place. `GeoNamesIndex::resolve` only
+//! accepts an id match that agrees with the geography's country and coordinates, and
+//! otherwise falls back to the place named by the geography's full name: the nearest
+//! one in the coded country within `MAX_FEATURE_DISTANCE_KM`. Callers that know they
+//! hold GeoNames ids look them up with `GeoNamesIndex::get`.
+//!
+//! The index keeps a slim `GeoNamesPlace` per record and looks names up by hash, so
+//! names are stored once. Malformed dump lines are skipped and counted rather than
+//! failing the load.
+
+use anyhow::{Result, anyhow};
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::types::event_table::{Coordinates, Geography};
+use crate::types::lookup_types::country::CountryZone;
+
+/// Number of columns in a GeoNames dump line
+const GEONAMES_COLUMNS: usize = 19;
+
+/// How far a geography's coordinates may lie from the GeoNames place its FeatureID
+/// resolves to before the match is rejected
+const MAX_FEATURE_DISTANCE_KM: f64 = 25.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeoNamesRecord {
+    pub geoname_id: u64,
+    pub name: String,
+    pub ascii_name: String,
+    pub alternate_names: Vec<String>,
+    pub coordinates: Coordinates,
+    /// GeoNames feature class (`P` populated place, `A` administrative, ...)
+    pub feature_class: Option<char>,
+    pub feature_code: Option<String>,
+    /// ISO 3166-1 alpha-2 country code
+    pub country_code: Option<String>,
+    pub admin1_code: Option<String>,
+    pub admin2_code: Option<String>,
+    pub admin3_code: Option<String>,
+    pub admin4_code: Option<String>,
+    pub population: u64,
+    pub timezone: Option<String>,
+}
+
+fn parse_optional_string(s: &str) -> Option<String> {
+    let trimmed = s.trim();
+    if trimmed.is_empty() {
+        None
+    } else {
+        Some(trimmed.to_string())
+    }
+}
+
+impl TryFrom<&str> for GeoNamesRecord {
+    type Error = anyhow::Error;
+
+    fn try_from(line: &str) -> Result<Self> {
+        let fields: Vec<&str> = line.trim_end_matches(['\r', '\n']).split('\t').collect();
+        if fields.len() < GEONAMES_COLUMNS {
+            return Err(anyhow!(
+                "Expected {} fields for GeoNames record, got {}",
+                GEONAMES_COLUMNS,
+                fields.len()
+            ));
+        }
+
+        Ok(GeoNamesRecord {
+            geoname_id: fields[0].trim().parse()?,
+            name: fields[1].trim().to_string(),
+            ascii_name: fields[2].trim().to_string(),
+            alternate_names: fields[3]
+                .split(',')
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .map(str::to_string)
+                .collect(),
+            coordinates: Coordinates {
+                latitude: fields[4].trim().parse()?,
+                longitude: fields[5].trim().parse()?,
+            },
+            feature_class: fields[6].trim().chars().next(),
+            feature_code: parse_optional_string(fields[7]),
+            country_code: parse_optional_string(fields[8]),
+            admin1_code: parse_optional_string(fields[10]),
+            admin2_code: parse_optional_string(fields[11]),
+            admin3_code: parse_optional_string(fields[12]),
+            admin4_code: parse_optional_string(fields[13]),
+            population: fields[14].trim().parse().unwrap_or(0),
+            timezone: parse_optional_string(fields[17]),
+        })
+    }
+}
+
+impl GeoNamesRecord {
+    pub fn country(&self) -> Option<CountryZone> {
+        self.country_code
+            .as_deref()
+            .and_then(CountryZone::from_iso_alpha2)
+    }
+
+    /// Country and admin codes from the top down, stopping at the first gap
+    pub fn admin_hierarchy(&self) -> Vec<&str> {
+        [
+            &self.country_code,
+            &self.admin1_code,
+            &self.admin2_code,
+            &self.admin3_code,
+            &self.admin4_code,
+        ]
+        .into_iter()
+        .map_while(|code| code.as_deref())
+        .collect()
+    }
+
+    /// Every spelling this place is known by, canonical name first
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.name.as_str())
+            .chain(std::iter::once(self.ascii_name.as_str()))
+            .chain(self.alternate_names.iter().map(String::as_str))
+    }
+}
+
+/// The part of a GeoNames record the index keeps
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeoNamesPlace {
+    pub geoname_id: u64,
+    pub name: String,
+    /// Other spellings, including the ASCII name where it differs from `name`
+    pub alternate_names: Vec<String>,
+    pub coordinates: Coordinates,
+    pub country: Option<CountryZone>,
+    /// Admin codes from ADM1 down, stopping at the first gap
+    pub admin_codes: Vec<String>,
+    pub population: u64,
+    pub timezone: Option<Arc<str>>,
+}
+
+impl GeoNamesPlace {
+    /// ISO country code and admin codes from the top down
+    pub fn admin_hierarchy(&self) -> Vec<&str> {
+        self.country
+            .and_then(|country| country.iso_alpha2())
+            .into_iter()
+            .chain(self.admin_codes.iter().map(String::as_str))
+            .collect()
+    }
+
+    /// Every spelling this place is known by, canonical name first
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.name.as_str()).chain(self.alternate_names.iter().map(String::as_str))
+    }
+
+    /// Whether the place agrees with everything `geography` says about its country
+    /// and coordinates. A geography with neither can't confirm anything.
+    fn agrees_with(&self, geography: &Geography) -> bool {
+        let country = geography
+            .country_code
+            .as_ref()
+            .and_then(|code| code.parsed());
+        let coordinates = geography
+            .coordinates
+            .as_ref()
+            .filter(|coordinates| coordinates.is_valid());
+        if country.is_none() && coordinates.is_none() {
+            return false;
+        }
+
+        country.is_none_or(|country| self.country == Some(*country))
+            && coordinates.is_none_or(|coordinates| {
+                distance_km(coordinates, &self.coordinates) <= MAX_FEATURE_DISTANCE_KM
+            })
+    }
+}
+
+/// Great-circle distance
+fn distance_km(a: &Coordinates, b: &Coordinates) -> f64 {
+    const EARTH_RADIUS_KM: f64 = 6371.0;
+    let (lat_a, lat_b) = (a.latitude.to_radians(), b.latitude.to_radians());
+    let d_lat = lat_b - lat_a;
+    let d_lon = (b.longitude - a.longitude).to_radians();
+    let h = (d_lat / 2.0).sin().powi(2) + lat_a.cos() * lat_b.cos() * (d_lon / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * h.sqrt().asin()
+}
+
+fn normalize_name(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+fn name_key(name: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    normalize_name(name).hash(&mut hasher);
+    hasher.finish()
+}
+
+/// In-memory index over a GeoNames dump
+#[derive(Debug, Default)]
+pub struct GeoNamesIndex {
+    places: Vec<GeoNamesPlace>,
+    by_id: HashMap<u64, u32>,
+    /// Positions by hash of the normalised name; candidates are checked against the
+    /// place's own names on lookup
+    by_name: HashMap<u64, Vec<u32>>,
+    timezones: HashSet<Arc<str>>,
+    skipped_lines: usize,
+}
+
+impl GeoNamesIndex {
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::from_reader(File::open(path)?)
+    }
+
+    pub fn from_reader<R: Read>(reader: R) -> Result<Self> {
+        Self::from_reader_filtered(reader, |_| true)
+    }
+
+    /// Load only the records accepted by `keep`.
+    ///
+    /// `allCountries.txt` has over 12 million rows, so filtering on feature class,
+    /// country or population at load time keeps the index manageable.
+    pub fn from_reader_filtered<R: Read>(
+        reader: R,
+        keep: impl Fn(&GeoNamesRecord) -> bool,
+    ) -> Result<Self> {
+        let mut index = GeoNamesIndex::default();
+        for (line_number, line) in BufReader::new(reader).lines().enumerate() {
+            let line = match line {
+                Ok(line) => line,
+                // The bad line has been consumed, so reading can carry on
+                Err(e) if e.kind() == std::io::ErrorKind::InvalidData => {
+                    log::debug!("Skipping GeoNames line {}: {}", line_number + 1, e);
+                    index.skipped_lines += 1;
+                    continue;
+                }
+                Err(e) => return Err(e.into()),
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+            match GeoNamesRecord::try_from(line.as_str()) {
+                Ok(record) if keep(&record) => index.insert(record),
+                Ok(_) => {}
+                Err(e) => {
+                    log::debug!("Skipping GeoNames line {}: {}", line_number + 1, e);
+                    index.skipped_lines += 1;
+                }
+            }
+        }
+        if index.skipped_lines > 0 {
+            log::warn!("Skipped {} malformed GeoNames lines", index.skipped_lines);
+        }
+        Ok(index)
+    }
+
+    pub fn insert(&mut self, record: GeoNamesRecord) {
+        let position = self.places.len() as u32;
+        self.by_id.insert(record.geoname_id, position);
+
+        let country = record.country();
+        let admin_codes = record
+            .admin_hierarchy()
+            .into_iter()
+            .skip(1)
+            .map(str::to_string)
+            .collect();
+        let timezone = record
+            .timezone
+            .map(|timezone| self.intern_timezone(timezone));
+
+        let mut alternate_names = record.alternate_names;
+        if record.ascii_name != record.name && !record.ascii_name.is_empty() {
+            alternate_names.insert(0, record.ascii_name);
+        }
+        let place = GeoNamesPlace {
+            geoname_id: record.geoname_id,
+            name: record.name,
+            alternate_names,
+            coordinates: record.coordinates,
+            country,
+            admin_codes,
+            population: record.population,
+            timezone,
+        };
+
+        let mut keys: Vec<u64> = place.names().map(name_key).collect();
+        keys.sort_unstable();
+        keys.dedup();
+        for key in keys {
+            self.by_name.entry(key).or_default().push(position);
+        }
+
+        self.places.push(place);
+    }
+
+    /// Share one allocation per timezone across all places
+    fn intern_timezone(&mut self, timezone: String) -> Arc<str> {
+        if let Some(shared) = self.timezones.get(timezone.as_str()) {
+            return shared.clone();
+        }
+        let shared: Arc<str> = timezone.into();
+        self.timezones.insert(shared.clone());
+        shared
+    }
+
+    pub fn len(&self) -> usize {
+        self.places.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.places.is_empty()
+    }
+
+    /// Lines of the dump that couldn't be parsed and were left out
+    pub fn skipped_lines(&self) -> usize {
+        self.skipped_lines
+    }
+
+    /// Look up a GeoNames id directly; only for ids known to come from GeoNames
+    pub fn get(&self, geoname_id: u64) -> Option<&GeoNamesPlace> {
+        self.by_id
+            .get(&geoname_id)
+            .map(|&position| &self.places[position as usize])
+    }
+
+    /// The GeoNames place a GDELT geography refers to.
+    ///
+    /// The FeatureID's number is first looked up as a GeoNames id, and that match is
+    /// only kept when it lies in the geography's country and within
+    /// `MAX_FEATURE_DISTANCE_KM` of its coordinates. As most FeatureIDs are GNS or GNIS
+    /// ids, the usual path is the fallback: the first part of the geography's full name
+    /// is searched within the coded country, and the nearest match within
+    /// `MAX_FEATURE_DISTANCE_KM` of the coordinates wins.
+    pub fn resolve(&self, geography: &Geography) -> Option<&GeoNamesPlace> {
+        geography
+            .feature_id
+            .as_ref()
+            .and_then(|feature_id| feature_id.0.trim().parse().ok())
+            .and_then(|id| self.get(id))
+            .filter(|place| place.agrees_with(geography))
+            .or_else(|| self.resolve_by_name(geography))
+    }
+
+    fn resolve_by_name(&self, geography: &Geography) -> Option<&GeoNamesPlace> {
+        // GDELT full names run from the place outwards, e.g. "Abuja, Federal Capital
+        // Territory, Nigeria"
+        let name = geography.fullname.as_deref()?.split(',').next()?.trim();
+        let coordinates = geography.plottable_coordinates()?;
+        let country = geography
+            .country_code
+            .as_ref()
+            .and_then(|code| code.parsed());
+
+        self.search_name(name)
+            .into_iter()
+            .filter(|place| country.is_none_or(|country| place.country == Some(*country)))
+            .map(|place| (distance_km(coordinates, &place.coordinates), place))
+            .filter(|(distance, _)| *distance <= MAX_FEATURE_DISTANCE_KM)
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, place)| place)
+    }
+
+    /// Every place known by `name` (canonical, ASCII or alternate spelling),
+    /// most populous first
+    pub fn search_name(&self, name: &str) -> Vec<&GeoNamesPlace> {
+        let wanted = normalize_name(name);
+        let mut matches: Vec<&GeoNamesPlace> = self
+            .by_name
+            .get(&name_key(name))
+            .into_iter()
+            .flatten()
+            .map(|&position| &self.places[position as usize])
+            .filter(|place| place.names().any(|name| normalize_name(name) == wanted))
+            .collect();
+        matches.sort_by_key(|place| std::cmp::Reverse(place.population));
+        matches
+    }
+
+    /// Canonical name of the most populous place known by `name`
+    pub fn canonical_name(&self, name: &str) -> Option<&str> {
+        self.search_name(name)
+            .first()
+            .map(|place| place.name.as_str())
+    }
+
+    pub fn places(&self) -> impl Iterator<Item = &GeoNamesPlace> {
+        self.places.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::event_table::FeatureID;
+    use crate::types::lookup_types::coded::Coded;
+    use log::info;
+
+    #[allow(dead_code)]
+    fn init_logger() {
+        static INIT: std::sync::Once = std::sync::Once::new();
+        INIT.call_once(|| {
+            env_logger::init();
+        });
+    }
+
+    const SAMPLE: &str = "2352778\tAbuja\tAbuja\tAbudja,Abuya,Abuja FCT\t9.05785\t7.49508\tP\tPPLC\tNG\t\tFC\t\t\t\t590400\t\t479\tAfrica/Lagos\t2023-01-01
+2332459\tLagos\tLagos\tLagos City,Eko\t6.45407\t3.39467\tP\tPPLA\tNG\t\t05\t\t\t\t9000000\t\t39\tAfrica/Lagos\t2023-01-01
+3998655\tLagos de Moreno\tLagos de Moreno\tLagos\t21.35618\t-101.93218\tP\tPPLA2\tMX\t\t14\t053\t\t\t90000\t\t1879\tAmerica/Mexico_City\t2023-01-01
+";
+
+    #[test]
+    fn test_geonames_record_parsing() {
+        // init_logger();
+
+        let line = SAMPLE.lines().next().unwrap();
+        let record = GeoNamesRecord::try_from(line).unwrap();
+        info!("Parsed GeoNames record: {:?}", record);
+
+        assert_eq!(record.geoname_id, 2352778);
+        assert_eq!(record.name, "Abuja");
+        assert_eq!(record.alternate_names.len(), 3);
+        assert_eq!(record.feature_class, Some('P'));
+        assert_eq!(record.country(), Some(CountryZone::Nigeria));
+        assert_eq!(record.admin_hierarchy(), vec!["NG", "FC"]);
+        assert_eq!(record.population, 590400);
+        assert_eq!(record.timezone.as_deref(), Some("Africa/Lagos"));
+
+        assert!(GeoNamesRecord::try_from("1\tToo short").is_err());
+    }
+
+    fn geography(
+        feature_id: &str,
+        country: Option<CountryZone>,
+        latitude: f64,
+        longitude: f64,
+    ) -> Geography {
+        Geography {
+            location_type: None,
+            fullname: None,
+            country_code: country.map(|country| Coded::new("", Some(country))),
+            adm1_code: None,
+            adm2_code: None,
+            coordinates: Some(Coordinates {
+                latitude,
+                longitude,
+            }),
+            feature_id: Some(FeatureID(feature_id.to_string())),
+        }
+    }
+
+    #[test]
+    fn test_geonames_index_resolution() {
+        // init_logger();
+
+        let index = GeoNamesIndex::from_reader(SAMPLE.as_bytes()).unwrap();
+        assert_eq!(index.len(), 3);
+
+        let abuja = index
+            .resolve(&geography(
+                "2352778",
+                Some(CountryZone::Nigeria),
+                9.06,
+                7.49,
+            ))
+            .unwrap();
+        assert_eq!(abuja.name, "Abuja");
+        assert_eq!(abuja.admin_hierarchy(), vec!["NG", "FC"]);
+        assert_eq!(abuja.timezone.as_deref(), Some("Africa/Lagos"));
+        assert!(
+            index
+                .resolve(&geography("-2014678", None, 9.06, 7.49))
+                .is_none()
+        );
+        assert!(
+            index
+                .resolve(&geography("NI05", None, 9.06, 7.49))
+                .is_none()
+        );
+
+        // A GNS id that collides with a GeoNames id elsewhere doesn't resolve
+        let elsewhere = geography("2352778", Some(CountryZone::Ghana), 5.55, -0.2);
+        assert!(index.resolve(&elsewhere).is_none());
+        let far_away = geography("2352778", None, 6.45, 3.39);
+        assert!(index.resolve(&far_away).is_none());
+        let mut unchecked = geography("2352778", None, 0.0, 0.0);
+        unchecked.coordinates = None;
+        assert!(index.resolve(&unchecked).is_none());
+        assert_eq!(
+            index.get(2352778).map(|place| place.population),
+            Some(590400)
+        );
+
+        // Spelling variants normalise to the canonical name
+        assert_eq!(index.canonical_name("abudja"), Some("Abuja"));
+        assert_eq!(index.canonical_name("Eko"), Some("Lagos"));
+
+        // Ambiguous names come back most populous first
+        let lagos = index.search_name("LAGOS");
+        assert_eq!(lagos.len(), 2);
+        assert_eq!(lagos[0].country, Some(CountryZone::Nigeria));
+    }
+
+    #[test]
+    fn test_geonames_index_resolves_gns_feature_ids() {
+        // init_logger();
+
+        let index = GeoNamesIndex::from_reader(SAMPLE.as_bytes()).unwrap();
+
+        // GDELT's Lagos carries a GNS UFI, so it resolves through its name
+        let mut lagos = geography("-2014678", Some(CountryZone::Nigeria), 6.45, 3.39);
+        lagos.fullname = Some("Lagos, Lagos, Nigeria".to_string());
+        let place = index.resolve(&lagos).unwrap();
+        assert_eq!(place.geoname_id, 2332459);
+        assert_eq!(place.population, 9000000);
+        assert!(place.alternate_names.contains(&"Eko".to_string()));
+
+        // The nearest Lagos in the coded country wins, not the most populous
+        let mut moreno = geography("-1234567", Some(CountryZone::Mexico), 21.36, -101.93);
+        moreno.fullname = Some("Lagos, Jalisco, Mexico".to_string());
+        assert_eq!(index.resolve(&moreno).unwrap().geoname_id, 3998655);
+
+        // A positive id that collides with the wrong place falls back to the name too
+        let mut collided = geography("2352778", Some(CountryZone::Nigeria), 6.45, 3.39);
+        collided.fullname = Some("Lagos, Lagos, Nigeria".to_string());
+        assert_eq!(index.resolve(&collided).unwrap().name, "Lagos");
+
+        // No candidate near the coordinates
+        let mut nowhere = geography("-1", Some(CountryZone::Nigeria), 12.0, 8.5);
+        nowhere.fullname = Some("Lagos, Kano, Nigeria".to_string());
+        assert!(index.resolve(&nowhere).is_none());
+    }
+
+    #[test]
+    fn test_geonames_index_skips_malformed_lines() {
+        // init_logger();
+
+        let dump = format!("{}1\tToo short\nnot-a-number{}", SAMPLE, "\tx".repeat(18));
+        let index = GeoNamesIndex::from_reader(dump.as_bytes()).unwrap();
+        assert_eq!(index.len(), 3);
+        assert_eq!(index.skipped_lines(), 2);
+    }
+
+    #[test]
+    fn test_geonames_index_filtered() {
+        // init_logger();
+
+        let index = GeoNamesIndex::from_reader_filtered(SAMPLE.as_bytes(), |record| {
+            record.country_code.as_deref() == Some("NG")
+        })
+        .unwrap();
+        assert_eq!(index.len(), 2);
+        assert!(index.get(3998655).is_none());
+    }
+}
@@ -0,0 +1,52 @@
+//! Geographic resolution backed by local data files
+//!
+//! Nothing in here is embedded: GeoNames dumps and boundary polygons are far too large
+//! to ship with the crate, so callers point the loaders at files they have downloaded.
+//!
+//! ## Module Structure
+//!
+//! - `geonames`: Loader and index for GeoNames dumps (`allCountries.txt`, `cities500.txt`, ...)
+//! - `reverse_geocode`: Country/ADM1 polygon index for filling and cross-checking codes from coordinates
+//!
+//! ## Example Usage
+//!
+//! ```rust,no_run
+//! use models::geo::GeoNamesIndex;
+//! # use models::types::event_table::Geography;
+//!
+//! # fn resolve(geography: &Geography) -> anyhow::Result<()> {
+//! let index = GeoNamesIndex::from_path("cities500.txt")?;
+//!
+//! // Only resolves when the place agrees with the geography's country and coordinates
+//! if let Some(place) = index.resolve(geography) {
+//!     println!("{} ({} people, {:?})", place.name, place.population, place.timezone);
+//! }
+//!
+//! // Normalise spelling variants to the most populous match
+//! let abuja = index.canonical_name("Abudja");
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ```rust,no_run
+//! use models::geo::ReverseGeocoder;
+//! # use models::types::event_table::Geography;
+//!
+//! # fn enrich(geography: &mut Geography) -> anyhow::Result<()> {
+//! let mut geocoder = ReverseGeocoder::new();
+//! geocoder.load_countries("ne_10m_admin_0_countries.geojson")?;
+//! geocoder.load_adm1("ne_10m_admin_1_states_provinces.geojson")?;
+//!
+//! let report = geocoder.enrich(geography);
+//! if report.has_mismatch() {
+//!     println!("Coded location disagrees with coordinates: {:?}", report);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod geonames;
+pub mod reverse_geocode;
+
+pub use geonames::{GeoNamesIndex, GeoNamesPlace, GeoNamesRecord};
+pub use reverse_geocode::{GeocodeCheck, GeocodeReport, ReverseGeocoder};
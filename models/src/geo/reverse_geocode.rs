@@ -0,0 +1,655 @@
+//! Offline reverse geocoding against local boundary polygons
+//!
+//! Loads country (ADM0) and ADM1 polygons from GeoJSON, e.g. Natural Earth's
+//! `ne_10m_admin_0_countries.geojson` and `ne_10m_admin_1_states_provinces.geojson`,
+//! into a one-degree grid index. Points are then matched with a ray-casting
+//! point-in-polygon test.
+//!
+//! Country features are matched to a `CountryZone` using, in order, the first property
+//! that resolves: FIPS (`FIPS_10`, `FIPS_10_`, `fips`), ISO alpha-2 (`ISO_A2`,
+//! `ISO_A2_EH`) and ISO alpha-3 (`ISO_A3`, `ADM0_A3`). Natural Earth uses `-99` for
+//! "no code", which is skipped. ADM1 features use their `fips` property, falling back
+//! to `iso_3166_2` through the embedded ADM1 table.
+
+use anyhow::{Result, anyhow};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::types::event_table::{ADM1Code, Coordinates, Geography};
+use crate::types::lookup_types::adm1::ADM1Entry;
+use crate::types::lookup_types::coded::Coded;
+use crate::types::lookup_types::country::CountryZone;
+
+/// A ring of (longitude, latitude) points
+type Ring = Vec<(f64, f64)>;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    pub min_longitude: f64,
+    pub min_latitude: f64,
+    pub max_longitude: f64,
+    pub max_latitude: f64,
+}
+
+impl BoundingBox {
+    fn from_ring(ring: &Ring) -> Option<Self> {
+        let (&(lon, lat), rest) = ring.split_first()?;
+        let mut bbox = BoundingBox {
+            min_longitude: lon,
+            min_latitude: lat,
+            max_longitude: lon,
+            max_latitude: lat,
+        };
+        for &(lon, lat) in rest {
+            bbox.min_longitude = bbox.min_longitude.min(lon);
+            bbox.min_latitude = bbox.min_latitude.min(lat);
+            bbox.max_longitude = bbox.max_longitude.max(lon);
+            bbox.max_latitude = bbox.max_latitude.max(lat);
+        }
+        Some(bbox)
+    }
+
+    pub fn contains(&self, longitude: f64, latitude: f64) -> bool {
+        (self.min_longitude..=self.max_longitude).contains(&longitude)
+            && (self.min_latitude..=self.max_latitude).contains(&latitude)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Polygon {
+    exterior: Ring,
+    holes: Vec<Ring>,
+    bbox: BoundingBox,
+}
+
+/// Even-odd ray casting; points exactly on an edge may fall either way
+fn ring_contains(ring: &Ring, longitude: f64, latitude: f64) -> bool {
+    let mut inside = false;
+    let mut j = ring.len().wrapping_sub(1);
+    for i in 0..ring.len() {
+        let (xi, yi) = ring[i];
+        let (xj, yj) = ring[j];
+        if (yi > latitude) != (yj > latitude)
+            && longitude < (xj - xi) * (latitude - yi) / (yj - yi) + xi
+        {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
+
+impl Polygon {
+    fn contains(&self, longitude: f64, latitude: f64) -> bool {
+        self.bbox.contains(longitude, latitude)
+            && ring_contains(&self.exterior, longitude, latitude)
+            && !self
+                .holes
+                .iter()
+                .any(|hole| ring_contains(hole, longitude, latitude))
+    }
+}
+
+/// One boundary feature: a country or an ADM1 division
+#[derive(Debug, Clone, PartialEq)]
+pub struct Boundary {
+    pub name: Option<String>,
+    pub country: Option<CountryZone>,
+    pub adm1_code: Option<ADM1Code>,
+    polygons: Vec<Polygon>,
+}
+
+impl Boundary {
+    pub fn contains(&self, coordinates: &Coordinates) -> bool {
+        self.polygons
+            .iter()
+            .any(|polygon| polygon.contains(coordinates.longitude, coordinates.latitude))
+    }
+}
+
+/// Grid cell size in degrees
+const CELL_SIZE: f64 = 1.0;
+
+fn cell_of(longitude: f64, latitude: f64) -> (i32, i32) {
+    (
+        (longitude / CELL_SIZE).floor() as i32,
+        (latitude / CELL_SIZE).floor() as i32,
+    )
+}
+
+/// Boundaries bucketed by the grid cells their polygons' bounding boxes touch
+#[derive(Debug, Default)]
+struct SpatialIndex {
+    boundaries: Vec<Boundary>,
+    cells: HashMap<(i32, i32), Vec<usize>>,
+}
+
+impl SpatialIndex {
+    fn insert(&mut self, boundary: Boundary) {
+        let position = self.boundaries.len();
+        for polygon in &boundary.polygons {
+            let (min_x, min_y) = cell_of(polygon.bbox.min_longitude, polygon.bbox.min_latitude);
+            let (max_x, max_y) = cell_of(polygon.bbox.max_longitude, polygon.bbox.max_latitude);
+            for x in min_x..=max_x {
+                for y in min_y..=max_y {
+                    let bucket = self.cells.entry((x, y)).or_default();
+                    if bucket.last() != Some(&position) {
+                        bucket.push(position);
+                    }
+                }
+            }
+        }
+        self.boundaries.push(boundary);
+    }
+
+    fn locate(&self, coordinates: &Coordinates) -> Option<&Boundary> {
+        let cell = cell_of(coordinates.longitude, coordinates.latitude);
+        self.cells
+            .get(&cell)?
+            .iter()
+            .map(|&position| &self.boundaries[position])
+            .find(|boundary| boundary.contains(coordinates))
+    }
+}
+
+fn parse_ring(value: &Value) -> Result<Ring> {
+    value
+        .as_array()
+        .ok_or_else(|| anyhow!("Polygon ring is not an array"))?
+        .iter()
+        .map(|point| {
+            let lon = point.get(0).and_then(Value::as_f64);
+            let lat = point.get(1).and_then(Value::as_f64);
+            match (lon, lat) {
+                (Some(lon), Some(lat)) => Ok((lon, lat)),
+                _ => Err(anyhow!("Invalid position in polygon ring")),
+            }
+        })
+        .collect()
+}
+
+fn parse_polygon(value: &Value) -> Result<Option<Polygon>> {
+    let rings = value
+        .as_array()
+        .ok_or_else(|| anyhow!("Polygon coordinates are not an array"))?;
+    let Some((exterior, holes)) = rings.split_first() else {
+        return Ok(None);
+    };
+    let exterior = parse_ring(exterior)?;
+    let Some(bbox) = BoundingBox::from_ring(&exterior) else {
+        return Ok(None);
+    };
+    Ok(Some(Polygon {
+        exterior,
+        holes: holes.iter().map(parse_ring).collect::<Result<_>>()?,
+        bbox,
+    }))
+}
+
+fn parse_geometry(geometry: &Value) -> Result<Vec<Polygon>> {
+    let coordinates = &geometry["coordinates"];
+    match geometry["type"].as_str() {
+        Some("Polygon") => Ok(parse_polygon(coordinates)?.into_iter().collect()),
+        Some("MultiPolygon") => {
+            let mut polygons = Vec::new();
+            for polygon in coordinates
+                .as_array()
+                .ok_or_else(|| anyhow!("MultiPolygon coordinates are not an array"))?
+            {
+                polygons.extend(parse_polygon(polygon)?);
+            }
+            Ok(polygons)
+        }
+        // Points and lines can't contain anything
+        _ => Ok(Vec::new()),
+    }
+}
+
+/// First non-empty string property among `keys`, matched case-insensitively
+fn property<'a>(properties: &'a Value, keys: &[&str]) -> Option<&'a str> {
+    let properties = properties.as_object()?;
+    keys.iter().find_map(|key| {
+        properties
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(key))
+            .and_then(|(_, value)| value.as_str())
+            .map(str::trim)
+            .filter(|value| !value.is_empty() && *value != "-99")
+    })
+}
+
+fn country_from_properties(properties: &Value) -> Option<CountryZone> {
+    property(properties, &["FIPS_10", "FIPS_10_", "fips"])
+        .and_then(CountryZone::from_fips)
+        .or_else(|| {
+            property(properties, &["ISO_A2", "ISO_A2_EH", "iso_a2"])
+                .and_then(CountryZone::from_iso_alpha2)
+        })
+        .or_else(|| {
+            property(properties, &["ISO_A3", "ADM0_A3", "iso_a3", "adm0_a3"])
+                .and_then(CountryZone::from_iso_alpha3)
+        })
+}
+
+fn adm1_from_properties(properties: &Value) -> Option<ADM1Code> {
+    property(properties, &["fips"])
+        .filter(|code| code.len() == 4)
+        .map(|code| ADM1Code(code.to_string()))
+        .or_else(|| {
+            property(properties, &["iso_3166_2"])
+                .and_then(ADM1Entry::from_iso_3166_2)
+                .map(|entry| ADM1Code(entry.code.to_string()))
+        })
+}
+
+fn parse_features(geojson: &str, adm1: bool) -> Result<Vec<Boundary>> {
+    let document: Value = serde_json::from_str(geojson)?;
+    let features = document["features"]
+        .as_array()
+        .ok_or_else(|| anyhow!("GeoJSON document has no features array"))?;
+
+    let mut boundaries = Vec::new();
+    for feature in features {
+        let polygons = parse_geometry(&feature["geometry"])?;
+        if polygons.is_empty() {
+            continue;
+        }
+        let properties = &feature["properties"];
+        let adm1_code = if adm1 {
+            adm1_from_properties(properties)
+        } else {
+            None
+        };
+        let country = country_from_properties(properties)
+            .or_else(|| adm1_code.as_ref().and_then(ADM1Code::country));
+
+        boundaries.push(Boundary {
+            name: property(properties, &["NAME", "name", "ADMIN"]).map(str::to_string),
+            country,
+            adm1_code,
+            polygons,
+        });
+    }
+    Ok(boundaries)
+}
+
+/// Result of checking one code against the point location
+#[derive(Debug, Clone, PartialEq)]
+pub enum GeocodeCheck {
+    /// The code was missing and has been filled from the coordinates
+    Filled(String),
+    /// The coded value agrees with the point location
+    Confirmed,
+    /// The coded value disagrees with the point location
+    Mismatch { coded: String, located: String },
+    /// The code is present but not in the lookup table, so it can't be compared; the raw
+    /// code is left as it is
+    Unmapped { raw: String, located: String },
+    /// The point lies in a country that has no FIPS code, so there is nothing to fill in
+    /// or compare against; `located` is the country's name
+    NoFipsCode { located: String },
+    /// No coordinates, or the point fell outside every loaded boundary
+    Unresolved,
+}
+
+/// FIPS code of a located country, or its name when it has none
+fn located_label(zone: CountryZone) -> String {
+    zone.fips_code()
+        .map(str::to_string)
+        .unwrap_or_else(|| zone.to_string())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeocodeReport {
+    pub country: GeocodeCheck,
+    pub adm1: GeocodeCheck,
+}
+
+impl GeocodeReport {
+    pub fn has_mismatch(&self) -> bool {
+        matches!(self.country, GeocodeCheck::Mismatch { .. })
+            || matches!(self.adm1, GeocodeCheck::Mismatch { .. })
+    }
+}
+
+/// Point-in-polygon lookups over loaded country and ADM1 boundaries
+#[derive(Debug, Default)]
+pub struct ReverseGeocoder {
+    countries: SpatialIndex,
+    adm1: SpatialIndex,
+}
+
+impl ReverseGeocoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load country polygons from a GeoJSON FeatureCollection; returns the number loaded
+    pub fn load_countries<P: AsRef<Path>>(&mut self, path: P) -> Result<usize> {
+        self.load_countries_str(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn load_countries_str(&mut self, geojson: &str) -> Result<usize> {
+        let boundaries = parse_features(geojson, false)?;
+        let count = boundaries.len();
+        boundaries
+            .into_iter()
+            .for_each(|boundary| self.countries.insert(boundary));
+        Ok(count)
+    }
+
+    /// Load ADM1 polygons from a GeoJSON FeatureCollection; returns the number loaded
+    pub fn load_adm1<P: AsRef<Path>>(&mut self, path: P) -> Result<usize> {
+        self.load_adm1_str(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn load_adm1_str(&mut self, geojson: &str) -> Result<usize> {
+        let boundaries = parse_features(geojson, true)?;
+        let count = boundaries.len();
+        boundaries
+            .into_iter()
+            .for_each(|boundary| self.adm1.insert(boundary));
+        Ok(count)
+    }
+
+    pub fn country_boundary(&self, coordinates: &Coordinates) -> Option<&Boundary> {
+        self.countries.locate(coordinates)
+    }
+
+    pub fn adm1_boundary(&self, coordinates: &Coordinates) -> Option<&Boundary> {
+        self.adm1.locate(coordinates)
+    }
+
+    /// Country at the point, falling back to the ADM1 layer's country
+    pub fn country_at(&self, coordinates: &Coordinates) -> Option<CountryZone> {
+        self.country_boundary(coordinates)
+            .and_then(|boundary| boundary.country)
+            .or_else(|| {
+                self.adm1_boundary(coordinates)
+                    .and_then(|boundary| boundary.country)
+            })
+    }
+
+    pub fn adm1_at(&self, coordinates: &Coordinates) -> Option<&ADM1Code> {
+        self.adm1_boundary(coordinates)
+            .and_then(|boundary| boundary.adm1_code.as_ref())
+    }
+
+    /// Compare a geography's country and ADM1 codes with its coordinates without changing it
+    pub fn check(&self, geography: &Geography) -> GeocodeReport {
+        let Some(coordinates) = geography.plottable_coordinates() else {
+            return GeocodeReport {
+                country: GeocodeCheck::Unresolved,
+                adm1: GeocodeCheck::Unresolved,
+            };
+        };
+
+        let located_country = self.country_at(coordinates);
+        let country = match (located_country, &geography.country_code) {
+            (None, _) => GeocodeCheck::Unresolved,
+            (Some(located), Some(coded)) => match coded.parsed() {
+                Some(&zone) if zone == located => GeocodeCheck::Confirmed,
+                Some(_) => GeocodeCheck::Mismatch {
+                    coded: coded.raw().to_string(),
+                    located: located_label(located),
+                },
+                None => GeocodeCheck::Unmapped {
+                    raw: coded.raw().to_string(),
+                    located: located_label(located),
+                },
+            },
+            (Some(located), None) => match located.fips_code() {
+                Some(fips) => GeocodeCheck::Filled(fips.to_string()),
+                None => GeocodeCheck::NoFipsCode {
+                    located: located.to_string(),
+                },
+            },
+        };
+        // An ADM1 code is only filled in under a country the point agrees with
+        let country_settled = matches!(country, GeocodeCheck::Confirmed | GeocodeCheck::Filled(_));
+
+        let located_adm1 = self.adm1_at(coordinates);
+        let coded_adm1 = geography
+            .adm1_code
+            .as_ref()
+            .filter(|code| !code.is_country_level());
+        let adm1 = match (located_adm1, coded_adm1) {
+            (None, _) => GeocodeCheck::Unresolved,
+            (Some(located), Some(coded)) => {
+                if coded.0 == located.0 {
+                    GeocodeCheck::Confirmed
+                } else {
+                    GeocodeCheck::Mismatch {
+                        coded: coded.0.clone(),
+                        located: located.0.clone(),
+                    }
+                }
+            }
+            (Some(located), None) if country_settled => GeocodeCheck::Filled(located.0.clone()),
+            (Some(_), None) => GeocodeCheck::Unresolved,
+        };
+
+        GeocodeReport { country, adm1 }
+    }
+
+    /// Fill missing country and ADM1 codes from the coordinates.
+    ///
+    /// Codes that are present are never overwritten: ones that disagree with the point
+    /// are reported as mismatches, unmappable ones as `Unmapped`. A missing ADM1 code is
+    /// only filled when the country is confirmed or was filled itself.
+    pub fn enrich(&self, geography: &mut Geography) -> GeocodeReport {
+        let report = self.check(geography);
+
+        if let GeocodeCheck::Filled(fips) = &report.country {
+            geography.country_code = Coded::from_field(fips, |code| {
+                CountryZone::from_fips(code).ok_or_else(|| anyhow!("Invalid FIPS Code"))
+            });
+        }
+        if let GeocodeCheck::Filled(adm1) = &report.adm1 {
+            geography.adm1_code = Some(ADM1Code(adm1.clone()));
+        }
+
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::lookup_types::geography_type::LocationType;
+    use log::info;
+
+    #[allow(dead_code)]
+    fn init_logger() {
+        static INIT: std::sync::Once = std::sync::Once::new();
+        INIT.call_once(|| {
+            env_logger::init();
+        });
+    }
+
+    // Boxes standing in for Nigeria, Benin and the Aland Islands, with a hole in "Nigeria"
+    const COUNTRIES: &str = r#"{
+        "type": "FeatureCollection",
+        "features": [
+            {
+                "type": "Feature",
+                "properties": { "NAME": "Nigeria", "ISO_A2": "NG", "FIPS_10": "NI" },
+                "geometry": {
+                    "type": "Polygon",
+                    "coordinates": [
+                        [[3.0, 4.0], [14.0, 4.0], [14.0, 14.0], [3.0, 14.0], [3.0, 4.0]],
+                        [[10.0, 10.0], [11.0, 10.0], [11.0, 11.0], [10.0, 11.0], [10.0, 10.0]]
+                    ]
+                }
+            },
+            {
+                "type": "Feature",
+                "properties": { "NAME": "Benin", "ISO_A2": "BJ", "FIPS_10": "-99" },
+                "geometry": {
+                    "type": "MultiPolygon",
+                    "coordinates": [[[[0.5, 6.0], [3.0, 6.0], [3.0, 12.0], [0.5, 12.0], [0.5, 6.0]]]]
+                }
+            },
+            {
+                "type": "Feature",
+                "properties": { "NAME": "Aland", "ISO_A2": "AX", "FIPS_10": "-99" },
+                "geometry": {
+                    "type": "Polygon",
+                    "coordinates": [[[19.5, 59.8], [21.0, 59.8], [21.0, 60.5], [19.5, 60.5], [19.5, 59.8]]]
+                }
+            }
+        ]
+    }"#;
+
+    const ADM1: &str = r#"{
+        "type": "FeatureCollection",
+        "features": [
+            {
+                "type": "Feature",
+                "properties": { "name": "Lagos", "iso_3166_2": "NG-LA" },
+                "geometry": {
+                    "type": "Polygon",
+                    "coordinates": [[[3.0, 6.0], [4.5, 6.0], [4.5, 7.0], [3.0, 7.0], [3.0, 6.0]]]
+                }
+            }
+        ]
+    }"#;
+
+    fn geocoder() -> ReverseGeocoder {
+        let mut geocoder = ReverseGeocoder::new();
+        assert_eq!(geocoder.load_countries_str(COUNTRIES).unwrap(), 3);
+        assert_eq!(geocoder.load_adm1_str(ADM1).unwrap(), 1);
+        geocoder
+    }
+
+    fn geography(country: Option<&str>, latitude: f64, longitude: f64) -> Geography {
+        Geography {
+            location_type: Some(LocationType::WorldCity),
+            fullname: None,
+            country_code: country.and_then(|code| {
+                Coded::from_field(code, |code| {
+                    CountryZone::from_fips(code).ok_or_else(|| anyhow!("Invalid FIPS Code"))
+                })
+            }),
+            adm1_code: None,
+            adm2_code: None,
+            coordinates: Some(Coordinates {
+                latitude,
+                longitude,
+            }),
+            feature_id: None,
+        }
+    }
+
+    #[test]
+    fn test_point_lookup() {
+        // init_logger();
+
+        let geocoder = geocoder();
+        let abuja = Coordinates {
+            latitude: 9.05785,
+            longitude: 7.49508,
+        };
+        assert_eq!(geocoder.country_at(&abuja), Some(CountryZone::Nigeria));
+
+        // Natural Earth's -99 falls through to the ISO code
+        let cotonou = Coordinates {
+            latitude: 6.36536,
+            longitude: 2.41833,
+        };
+        assert_eq!(geocoder.country_at(&cotonou), Some(CountryZone::Benin));
+
+        let in_hole = Coordinates {
+            latitude: 10.5,
+            longitude: 10.5,
+        };
+        assert_eq!(geocoder.country_at(&in_hole), None);
+
+        let lagos = Coordinates {
+            latitude: 6.45,
+            longitude: 3.39,
+        };
+        assert_eq!(geocoder.adm1_at(&lagos).unwrap().0, "NI05");
+        info!(
+            "Lagos boundary: {:?}",
+            geocoder.adm1_boundary(&lagos).unwrap().name
+        );
+    }
+
+    #[test]
+    fn test_enrich_fills_missing_codes() {
+        // init_logger();
+
+        let geocoder = geocoder();
+
+        let mut lagos = geography(None, 6.45, 3.39);
+        let report = geocoder.enrich(&mut lagos);
+        assert_eq!(report.country, GeocodeCheck::Filled("NI".to_string()));
+        assert_eq!(report.adm1, GeocodeCheck::Filled("NI05".to_string()));
+        assert_eq!(lagos.country_code.unwrap(), CountryZone::Nigeria);
+        assert_eq!(lagos.adm1_code.unwrap().0, "NI05");
+
+        // Unmappable codes are reported and kept, and no ADM1 code is filled under them
+        let mut unknown = geography(Some("QQ"), 6.45, 3.39);
+        let report = geocoder.enrich(&mut unknown);
+        assert_eq!(
+            report.country,
+            GeocodeCheck::Unmapped {
+                raw: "QQ".to_string(),
+                located: "NI".to_string()
+            }
+        );
+        assert_eq!(report.adm1, GeocodeCheck::Unresolved);
+        assert_eq!(unknown.country_code.unwrap().raw(), "QQ");
+        assert!(unknown.adm1_code.is_none());
+
+        // A country without a FIPS code is located but can't be filled in
+        let mut mariehamn = geography(None, 60.1, 19.93);
+        let report = geocoder.enrich(&mut mariehamn);
+        assert_eq!(
+            report.country,
+            GeocodeCheck::NoFipsCode {
+                located: CountryZone::AlandIslands.to_string()
+            }
+        );
+        assert!(mariehamn.country_code.is_none());
+    }
+
+    #[test]
+    fn test_check_flags_mismatches() {
+        // init_logger();
+
+        let geocoder = geocoder();
+
+        let confirmed = geography(Some("NI"), 9.0, 7.5);
+        let report = geocoder.check(&confirmed);
+        assert_eq!(report.country, GeocodeCheck::Confirmed);
+        assert!(!report.has_mismatch());
+
+        let mut mismatched = geography(Some("GH"), 9.0, 7.5);
+        let report = geocoder.enrich(&mut mismatched);
+        assert!(report.has_mismatch());
+        assert_eq!(
+            report.country,
+            GeocodeCheck::Mismatch {
+                coded: "GH".to_string(),
+                located: "NI".to_string()
+            }
+        );
+        // Mismatches are reported, not overwritten
+        assert_eq!(mismatched.country_code.unwrap().raw(), "GH");
+
+        // No ADM1 code is filled in under a mismatched country
+        let mut angola = geography(Some("AO"), 6.45, 3.39);
+        let report = geocoder.enrich(&mut angola);
+        assert!(matches!(report.country, GeocodeCheck::Mismatch { .. }));
+        assert_eq!(report.adm1, GeocodeCheck::Unresolved);
+        assert!(angola.adm1_code.is_none());
+
+        let mut offshore = geography(Some("NI"), 0.0, 0.0);
+        offshore.coordinates = None;
+        let report = geocoder.check(&offshore);
+        assert_eq!(report.country, GeocodeCheck::Unresolved);
+    }
+}
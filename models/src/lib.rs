@@ -6,17 +6,25 @@
 // 3. Write the functionality for the parsing of NewType for Intermediary type (So instead of parsing from &str, parse from u128). Super low priority, but makes it easier for data creation later on. I dont want to take a number, convert it to a string, then convert it to a newtype. That is silly
 
 pub mod gcam;
+pub mod geo;
 pub mod types;
 
 // Re-export commonly used types from GCAM module
 pub use gcam::{
+    CodebookVersion,
+    CrossLanguageMap,
     Dictionary,
+    DictionaryRegistry,
     EnrichedGCAMEntry,
+    GCAMAnalysis,
+    GCAMBatchCoverage,
     GCAMCodebookDatabase,
     GCAMCodebookEntry,
     GCAMCodebookParser,
     GCAMCoverageStats,
     GCAMEntry, // Keep for backward compatibility
+    GCAMField,
+    GCAMSelection,
     Language,
     MeasurementType,
 };
@@ -5,10 +5,12 @@ use std::convert::TryFrom;
 
 use url::Url;
 
+use crate::types::lookup_types::african_region::AfricanRegion;
+use crate::types::lookup_types::coded::Coded;
 use crate::types::lookup_types::country::CountryZone;
 use crate::types::lookup_types::ethnicity::Ethnicity;
 use crate::types::lookup_types::event_action_description::top_level_actions::EventActionDescription;
-use crate::types::lookup_types::geography_type::GeographyType;
+use crate::types::lookup_types::geography_type::{GeographyType, LocationPrecision, LocationType};
 use crate::types::lookup_types::known_group::KnownGroup;
 use crate::types::lookup_types::quad_class::{Manner, QuadClass};
 use crate::types::lookup_types::religion::Religion;
@@ -82,14 +84,74 @@ pub struct CAMEOTypeCode(pub String);
 pub struct Actor {
     pub code: Option<CAMEOCode>,
     pub name: Option<String>,
-    pub country_code: Option<CountryZone>,
-    pub known_group_code: Option<KnownGroup>,
-    pub ethnic_code: Option<Ethnicity>,
-    pub religion1_code: Option<Religion>,
-    pub religion2_code: Option<Religion>,
-    pub type1_code: Option<ActorRole>,
-    pub type2_code: Option<ActorRole>,
-    pub type3_code: Option<ActorRole>,
+    pub country_code: Option<Coded<CountryZone>>,
+    pub known_group_code: Option<Coded<KnownGroup>>,
+    pub ethnic_code: Option<Coded<Ethnicity>>,
+    pub religion1_code: Option<Coded<Religion>>,
+    pub religion2_code: Option<Coded<Religion>>,
+    pub type1_code: Option<Coded<ActorRole>>,
+    pub type2_code: Option<Coded<ActorRole>>,
+    pub type3_code: Option<Coded<ActorRole>>,
+}
+
+impl Actor {
+    /// Roles from the type columns, followed by any role segments of the actor code
+    /// that aren't already listed.
+    ///
+    /// GDELT often leaves `type1/2/3_code` empty even when the actor code carries the
+    /// role (`NGAGOVMIL`).
+    pub fn roles(&self) -> Vec<ActorRole> {
+        let mut roles: Vec<ActorRole> = [&self.type1_code, &self.type2_code, &self.type3_code]
+            .into_iter()
+            .flatten()
+            .filter_map(|code| code.parsed().cloned())
+            .collect();
+        if let Some(code) = &self.code {
+            for role in code.parse().roles() {
+                if !roles.contains(role) {
+                    roles.push(role.clone());
+                }
+            }
+        }
+        roles
+    }
+
+    pub fn has_role(&self, role: &ActorRole) -> bool {
+        self.roles().contains(role)
+    }
+
+    /// Whether the actor's country code falls inside `region`
+    pub fn is_in_region(&self, region: AfricanRegion) -> bool {
+        self.country_code
+            .as_ref()
+            .and_then(|code| code.parsed())
+            .is_some_and(|zone| region.contains(*zone))
+    }
+
+    /// Raw codes on this actor that the lookup tables could not map
+    pub fn unmapped_codes(&self) -> Vec<&str> {
+        [
+            self.country_code.as_ref().map(|c| (c.raw(), c.is_mapped())),
+            self.known_group_code
+                .as_ref()
+                .map(|c| (c.raw(), c.is_mapped())),
+            self.ethnic_code.as_ref().map(|c| (c.raw(), c.is_mapped())),
+            self.religion1_code
+                .as_ref()
+                .map(|c| (c.raw(), c.is_mapped())),
+            self.religion2_code
+                .as_ref()
+                .map(|c| (c.raw(), c.is_mapped())),
+            self.type1_code.as_ref().map(|c| (c.raw(), c.is_mapped())),
+            self.type2_code.as_ref().map(|c| (c.raw(), c.is_mapped())),
+            self.type3_code.as_ref().map(|c| (c.raw(), c.is_mapped())),
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|(_, mapped)| !mapped)
+        .map(|(raw, _)| raw)
+        .collect()
+    }
 }
 
 // Event Action codes and metrics
@@ -120,7 +182,7 @@ pub struct AvgTone(pub f64);
 #[derive(Debug, Clone, PartialEq)]
 pub struct EventAction {
     pub is_root_event: bool,
-    pub event_code: Option<EventActionDescription>,
+    pub event_code: Option<Coded<EventActionDescription>>,
     pub event_base_code: EventBaseCode,
     pub event_root_code: EventRootCode,
     pub quad_class: QuadClass,
@@ -148,20 +210,88 @@ pub struct Coordinates {
     pub longitude: f64,
 }
 
+impl Coordinates {
+    /// In range and not exactly 0,0, which GDELT never geocodes to but broken
+    /// parsing produces
+    pub fn is_valid(&self) -> bool {
+        (-90.0..=90.0).contains(&self.latitude)
+            && (-180.0..=180.0).contains(&self.longitude)
+            && !(self.latitude == 0.0 && self.longitude == 0.0)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct FeatureID(pub String);
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Geography {
-    pub geo_type: Option<GeographyType>,
+    pub location_type: Option<LocationType>,
     pub fullname: Option<String>,
-    pub country_code: Option<CountryZone>,
+    pub country_code: Option<Coded<CountryZone>>,
     pub adm1_code: Option<ADM1Code>,
     pub adm2_code: Option<ADM2Code>,
     pub coordinates: Option<Coordinates>,
     pub feature_id: Option<FeatureID>,
 }
 
+impl Geography {
+    pub fn geo_type(&self) -> Option<GeographyType> {
+        self.location_type
+            .map(|location_type| location_type.geography_type())
+    }
+
+    pub fn precision(&self) -> Option<LocationPrecision> {
+        self.location_type
+            .map(|location_type| location_type.precision())
+    }
+
+    /// True when the location has a known type and usable coordinates
+    pub fn is_valid(&self) -> bool {
+        self.location_type.is_some() && self.plottable_coordinates().is_some()
+    }
+
+    /// Coordinates, if present and valid
+    pub fn plottable_coordinates(&self) -> Option<&Coordinates> {
+        self.coordinates
+            .as_ref()
+            .filter(|coordinates| coordinates.is_valid())
+    }
+
+    /// Whether the location's country code falls inside `region`
+    pub fn is_in_region(&self, region: AfricanRegion) -> bool {
+        self.country_code
+            .as_ref()
+            .and_then(|code| code.parsed())
+            .is_some_and(|zone| region.contains(*zone))
+    }
+
+    /// Check that the ADM1 code exists and belongs to the geography's country.
+    ///
+    /// A missing ADM1 code is fine; country-level codes (`"NI"`) only need to match
+    /// the country.
+    pub fn validate_adm1(&self) -> Result<()> {
+        let Some(adm1) = &self.adm1_code else {
+            return Ok(());
+        };
+
+        if let Some(country) = &self.country_code
+            && adm1.country_fips() != Some(country.raw())
+        {
+            return Err(anyhow::anyhow!(
+                "ADM1 code {} does not belong to country {}",
+                adm1.0,
+                country.raw()
+            ));
+        }
+
+        if !adm1.is_country_level() && adm1.entry().is_none() {
+            return Err(anyhow::anyhow!("Unknown ADM1 code: {}", adm1.0));
+        }
+
+        Ok(())
+    }
+}
+
 // Main EventTable structure - no duplicate data
 #[derive(Debug, Clone, PartialEq)]
 pub struct EventTable {
@@ -196,15 +326,6 @@ fn parse_optional_string(s: &str) -> Option<String> {
     }
 }
 
-fn parse_optional_u32(s: &str) -> Option<u32> {
-    let trimmed = s.trim();
-    if trimmed.is_empty() {
-        None
-    } else {
-        trimmed.parse().ok()
-    }
-}
-
 fn parse_optional_f64(s: &str) -> Option<f64> {
     let trimmed = s.trim();
     if trimmed.is_empty() {
@@ -214,9 +335,14 @@ fn parse_optional_f64(s: &str) -> Option<f64> {
     }
 }
 
-fn parse_coordinates(lat_str: &str, long_str: &str) -> Option<Coordinates> {
+/// Coordinates from latitude/longitude fields. Either field missing, unparseable or out
+/// of range gives `None`.
+pub(crate) fn parse_coordinates(lat_str: &str, long_str: &str) -> Option<Coordinates> {
     let lat = parse_optional_f64(lat_str)?;
     let long = parse_optional_f64(long_str)?;
+    if !(-90.0..=90.0).contains(&lat) || !(-180.0..=180.0).contains(&long) {
+        return None;
+    }
     Some(Coordinates {
         latitude: lat,
         longitude: long,
@@ -548,62 +674,33 @@ impl TryFrom<&[&str]> for Actor {
             ));
         }
 
-        // Parse country code and convert to CountryZone
-        let country_code = match parse_optional_string(fields[2]).map(CAMEOCountryCode) {
-            Some(code) => CountryZone::try_from(Some(code)).ok(),
-            None => None,
-        };
-
-        // Parse known group code and convert to KnownGroup
-        let known_group_code = match parse_optional_string(fields[3]).map(CAMEOKnownGroupCode) {
-            Some(code) => KnownGroup::try_from(Some(code)).ok(),
-            None => None,
-        };
-
-        // Parse ethnic code and convert to Ethnicity
-        let ethnic_code = match parse_optional_string(fields[4]).map(CAMEOEthnicCode) {
-            Some(code) => Ethnicity::try_from(Some(code)).ok(),
-            None => None,
-        };
-
-        // Parse religion codes and convert to Religion
-        let religion1_code = match parse_optional_string(fields[5]).map(CAMEOReligionCode) {
-            Some(code) => Religion::try_from(Some(code)).ok(),
-            None => None,
-        };
-
-        let religion2_code = match parse_optional_string(fields[6]).map(CAMEOReligionCode) {
-            Some(code) => Religion::try_from(Some(code)).ok(),
-            None => None,
-        };
-
-        // Parse type codes and convert to ActorRole
-        let type1_code = match parse_optional_string(fields[7]).map(CAMEOTypeCode) {
-            Some(code) => {
-                use crate::types::event_table::actor::CAMEORoleCode;
-                let role_code = CAMEORoleCode(code.0);
-                ActorRole::try_from(Some(role_code)).ok()
-            }
-            None => None,
-        };
-
-        let type2_code = match parse_optional_string(fields[8]).map(CAMEOTypeCode) {
-            Some(code) => {
-                use crate::types::event_table::actor::CAMEORoleCode;
-                let role_code = CAMEORoleCode(code.0);
-                ActorRole::try_from(Some(role_code)).ok()
-            }
-            None => None,
-        };
-
-        let type3_code = match parse_optional_string(fields[9]).map(CAMEOTypeCode) {
-            Some(code) => {
-                use crate::types::event_table::actor::CAMEORoleCode;
-                let role_code = CAMEORoleCode(code.0);
-                ActorRole::try_from(Some(role_code)).ok()
-            }
-            None => None,
-        };
+        use crate::types::event_table::actor::CAMEORoleCode;
+
+        // Each code keeps its raw value even when the lookup table doesn't know it
+        let country_code = Coded::from_field(fields[2], |code| {
+            CountryZone::try_from(Some(CAMEOCountryCode(code.to_string())))
+        });
+        let known_group_code = Coded::from_field(fields[3], |code| {
+            KnownGroup::try_from(Some(CAMEOKnownGroupCode(code.to_string())))
+        });
+        let ethnic_code = Coded::from_field(fields[4], |code| {
+            Ethnicity::try_from(Some(CAMEOEthnicCode(code.to_string())))
+        });
+        let religion1_code = Coded::from_field(fields[5], |code| {
+            Religion::try_from(Some(CAMEOReligionCode(code.to_string())))
+        });
+        let religion2_code = Coded::from_field(fields[6], |code| {
+            Religion::try_from(Some(CAMEOReligionCode(code.to_string())))
+        });
+        let type1_code = Coded::from_field(fields[7], |code| {
+            ActorRole::try_from(Some(CAMEORoleCode(code.to_string())))
+        });
+        let type2_code = Coded::from_field(fields[8], |code| {
+            ActorRole::try_from(Some(CAMEORoleCode(code.to_string())))
+        });
+        let type3_code = Coded::from_field(fields[9], |code| {
+            ActorRole::try_from(Some(CAMEORoleCode(code.to_string())))
+        });
 
         Ok(Actor {
             code: parse_optional_string(fields[0]).map(CAMEOCode),
@@ -651,19 +748,12 @@ impl TryFrom<&[&str]> for EventAction {
             }
         };
 
-        // Parse event code and convert to EventActionDescription
-        let event_code = {
+        // Parse event code and convert to EventActionDescription, keeping the raw code
+        let event_code = Coded::from_field(fields[1], |code| {
             use crate::types::event_table::event_action::CAMEOEventCode;
-            let field_value = if fields[1].trim().is_empty() {
-                None
-            } else {
-                Some(fields[1])
-            };
-            match CAMEOEventCode::try_from(field_value) {
-                Ok(code) => EventActionDescription::try_from(Some(code)).ok(),
-                Err(_) => None,
-            }
-        };
+            CAMEOEventCode::try_from(Some(code))
+                .and_then(|code| EventActionDescription::try_from(Some(code)))
+        });
 
         Ok(EventAction {
             is_root_event,
@@ -691,26 +781,18 @@ impl TryFrom<&[&str]> for Geography {
             ));
         }
 
-        // Parse geography type and convert to GeographyType
-        let geo_type = match fields[0].trim().parse::<u8>() {
-            Ok(type_code) => {
-                use crate::types::event_table::event_geography::GeographyTypeCode;
-                match GeographyTypeCode::try_from(Some(type_code.to_string().as_str())) {
-                    Ok(code) => GeographyType::try_from(Some(code)).ok(),
-                    Err(_) => None,
-                }
-            }
-            Err(_) => None,
-        };
+        // Keep the original type code (US vs world state/city)
+        let location_type = event_geography::GeographyTypeCode::try_from(Some(fields[0].trim()))
+            .ok()
+            .and_then(|code| LocationType::try_from(Some(code)).ok());
 
-        // Parse FIPS country code and convert to CountryZone
-        let country_code = match parse_optional_string(fields[2]).map(FIPSCountryCode) {
-            Some(code) => CountryZone::try_from(Some(code)).ok(),
-            None => None,
-        };
+        // Parse FIPS country code and convert to CountryZone, keeping the raw code
+        let country_code = Coded::from_field(fields[2], |code| {
+            CountryZone::try_from(Some(FIPSCountryCode(code.to_string())))
+        });
 
         Ok(Geography {
-            geo_type,
+            location_type,
             fullname: parse_optional_string(fields[1]),
             country_code,
             adm1_code: parse_optional_string(fields[3]).map(ADM1Code),
@@ -788,13 +870,61 @@ impl TryFrom<StringRecord> for EventTable {
     }
 }
 
+impl EventTable {
+    /// True when either actor or any of the three geographies falls inside `region`
+    pub fn involves_region(&self, region: AfricanRegion) -> bool {
+        [&self.actor1, &self.actor2]
+            .into_iter()
+            .flatten()
+            .any(|actor| actor.is_in_region(region))
+            || [
+                &self.actor1_geography,
+                &self.actor2_geography,
+                &self.action_geography,
+            ]
+            .into_iter()
+            .flatten()
+            .any(|geography| geography.is_in_region(region))
+    }
+
+    /// Every raw code in this event that could not be mapped to a lookup type.
+    ///
+    /// Useful for spotting codes GDELT has added since the lookup tables were written.
+    pub fn unmapped_codes(&self) -> Vec<&str> {
+        let mut codes = Vec::new();
+        for actor in [&self.actor1, &self.actor2].into_iter().flatten() {
+            codes.extend(actor.unmapped_codes());
+        }
+        if let Some(event_code) = &self.event_action.event_code
+            && !event_code.is_mapped()
+        {
+            codes.push(event_code.raw());
+        }
+        for geography in [
+            &self.actor1_geography,
+            &self.actor2_geography,
+            &self.action_geography,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if let Some(country) = &geography.country_code
+                && !country.is_mapped()
+            {
+                codes.push(country.raw());
+            }
+        }
+        codes
+    }
+}
+
 impl crate::types::DatabaseTable for EventTable {}
 
 #[cfg(test)]
 mod tests {
     use super::{
-        Actor, ActorRole, CountryZone, EventAction, EventDate, Geography, GeographyType, Manner,
-        QuadClass,
+        Actor, ActorRole, AfricanRegion, CountryZone, EventAction, EventDate, Geography,
+        GeographyType, LocationPrecision, LocationType, Manner, QuadClass,
     };
     use crate::types::lookup_types::religion::Religion;
 
@@ -809,7 +939,7 @@ mod tests {
 
         // Test fractional date (approximately)
         let fraction = date.fraction_date();
-        assert!(fraction >= 2025.0 && fraction < 2026.0);
+        assert!((2025.0..2026.0).contains(&fraction));
     }
 
     #[test]
@@ -834,6 +964,7 @@ mod tests {
             actor.country_code.as_ref().unwrap(),
             &CountryZone::UnitedStates
         );
+        assert_eq!(actor.country_code.as_ref().unwrap().raw(), "USA");
         assert!(actor.known_group_code.is_none());
         assert_eq!(actor.type1_code.as_ref().unwrap(), &ActorRole::Government);
     }
@@ -852,7 +983,10 @@ mod tests {
         ];
         let geo = Geography::try_from(fields.as_slice()).unwrap();
 
-        assert!(matches!(geo.geo_type, Some(GeographyType::City)));
+        assert!(matches!(geo.geo_type(), Some(GeographyType::City)));
+        assert_eq!(geo.location_type, Some(LocationType::USCity));
+        assert_eq!(geo.precision(), Some(LocationPrecision::City));
+        assert!(geo.is_valid());
         assert_eq!(
             geo.fullname.as_ref().unwrap(),
             "Washington, DC, United States"
@@ -861,12 +995,33 @@ mod tests {
             geo.country_code.as_ref().unwrap(),
             &CountryZone::UnitedStates
         );
+        assert_eq!(geo.country_code.as_ref().unwrap().raw(), "US");
         assert!(geo.coordinates.is_some());
         let coords = geo.coordinates.as_ref().unwrap();
         assert_eq!(coords.latitude, 38.9072);
         assert_eq!(coords.longitude, -77.0369);
     }
 
+    #[test]
+    fn test_geography_missing_coordinates() {
+        let fields = vec!["5", "Borno, Nigeria", "NI", "NI27", "", "", "", "-2014678"];
+        let geo = Geography::try_from(fields.as_slice()).unwrap();
+        assert_eq!(geo.location_type, Some(LocationType::WorldState));
+        assert_eq!(geo.precision(), Some(LocationPrecision::ADM1));
+        assert!(geo.coordinates.is_none());
+        assert!(!geo.is_valid());
+
+        let fields = vec!["4", "Nowhere", "NI", "", "", "0", "0", ""];
+        let geo = Geography::try_from(fields.as_slice()).unwrap();
+        assert!(geo.coordinates.is_some());
+        assert!(geo.plottable_coordinates().is_none());
+
+        let fields = vec!["", "", "", "", "", "91.5", "10", ""];
+        let geo = Geography::try_from(fields.as_slice()).unwrap();
+        assert!(geo.location_type.is_none());
+        assert!(geo.coordinates.is_none());
+    }
+
     #[test]
     fn test_event_action_quad_class() {
         let fields = vec!["1", "010", "01", "01", "1", "1.0", "5", "3", "8", "2.5"];
@@ -901,11 +1056,17 @@ mod tests {
 
         // Verify enums are properly parsed
         assert!(matches!(
-            actor.country_code,
+            actor.country_code.as_ref().and_then(|c| c.parsed()),
             Some(CountryZone::UnitedStates)
         ));
-        assert!(matches!(actor.religion1_code, Some(Religion::Christianity)));
-        assert!(matches!(actor.type1_code, Some(ActorRole::Government)));
+        assert!(matches!(
+            actor.religion1_code.as_ref().and_then(|c| c.parsed()),
+            Some(Religion::Christianity)
+        ));
+        assert!(matches!(
+            actor.type1_code.as_ref().and_then(|c| c.parsed()),
+            Some(ActorRole::Government)
+        ));
 
         // Verify None values for empty fields
         assert!(actor.known_group_code.is_none());
@@ -914,12 +1075,106 @@ mod tests {
         assert!(actor.type2_code.is_none());
         assert!(actor.type3_code.is_none());
     }
+
+    #[test]
+    fn test_actor_unmapped_codes_keep_raw() {
+        // Codes the lookup tables don't know should survive as raw strings
+        let fields = vec!["ZZZGOV", "", "ZZZ", "", "", "", "", "GOV", "QQQ", ""];
+        let actor = Actor::try_from(fields.as_slice()).unwrap();
+
+        let country = actor.country_code.as_ref().unwrap();
+        assert_eq!(country.raw(), "ZZZ");
+        assert!(country.parsed().is_none());
+        assert!(actor.type1_code.as_ref().unwrap().is_mapped());
+        assert_eq!(actor.unmapped_codes(), vec!["ZZZ", "QQQ"]);
+    }
+
+    #[test]
+    fn test_event_action_keeps_raw_event_code() {
+        let fields = vec!["1", "0101", "010", "01", "1", "1.0", "5", "3", "8", "2.5"];
+        let action = EventAction::try_from(fields.as_slice()).unwrap();
+        assert_eq!(action.event_code.as_ref().unwrap().raw(), "0101");
+
+        let fields = vec!["1", "999", "99", "99", "1", "1.0", "5", "3", "8", "2.5"];
+        let action = EventAction::try_from(fields.as_slice()).unwrap();
+        let event_code = action.event_code.as_ref().unwrap();
+        assert_eq!(event_code.raw(), "999");
+        assert!(!event_code.is_mapped());
+    }
+
+    #[test]
+    fn test_actor_region_predicates() {
+        let fields = vec!["NGAGOV", "NIGERIA", "NGA", "", "", "", "", "GOV", "", ""];
+        let actor = Actor::try_from(fields.as_slice()).unwrap();
+        assert!(actor.is_in_region(AfricanRegion::ECOWAS));
+        assert!(actor.is_in_region(AfricanRegion::AfricanUnion));
+        assert!(!actor.is_in_region(AfricanRegion::SADC));
+
+        // CAMEO's "West Africa" zone counts for the AU region but not for ECOWAS
+        let fields = vec!["WAF", "", "WAF", "", "", "", "", "", "", ""];
+        let actor = Actor::try_from(fields.as_slice()).unwrap();
+        assert!(actor.is_in_region(AfricanRegion::AUWesternAfrica));
+        assert!(!actor.is_in_region(AfricanRegion::ECOWAS));
+    }
+
+    #[test]
+    fn test_actor_roles_from_code() {
+        // Type columns empty, roles only in the actor code
+        let fields = vec!["NGAGOVMIL", "NIGERIA", "NGA", "", "", "", "", "", "", ""];
+        let actor = Actor::try_from(fields.as_slice()).unwrap();
+        assert!(actor.has_role(&ActorRole::Military));
+        assert_eq!(
+            actor.roles(),
+            vec![ActorRole::Government, ActorRole::Military]
+        );
+
+        let fields = vec!["NGAGOV", "NIGERIA", "NGA", "", "", "", "", "GOV", "", ""];
+        let actor = Actor::try_from(fields.as_slice()).unwrap();
+        assert_eq!(actor.roles(), vec![ActorRole::Government]);
+    }
+
+    #[test]
+    fn test_geography_adm1_validation() {
+        let fields = vec![
+            "4",
+            "Lagos, Lagos, Nigeria",
+            "NI",
+            "NI05",
+            "",
+            "6.45306",
+            "3.39583",
+            "-2014678",
+        ];
+        let geography = Geography::try_from(fields.as_slice()).unwrap();
+        assert!(geography.validate_adm1().is_ok());
+        assert_eq!(geography.adm1_code.as_ref().unwrap().name(), Some("Lagos"));
+
+        // ADM1 from a different country
+        let fields = vec![
+            "4",
+            "Lagos, Nigeria",
+            "GH",
+            "NI05",
+            "",
+            "6.45306",
+            "3.39583",
+            "-2014678",
+        ];
+        let geography = Geography::try_from(fields.as_slice()).unwrap();
+        assert!(geography.validate_adm1().is_err());
+
+        let fields = vec!["2", "Nowhere", "NI", "NI99", "", "", "", ""];
+        let geography = Geography::try_from(fields.as_slice()).unwrap();
+        assert!(geography.validate_adm1().is_err());
+
+        let fields = vec!["1", "Nigeria", "NI", "NI", "", "10", "8", "NI"];
+        let geography = Geography::try_from(fields.as_slice()).unwrap();
+        assert!(geography.validate_adm1().is_ok());
+    }
 }
 
 // Backward compatibility modules for other files that import from these paths
 pub mod actor {
-    use super::*;
-
     // Re-export types for backward compatibility
     pub type ActorName = String;
     pub use super::CAMEOCountryCode;
@@ -0,0 +1,192 @@
+//! Shared parser for GDELT's XML-encoded extras columns
+//!
+//! GKG `V2EXTRASXML` and the mentions `Extras` column both hold a flat run of
+//! `<TAG>text</TAG>` blocks rather than a well-formed document. GDELT adds tags over
+//! time, so every block is kept, in order, whether or not a caller knows its tag.
+
+use std::str::FromStr;
+
+/// One `<TAG>text</TAG>` block, with entities in the text decoded
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtrasBlock {
+    pub tag: String,
+    pub text: String,
+}
+
+/// The blocks of an extras field, in source order
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExtrasXml {
+    blocks: Vec<ExtrasBlock>,
+}
+
+impl ExtrasXml {
+    pub fn parse(s: &str) -> Self {
+        ExtrasXml {
+            blocks: split_blocks(s)
+                .into_iter()
+                .map(|(tag, text)| ExtrasBlock {
+                    tag: tag.trim().to_string(),
+                    text: decode_xml_entities(text.trim()),
+                })
+                .collect(),
+        }
+    }
+
+    pub fn blocks(&self) -> &[ExtrasBlock] {
+        &self.blocks
+    }
+
+    pub fn into_blocks(self) -> Vec<ExtrasBlock> {
+        self.blocks
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+
+    /// Text of the first block with this tag
+    pub fn get(&self, tag: &str) -> Option<&str> {
+        self.blocks
+            .iter()
+            .find(|block| block.tag == tag)
+            .map(|block| block.text.as_str())
+    }
+
+    /// Text of every block with this tag
+    pub fn get_all<'a>(&'a self, tag: &'a str) -> impl Iterator<Item = &'a str> {
+        self.blocks
+            .iter()
+            .filter(move |block| block.tag == tag)
+            .map(|block| block.text.as_str())
+    }
+
+    /// First block with this tag, parsed as `T`. `None` if missing or unparseable.
+    pub fn get_parsed<T: FromStr>(&self, tag: &str) -> Option<T> {
+        self.get(tag)?.parse().ok()
+    }
+
+    pub fn tags(&self) -> impl Iterator<Item = &str> {
+        self.blocks.iter().map(|block| block.tag.as_str())
+    }
+}
+
+/// Decode the XML entities GDELT escapes in extras text
+fn decode_xml_entities(s: &str) -> String {
+    let mut decoded = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        let tail = &rest[start..];
+        let entity = tail.find(';').map(|end| &tail[1..end]);
+        let replacement = match entity {
+            Some("amp") => Some('&'),
+            Some("lt") => Some('<'),
+            Some("gt") => Some('>'),
+            Some("quot") => Some('"'),
+            Some("apos") => Some('\''),
+            Some(numeric) if numeric.starts_with("#x") || numeric.starts_with("#X") => {
+                u32::from_str_radix(&numeric[2..], 16)
+                    .ok()
+                    .and_then(char::from_u32)
+            }
+            Some(numeric) if numeric.starts_with('#') => {
+                numeric[1..].parse::<u32>().ok().and_then(char::from_u32)
+            }
+            _ => None,
+        };
+        match (replacement, entity) {
+            (Some(c), Some(entity)) => {
+                decoded.push(c);
+                rest = &tail[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &tail[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Split an extras field into raw (tag, text) pairs.
+///
+/// Text between blocks is ignored, and a block whose closing tag is missing runs to
+/// the next opening tag or the end of the field.
+fn split_blocks(s: &str) -> Vec<(&str, &str)> {
+    let mut blocks = Vec::new();
+    let mut rest = s;
+    while let Some(open) = rest.find('<') {
+        let after_open = &rest[open + 1..];
+        let Some(close) = after_open.find('>') else {
+            break;
+        };
+        let tag = &after_open[..close];
+        let body = &after_open[close + 1..];
+        if tag.starts_with('/') || tag.is_empty() {
+            rest = body;
+            continue;
+        }
+
+        let closing = format!("</{}>", tag);
+        let (text, remainder) = match body.find(&closing) {
+            Some(end) => (&body[..end], &body[end + closing.len()..]),
+            None => {
+                let end = body.find('<').unwrap_or(body.len());
+                (&body[..end], &body[end..])
+            }
+        };
+        blocks.push((tag, text));
+        rest = remainder;
+    }
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use log::info;
+
+    #[allow(dead_code)]
+    fn init_logger() {
+        static INIT: std::sync::Once = std::sync::Once::new();
+        INIT.call_once(|| {
+            env_logger::init();
+        });
+    }
+
+    #[test]
+    fn test_extras_xml_blocks() {
+        // init_logger();
+
+        let xml = ExtrasXml::parse(
+            "<PAGE_TITLE>Aid &amp; relief</PAGE_TITLE><SCORE> 42 </SCORE><SCORE>7</SCORE>",
+        );
+        info!("Parsed extras: {:?}", xml);
+        assert_eq!(
+            xml.tags().collect::<Vec<_>>(),
+            vec!["PAGE_TITLE", "SCORE", "SCORE"]
+        );
+        assert_eq!(xml.get("PAGE_TITLE"), Some("Aid & relief"));
+        assert_eq!(xml.get_parsed::<u32>("SCORE"), Some(42));
+        assert_eq!(xml.get_all("SCORE").collect::<Vec<_>>(), vec!["42", "7"]);
+        assert_eq!(xml.get_parsed::<u32>("PAGE_TITLE"), None);
+        assert!(xml.get("MISSING").is_none());
+    }
+
+    #[test]
+    fn test_extras_xml_malformed_input() {
+        // init_logger();
+
+        assert!(ExtrasXml::parse("").is_empty());
+        assert!(ExtrasXml::parse("plain text").is_empty());
+        assert!(ExtrasXml::parse("<unterminated").is_empty());
+
+        // Missing closing tag runs to the next block
+        let xml = ExtrasXml::parse("<A>one<B>two</B>");
+        assert_eq!(xml.get("A"), Some("one"));
+        assert_eq!(xml.get("B"), Some("two"));
+
+        assert_eq!(decode_xml_entities("&#x41;&#66;&bogus;&"), "AB&bogus;&");
+    }
+}
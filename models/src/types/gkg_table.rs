@@ -4,18 +4,31 @@
 //! The GKG format contains enhanced semantic information extracted from news articles.
 
 use anyhow::{Result, anyhow};
-use chrono::{DateTime, TimeZone, Utc};
+use chrono::{DateTime, Datelike, Months, NaiveDate, TimeZone, Utc};
 use csv::StringRecord;
 
+use std::collections::HashMap;
 use std::fmt;
 use url::Url;
 
 use super::event_table::{
-    ADM1Code, ADM2Code, Coordinates, FIPSCountryCode, FeatureID, event_geography::EventGeography,
+    ADM1Code, ADM2Code, FIPSCountryCode, FeatureID,
+    event_geography::{EventGeography, GeographyTypeCode},
+    parse_coordinates,
 };
+use super::extras_xml::{ExtrasBlock, ExtrasXml};
+use super::lookup_types::african_region::AfricanRegion;
+use super::lookup_types::amount_unit::{
+    AmountCategory, AmountUnit, NormalizedAmount, normalize_amount,
+};
+use super::lookup_types::coded::Coded;
+use super::lookup_types::count_type::{CountCategory, CountType};
 use super::lookup_types::country::CountryZone;
-use super::lookup_types::geography_type::GeographyType;
-use crate::gcam::lookup::EnrichedGCAMEntry;
+use super::lookup_types::geography_type::LocationType;
+use super::lookup_types::gkg_theme::{GKGThemeEntry, ThemeFamily, theme_label, theme_path};
+use crate::gcam::analysis::GCAMAnalysis;
+use crate::gcam::field::{GCAMField, GCAMSelection};
+use crate::gcam::lookup::{EnrichedGCAMEntry, Language};
 use crate::gcam::memory_database::GCAMCodebookDatabase;
 
 /// Character offset within a document
@@ -64,26 +77,290 @@ pub struct Theme {
     pub offset: Option<CharOffset>,
 }
 
+impl Theme {
+    pub fn family(&self) -> ThemeFamily {
+        ThemeFamily::of(&self.name)
+    }
+
+    pub fn path(&self) -> Vec<&str> {
+        theme_path(&self.name)
+    }
+
+    pub fn label(&self) -> String {
+        theme_label(&self.name)
+    }
+
+    /// Entry in GDELT's published theme list, if the theme is on it
+    pub fn entry(&self) -> Option<&'static GKGThemeEntry> {
+        GKGThemeEntry::lookup(&self.name)
+    }
+}
+
 /// Count entry from V1COUNTS or V2.1COUNTS
 #[derive(Debug, Clone, PartialEq)]
 pub struct Count {
-    pub count_type: String,
+    pub count_type: CountType,
     pub count: u64,
     pub object_type: Option<String>,
     pub location: Option<EventGeography>,
     pub offset: Option<CharOffset>,
 }
 
+impl Count {
+    pub fn category(&self) -> CountCategory {
+        self.count_type.category()
+    }
+}
+
+/// Country and ADM1 code a count total is keyed on, as they appeared in the record
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CountLocation {
+    pub country_code: Option<String>,
+    pub adm1_code: Option<String>,
+}
+
+impl CountLocation {
+    pub fn of(location: Option<&EventGeography>) -> Self {
+        CountLocation {
+            country_code: location
+                .and_then(|geo| geo.country_code.as_ref())
+                .map(|code| code.raw().to_string()),
+            adm1_code: location
+                .and_then(|geo| geo.adm1_code.as_ref())
+                .map(|code| code.0.clone()),
+        }
+    }
+
+    pub fn country(&self) -> Option<CountryZone> {
+        self.country_code
+            .as_deref()
+            .and_then(CountryZone::from_fips)
+    }
+}
+
+/// Count totals summed by count type and location across GKG records
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CountAggregate {
+    totals: HashMap<(CountType, CountLocation), u64>,
+}
+
+impl CountAggregate {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_records<'a>(records: impl IntoIterator<Item = &'a GKGTable>) -> Self {
+        let mut aggregate = Self::new();
+        for record in records {
+            aggregate.add_record(record);
+        }
+        aggregate
+    }
+
+    pub fn add_record(&mut self, record: &GKGTable) {
+        for count in record.counts() {
+            self.add(count);
+        }
+    }
+
+    pub fn add(&mut self, count: &Count) {
+        let key = (
+            count.count_type.clone(),
+            CountLocation::of(count.location.as_ref()),
+        );
+        *self.totals.entry(key).or_default() += count.count;
+    }
+
+    pub fn get(&self, count_type: &CountType, location: &CountLocation) -> u64 {
+        self.totals
+            .get(&(count_type.clone(), location.clone()))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Total for a count type across every location
+    pub fn total(&self, count_type: &CountType) -> u64 {
+        self.sum_where(|key_type, _| key_type == count_type)
+    }
+
+    pub fn total_for_category(&self, category: CountCategory) -> u64 {
+        self.sum_where(|key_type, _| key_type.category() == category)
+    }
+
+    /// Total for a category over locations whose country falls inside `region`
+    pub fn total_in_region(&self, category: CountCategory, region: AfricanRegion) -> u64 {
+        self.sum_where(|key_type, location| {
+            key_type.category() == category
+                && location.country().is_some_and(|zone| region.contains(zone))
+        })
+    }
+
+    /// Totals per location for one count type, largest first
+    pub fn by_location(&self, count_type: &CountType) -> Vec<(&CountLocation, u64)> {
+        let mut totals: Vec<(&CountLocation, u64)> = self
+            .totals
+            .iter()
+            .filter(|((key_type, _), _)| key_type == count_type)
+            .map(|((_, location), total)| (location, *total))
+            .collect();
+        totals.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        totals
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&CountType, &CountLocation, u64)> {
+        self.totals
+            .iter()
+            .map(|((count_type, location), total)| (count_type, location, *total))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.totals.is_empty()
+    }
+
+    fn sum_where(&self, keep: impl Fn(&CountType, &CountLocation) -> bool) -> u64 {
+        self.totals
+            .iter()
+            .filter(|((count_type, location), _)| keep(count_type, location))
+            .map(|(_, total)| *total)
+            .sum()
+    }
+}
+
+/// Resolution of a V2.1ENHANCEDDATES entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateResolution {
+    Year = 1,
+    Month = 2,
+    Day = 3,
+    /// Month and day given, year left out ("on March 3rd")
+    MonthDay = 4,
+}
+
+impl TryFrom<u8> for DateResolution {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            1 => Ok(Self::Year),
+            2 => Ok(Self::Month),
+            3 => Ok(Self::Day),
+            4 => Ok(Self::MonthDay),
+            _ => Err(anyhow!("Invalid date resolution: {}", value)),
+        }
+    }
+}
+
 /// Enhanced date with resolution and offset
 #[derive(Debug, Clone, PartialEq)]
 pub struct EnhancedDate {
-    pub resolution: u8, // 1=year, 2=month, 3=day, 4=month-day without year
+    pub resolution: DateResolution,
     pub month: u8,
     pub day: u8,
     pub year: u16,
     pub offset: CharOffset,
 }
 
+/// A date known only to its resolution
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartialDate {
+    Year(i32),
+    YearMonth(i32, u32),
+    Date(NaiveDate),
+    /// Month and day with no year
+    MonthDay(u32, u32),
+}
+
+impl PartialDate {
+    /// First and last day the date covers. `None` for `MonthDay`.
+    pub fn span(&self) -> Option<(NaiveDate, NaiveDate)> {
+        match *self {
+            PartialDate::Year(year) => Some((
+                NaiveDate::from_ymd_opt(year, 1, 1)?,
+                NaiveDate::from_ymd_opt(year, 12, 31)?,
+            )),
+            PartialDate::YearMonth(year, month) => {
+                let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+                let next = first.checked_add_months(Months::new(1))?;
+                Some((first, next.pred_opt()?))
+            }
+            PartialDate::Date(date) => Some((date, date)),
+            PartialDate::MonthDay(..) => None,
+        }
+    }
+}
+
+impl fmt::Display for PartialDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PartialDate::Year(year) => write!(f, "{:04}", year),
+            PartialDate::YearMonth(year, month) => write!(f, "{:04}-{:02}", year, month),
+            PartialDate::Date(date) => write!(f, "{}", date.format("%Y-%m-%d")),
+            PartialDate::MonthDay(month, day) => write!(f, "--{:02}-{:02}", month, day),
+        }
+    }
+}
+
+impl EnhancedDate {
+    /// The date as written, without resolving a missing year. `None` if the numbers
+    /// do not form a real date.
+    pub fn partial_date(&self) -> Option<PartialDate> {
+        let (year, month, day) = (self.year as i32, self.month as u32, self.day as u32);
+        match self.resolution {
+            DateResolution::Year => Some(PartialDate::Year(year)),
+            DateResolution::Month => {
+                NaiveDate::from_ymd_opt(year, month, 1).map(|_| PartialDate::YearMonth(year, month))
+            }
+            DateResolution::Day => NaiveDate::from_ymd_opt(year, month, day).map(PartialDate::Date),
+            // 2000 is a leap year, so February 29th passes
+            DateResolution::MonthDay => {
+                NaiveDate::from_ymd_opt(2000, month, day).map(|_| PartialDate::MonthDay(month, day))
+            }
+        }
+    }
+
+    /// The date with a missing year filled in from `reference`, usually the article date.
+    ///
+    /// Month-day dates take whichever of the previous, same or next year puts them
+    /// closest to the reference, so "January 5th" in a late-December article is read as
+    /// the coming January.
+    pub fn resolve(&self, reference: NaiveDate) -> Option<PartialDate> {
+        match self.partial_date()? {
+            PartialDate::MonthDay(month, day) => (reference.year() - 1..=reference.year() + 1)
+                .filter_map(|year| NaiveDate::from_ymd_opt(year, month, day))
+                .min_by_key(|date| (*date - reference).num_days().abs())
+                .map(PartialDate::Date),
+            date => Some(date),
+        }
+    }
+
+    /// Day-level date, resolving a missing year against `reference`
+    pub fn to_naive_date(&self, reference: NaiveDate) -> Option<NaiveDate> {
+        match self.resolve(reference)? {
+            PartialDate::Date(date) => Some(date),
+            _ => None,
+        }
+    }
+}
+
+/// Where a referenced date falls relative to publication
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateRelation {
+    Past,
+    /// The referenced period contains the publication date
+    Current,
+    Future,
+}
+
+/// A date an article mentions, placed relative to the article's publication date
+#[derive(Debug, Clone, PartialEq)]
+pub struct DateReference {
+    pub date: PartialDate,
+    pub relation: DateRelation,
+    /// Days from publication to the start of the referenced period
+    pub days_from_publication: i64,
+    pub offset: CharOffset,
+}
+
 /// V1.5TONE structure
 #[derive(Debug, Clone, PartialEq)]
 pub struct Tone {
@@ -120,6 +397,74 @@ pub struct Amount {
     pub offset: CharOffset,
 }
 
+impl Amount {
+    /// Amount with scale words applied, its object classified and converted to a base unit
+    pub fn normalize(&self) -> NormalizedAmount {
+        normalize_amount(self.amount, self.object.as_deref())
+    }
+}
+
+/// Normalized amounts summed by category and unit across GKG records
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AmountAggregate {
+    totals: HashMap<(AmountCategory, AmountUnit), f64>,
+}
+
+impl AmountAggregate {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_records<'a>(records: impl IntoIterator<Item = &'a GKGTable>) -> Self {
+        let mut aggregate = Self::new();
+        for record in records {
+            aggregate.add_record(record);
+        }
+        aggregate
+    }
+
+    pub fn add_record(&mut self, record: &GKGTable) {
+        for amount in &record.amounts {
+            self.add(&amount.normalize());
+        }
+    }
+
+    pub fn add(&mut self, amount: &NormalizedAmount) {
+        *self
+            .totals
+            .entry((amount.category, amount.unit))
+            .or_default() += amount.value;
+    }
+
+    pub fn total(&self, category: AmountCategory, unit: AmountUnit) -> f64 {
+        self.totals.get(&(category, unit)).copied().unwrap_or(0.0)
+    }
+
+    /// Currency totals by ISO 4217 code, largest first. Ambiguous currencies are left out.
+    pub fn currency_totals(&self) -> Vec<(&'static str, f64)> {
+        let mut totals: Vec<(&'static str, f64)> = self
+            .totals
+            .iter()
+            .filter_map(|((_, unit), total)| match unit {
+                AmountUnit::Currency(Some(code)) => Some((*code, *total)),
+                _ => None,
+            })
+            .collect();
+        totals.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        totals
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (AmountCategory, AmountUnit, f64)> + '_ {
+        self.totals
+            .iter()
+            .map(|((category, unit), total)| (*category, *unit, *total))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.totals.is_empty()
+    }
+}
+
 /// Translation information
 #[derive(Debug, Clone, PartialEq)]
 pub struct TranslationInfo {
@@ -127,6 +472,23 @@ pub struct TranslationInfo {
     pub engine: Option<String>,
 }
 
+/// Page metadata from V2EXTRASXML
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GKGExtras {
+    /// `<PAGE_TITLE>`
+    pub page_title: Option<String>,
+    /// `<PAGE_AUTHORS>`, split into individual names
+    pub page_authors: Vec<String>,
+    /// `<PAGE_PRECISEPUBTIMESTAMP>`
+    pub precise_pub_timestamp: Option<DateTime<Utc>>,
+    /// `<PAGE_LINKS>`
+    pub page_links: Vec<Url>,
+    /// `<PAGE_ALTURL_AMP>`
+    pub alt_url_amp: Option<Url>,
+    /// Blocks not listed above
+    pub other: Vec<ExtrasBlock>,
+}
+
 /// Main GKG Table structure according to V2.1 specification
 #[derive(Debug, Clone, PartialEq)]
 pub struct GKGTable {
@@ -181,8 +543,8 @@ pub struct GKGTable {
     /// V2.1ENHANCEDDATES - Date references with offsets
     pub enhanced_dates: Vec<EnhancedDate>,
 
-    /// V2GCAM - Global Content Analysis Measures (Enhanced)
-    pub gcam: Vec<EnrichedGCAMEntry>,
+    /// V2GCAM - Global Content Analysis Measures (Enhanced), parsed on first access
+    pub gcam: GCAMField,
 
     /// V2.1SHARINGIMAGE - Sharing image URL
     pub sharing_image: Option<Url>,
@@ -205,6 +567,9 @@ pub struct GKGTable {
     /// V2.1AMOUNTS - Numeric amounts with objects and offsets
     pub amounts: Vec<Amount>,
 
+    /// V2EXTRASXML - Page title, authors, precise timestamp and links
+    pub extras: Option<GKGExtras>,
+
     /// V2.1TRANSLATIONINFO - Translation provenance information
     pub translation_info: Option<TranslationInfo>,
 }
@@ -230,6 +595,23 @@ impl GKGTable {
         &self.source_common_name
     }
 
+    /// Country of the publishing outlet, from the country-code domain of the source name
+    ///
+    /// Outlets on generic domains (`.com`, `.org`, ...) give `None`.
+    pub fn source_country(&self) -> Option<CountryZone> {
+        country_from_domain(&self.source_common_name)
+    }
+
+    /// The location mentioned first in the article: the lowest-offset V2 enhanced
+    /// location, or the first V1 location for records without them
+    pub fn primary_location(&self) -> Option<&EventGeography> {
+        self.v2_enhanced_locations
+            .iter()
+            .min_by_key(|(_, offset)| offset.0)
+            .map(|(location, _)| location)
+            .or_else(|| self.v1_locations.first())
+    }
+
     /// Get the document identifier
     pub fn document_identifier(&self) -> &str {
         &self.document_identifier
@@ -245,6 +627,24 @@ impl GKGTable {
         &self.v2_counts
     }
 
+    /// Get counts, preferring V2.1 and falling back to V1.
+    ///
+    /// The two fields repeat the same counts, so they are never combined.
+    pub fn counts(&self) -> &[Count] {
+        if self.v2_counts.is_empty() {
+            &self.v1_counts
+        } else {
+            &self.v2_counts
+        }
+    }
+
+    pub fn counts_by_category(&self, category: CountCategory) -> Vec<&Count> {
+        self.counts()
+            .iter()
+            .filter(|count| count.category() == category)
+            .collect()
+    }
+
     /// Get V1 themes (simple themes without offsets)
     pub fn v1_themes(&self) -> &[String] {
         &self.v1_themes
@@ -290,9 +690,56 @@ impl GKGTable {
         &self.enhanced_dates
     }
 
-    /// Get GCAM data
+    /// Dates the article refers to, resolved against and placed relative to its
+    /// publication date, in document order
+    pub fn date_references(&self) -> Vec<DateReference> {
+        let published = self.date.date_naive();
+        self.enhanced_dates
+            .iter()
+            .filter_map(|enhanced| {
+                let date = enhanced.resolve(published)?;
+                let (start, end) = date.span()?;
+                let relation = if end < published {
+                    DateRelation::Past
+                } else if start > published {
+                    DateRelation::Future
+                } else {
+                    DateRelation::Current
+                };
+                Some(DateReference {
+                    date,
+                    relation,
+                    days_from_publication: (start - published).num_days(),
+                    offset: enhanced.offset.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Referenced dates that fall after publication, soonest first
+    pub fn upcoming_dates(&self) -> Vec<DateReference> {
+        let mut upcoming: Vec<DateReference> = self
+            .date_references()
+            .into_iter()
+            .filter(|reference| reference.relation == DateRelation::Future)
+            .collect();
+        upcoming.sort_by_key(|reference| reference.days_from_publication);
+        upcoming
+    }
+
+    /// Get GCAM data, parsing the field on first access
     pub fn gcam(&self) -> &[EnrichedGCAMEntry] {
-        &self.gcam
+        self.gcam.entries()
+    }
+
+    /// Interpret the GCAM values: densities per word, paired scores and per-dictionary vectors
+    pub fn gcam_analysis(&self) -> GCAMAnalysis {
+        GCAMAnalysis::from_entries(self.gcam())
+    }
+
+    /// Restrict which GCAM dimensions are materialized, e.g. to LIWC only
+    pub fn select_gcam(&mut self, selection: GCAMSelection) {
+        self.gcam.select(selection);
     }
 
     /// Get GCAM entries by dictionary type
@@ -300,7 +747,7 @@ impl GKGTable {
         &self,
         dictionary: &crate::gcam::lookup::Dictionary,
     ) -> Vec<&EnrichedGCAMEntry> {
-        self.gcam
+        self.gcam()
             .iter()
             .filter(|entry| {
                 entry
@@ -312,9 +759,17 @@ impl GKGTable {
             .collect()
     }
 
+    /// Get V2 enhanced themes belonging to a theme family
+    pub fn themes_by_family(&self, family: ThemeFamily) -> Vec<&Theme> {
+        self.v2_enhanced_themes
+            .iter()
+            .filter(|theme| theme.family() == family)
+            .collect()
+    }
+
     /// Get GCAM entries with available metadata
     pub fn gcam_with_metadata(&self) -> Vec<&EnrichedGCAMEntry> {
-        self.gcam
+        self.gcam()
             .iter()
             .filter(|entry| entry.metadata.is_some())
             .collect()
@@ -322,15 +777,39 @@ impl GKGTable {
 
     /// Get GCAM entries without metadata (unknown variables)
     pub fn gcam_without_metadata(&self) -> Vec<&EnrichedGCAMEntry> {
-        self.gcam
+        self.gcam()
             .iter()
             .filter(|entry| entry.metadata.is_none())
             .collect()
     }
 
+    /// Language the article was written in: the `srclc` of its translation info, or
+    /// English for records from the untranslated stream
+    pub fn source_language(&self) -> Language {
+        self.translation_info
+            .as_ref()
+            .and_then(|info| info.source_language_code.as_deref())
+            .map(|code| Language::from(code.trim().to_lowercase().as_str()))
+            .unwrap_or(Language::English)
+    }
+
+    /// GCAM entries from dictionaries in the article's source language
+    pub fn gcam_in_source_language(&self) -> Vec<&EnrichedGCAMEntry> {
+        let language = self.source_language();
+        self.gcam()
+            .iter()
+            .filter(|entry| {
+                entry
+                    .metadata
+                    .as_ref()
+                    .is_some_and(|meta| meta.language == language)
+            })
+            .collect()
+    }
+
     /// Get GCAM coverage statistics
     pub fn gcam_coverage_stats(&self) -> crate::gcam::GCAMCoverageStats {
-        let total_entries = self.gcam.len();
+        let total_entries = self.gcam().len();
         let entries_with_metadata = self.gcam_with_metadata().len();
         let entries_without_metadata = self.gcam_without_metadata().len();
 
@@ -383,6 +862,28 @@ impl GKGTable {
         &self.amounts
     }
 
+    /// Get V2EXTRASXML page metadata
+    pub fn extras(&self) -> Option<&GKGExtras> {
+        self.extras.as_ref()
+    }
+
+    /// Get the article title from V2EXTRASXML
+    pub fn page_title(&self) -> Option<&str> {
+        self.extras.as_ref()?.page_title.as_deref()
+    }
+
+    /// Get the article authors from V2EXTRASXML
+    pub fn page_authors(&self) -> &[String] {
+        self.extras
+            .as_ref()
+            .map_or(&[], |extras| extras.page_authors.as_slice())
+    }
+
+    /// Get amounts normalized into categories and base units
+    pub fn normalized_amounts(&self) -> Vec<NormalizedAmount> {
+        self.amounts.iter().map(Amount::normalize).collect()
+    }
+
     /// Get translation info
     pub fn translation_info(&self) -> Option<&TranslationInfo> {
         self.translation_info.as_ref()
@@ -541,7 +1042,7 @@ impl fmt::Display for GKGTable {
 
         // Show GCAM info if available
         if !self.gcam.is_empty() {
-            writeln!(f, "  GCAM Measures ({} entries)", self.gcam.len())?;
+            writeln!(f, "  GCAM Measures ({} entries)", self.gcam().len())?;
         }
 
         // Show sharing image
@@ -578,6 +1079,14 @@ impl fmt::Display for GKGTable {
             writeln!(f, "  Amounts: {} items", self.amounts.len())?;
         }
 
+        // Show page title and authors
+        if let Some(title) = self.page_title() {
+            writeln!(f, "  Title: {}", title)?;
+        }
+        if !self.page_authors().is_empty() {
+            writeln!(f, "  Authors: {}", self.page_authors().join(", "))?;
+        }
+
         // Show translation info
         if let Some(ref translation) = self.translation_info {
             writeln!(f, "  Translation: {:?}", translation)?;
@@ -589,6 +1098,16 @@ impl fmt::Display for GKGTable {
 
 impl super::DatabaseTable for GKGTable {}
 
+/// Trimmed field, or `None` when empty
+fn parse_optional_field(s: &str) -> Option<String> {
+    let trimmed = s.trim();
+    if trimmed.is_empty() {
+        None
+    } else {
+        Some(trimmed.to_string())
+    }
+}
+
 /// Parse floating point with default fallback
 fn parse_f32_or_default(s: &str) -> Result<f32> {
     if s.is_empty() {
@@ -616,7 +1135,7 @@ fn parse_counts(s: &str, with_offsets: bool) -> Result<Vec<Count>> {
             continue; // Skip malformed count entries
         }
 
-        let count_type = parts[0].to_string();
+        let count_type = CountType::from(parts[0]);
         let count = parts[1].parse::<u64>().unwrap_or(0);
         let object_type = if parts[2].is_empty() {
             None
@@ -626,7 +1145,7 @@ fn parse_counts(s: &str, with_offsets: bool) -> Result<Vec<Count>> {
 
         // Parse location if present (parts 3-9)
         let location = if parts.len() > 9 {
-            EventGeography::try_from_gkg_parts(&parts[3..]).ok()
+            EventGeography::try_from_gkg_parts(&parts[3..10], false).ok()
         } else {
             None
         };
@@ -738,42 +1257,11 @@ fn parse_simple_list(s: &str) -> Vec<String> {
 }
 
 /// Parse GCAM data
+#[cfg(test)]
 fn parse_gcam(s: &str) -> Vec<EnrichedGCAMEntry> {
-    parse_gcam_with_database(s, None)
-}
-
-fn parse_gcam_with_database(
-    s: &str,
-    gcam_db: Option<&GCAMCodebookDatabase>,
-) -> Vec<EnrichedGCAMEntry> {
-    if s.is_empty() {
-        return vec![];
-    }
-
-    let mut entries = Vec::new();
-    for entry_str in s.split(',') {
-        if entry_str.is_empty() {
-            continue;
-        }
-
-        let parts: Vec<&str> = entry_str.split(':').collect();
-        if parts.len() != 2 {
-            continue;
-        }
-
-        let key = parts[0].to_string();
-        if let Ok(value) = parts[1].parse::<f32>() {
-            let enriched_entry = if let Some(db) = gcam_db {
-                db.enrich_gcam_entry(&key, value)
-                    .unwrap_or_else(|_| EnrichedGCAMEntry::from_simple(key, value))
-            } else {
-                EnrichedGCAMEntry::from_simple(key, value)
-            };
-            entries.push(enriched_entry);
-        }
-    }
-
-    entries
+    GCAMField::new(s, None, GCAMSelection::All)
+        .entries()
+        .to_vec()
 }
 
 /// Parse URLs from semicolon-delimited format
@@ -877,7 +1365,7 @@ fn parse_locations_v1(s: &str) -> Vec<EventGeography> {
             continue; // Need at least 7 parts for V1 format
         }
 
-        if let Ok(geography) = EventGeography::try_from_gkg_parts(&parts) {
+        if let Ok(geography) = EventGeography::try_from_gkg_parts(&parts, false) {
             locations.push(geography);
         }
     }
@@ -907,7 +1395,7 @@ fn parse_locations_v2(s: &str) -> Vec<(EventGeography, CharOffset)> {
 
         // Parse geography from all parts except the last (offset)
         let geo_parts = &parts[..parts.len() - 1];
-        if let Ok(geography) = EventGeography::try_from_gkg_parts(geo_parts) {
+        if let Ok(geography) = EventGeography::try_from_gkg_parts(geo_parts, true) {
             locations.push((geography, CharOffset(offset)));
         }
     }
@@ -915,6 +1403,21 @@ fn parse_locations_v2(s: &str) -> Vec<(EventGeography, CharOffset)> {
     locations
 }
 
+/// Country-code top-level domains used as generic domains rather than by the country
+const GENERIC_CCTLDS: &[&str] = &["io", "tv", "fm", "me", "cc", "ws", "ai"];
+
+/// Country of a domain's country-code TLD, e.g. `dailytrust.com.ng` -> Nigeria
+fn country_from_domain(domain: &str) -> Option<CountryZone> {
+    let tld = domain.trim().trim_end_matches('.').rsplit('.').next()?;
+    let tld = tld.to_lowercase();
+    if tld.len() != 2 || GENERIC_CCTLDS.contains(&tld.as_str()) {
+        return None;
+    }
+    // .uk is the one ccTLD that differs from the ISO code
+    let iso = if tld == "uk" { "GB" } else { &tld };
+    CountryZone::from_iso_alpha2(&iso.to_uppercase())
+}
+
 /// Parse enhanced dates with resolution and offsets
 fn parse_enhanced_dates(s: &str) -> Vec<EnhancedDate> {
     if s.is_empty() {
@@ -927,12 +1430,20 @@ fn parse_enhanced_dates(s: &str) -> Vec<EnhancedDate> {
             continue;
         }
 
-        let parts: Vec<&str> = date_str.split(',').collect();
+        // GDELT delimits the fields with '#'; older extracts use ','
+        let separator = if date_str.contains('#') { '#' } else { ',' };
+        let parts: Vec<&str> = date_str.split(separator).collect();
         if parts.len() < 5 {
             continue; // Need at least resolution, month, day, year, offset
         }
 
-        let resolution = parts[0].parse::<u8>().unwrap_or(0);
+        let Some(resolution) = parts[0]
+            .parse::<u8>()
+            .ok()
+            .and_then(|code| DateResolution::try_from(code).ok())
+        else {
+            continue; // Skip entries with an unknown resolution
+        };
         let month = parts[1].parse::<u8>().unwrap_or(0);
         let day = parts[2].parse::<u8>().unwrap_or(0);
         let year = parts[3].parse::<u16>().unwrap_or(0);
@@ -950,6 +1461,52 @@ fn parse_enhanced_dates(s: &str) -> Vec<EnhancedDate> {
     dates
 }
 
+/// Split a PAGE_AUTHORS value into names, dropping a leading "By"
+fn parse_page_authors(s: &str) -> Vec<String> {
+    s.split([',', ';', '|'])
+        .flat_map(|part| part.split(" and "))
+        .map(|name| {
+            let name = name.trim();
+            name.strip_prefix("By ")
+                .or_else(|| name.strip_prefix("by "))
+                .unwrap_or(name)
+                .trim()
+        })
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Parse the V2EXTRASXML field
+fn parse_extras_xml(s: &str) -> Option<GKGExtras> {
+    if s.trim().is_empty() {
+        return None;
+    }
+
+    let mut extras = GKGExtras::default();
+    for ExtrasBlock { tag, text } in ExtrasXml::parse(s).into_blocks() {
+        match tag.as_str() {
+            "PAGE_TITLE" => extras.page_title = Some(text).filter(|title| !title.is_empty()),
+            "PAGE_AUTHORS" => extras.page_authors = parse_page_authors(&text),
+            "PAGE_PRECISEPUBTIMESTAMP" => {
+                extras.precise_pub_timestamp =
+                    chrono::NaiveDateTime::parse_from_str(&text, "%Y%m%d%H%M%S")
+                        .ok()
+                        .map(|ndt| Utc.from_utc_datetime(&ndt))
+            }
+            "PAGE_LINKS" => extras.page_links = parse_urls(&text),
+            "PAGE_ALTURL_AMP" => extras.alt_url_amp = Url::parse(&text).ok(),
+            _ => extras.other.push(ExtrasBlock { tag, text }),
+        }
+    }
+
+    if extras == GKGExtras::default() {
+        None
+    } else {
+        Some(extras)
+    }
+}
+
 /// Parse translation info from semicolon-delimited format
 fn parse_translation_info(s: &str) -> Option<TranslationInfo> {
     if s.is_empty() {
@@ -990,6 +1547,17 @@ impl GKGTable {
     pub fn try_from_with_optional_gcam_db(
         record: StringRecord,
         gcam_db: Option<&GCAMCodebookDatabase>,
+    ) -> Result<Self, anyhow::Error> {
+        Self::try_from_with_gcam_selection(record, gcam_db, GCAMSelection::All)
+    }
+
+    /// Create GKGTable from StringRecord, materializing only the selected GCAM dimensions
+    ///
+    /// The GCAM field is kept raw either way and only parsed when first accessed.
+    pub fn try_from_with_gcam_selection(
+        record: StringRecord,
+        gcam_db: Option<&GCAMCodebookDatabase>,
+        gcam_selection: GCAMSelection,
     ) -> Result<Self, anyhow::Error> {
         // GKG V2.1 format has variable number of fields, but minimum 16 for core data
         if record.len() < 16 {
@@ -1009,7 +1577,8 @@ impl GKGTable {
             ));
         }
         let record_date_str = compound_field_parts[0];
-        let sequence_str = compound_field_parts[1];
+        // Translingual records mark the sequence with a `T`, e.g. `20150218230000-T52`
+        let sequence_str = compound_field_parts[1].trim_start_matches('T');
 
         // Parse V2SOURCECOLLECTIONIDENTIFIER from field 2
         let source_collection_identifier = SourceCollectionIdentifier::try_from(
@@ -1023,7 +1592,7 @@ impl GKGTable {
             let tone_parts: Vec<&str> = fields[15].split(',').collect();
             Tone {
                 tone: tone_parts
-                    .get(0)
+                    .first()
                     .map_or(Ok(0.0), |s| parse_f32_or_default(s))?,
                 positive_score: tone_parts
                     .get(1)
@@ -1087,7 +1656,7 @@ impl GKGTable {
             v2_counts: parse_counts(fields.get(6).map_or("", |s| s), true)?,
 
             // Field 7: V1THEMES (semicolon-delimited)
-            v1_themes: parse_simple_list(fields.get(7).map_or("", |s| s)),
+            v1_themes: parse_themes_v1(fields.get(7).map_or("", |s| s)),
 
             // Field 8: V2ENHANCEDTHEMES (with character offsets)
             v2_enhanced_themes: parse_themes_v2(fields.get(8).map_or("", |s| s)),
@@ -1117,7 +1686,7 @@ impl GKGTable {
             enhanced_dates: parse_enhanced_dates(fields.get(16).map_or("", |s| s)),
 
             // Field 17: V2GCAM (comma-delimited blocks with colon key/value pairs)
-            gcam: parse_gcam_with_database(fields.get(17).map_or("", |s| s), gcam_db),
+            gcam: GCAMField::new(fields.get(17).map_or("", |s| s), gcam_db, gcam_selection),
 
             // Field 18: V2.1SHARINGIMAGE (textual URL)
             sharing_image: fields
@@ -1143,6 +1712,9 @@ impl GKGTable {
             // Field 24: V2.1AMOUNTS (semicolon-delimited blocks with comma-separated fields)
             amounts: parse_amounts(fields.get(24).map_or("", |s| s)),
 
+            // Field 25: V2EXTRASXML (XML-style blocks)
+            extras: parse_extras_xml(fields.get(25).map_or("", |s| s)),
+
             // Field 26: V2.1TRANSLATIONINFO (semicolon-delimited fields)
             translation_info: parse_translation_info(fields.get(26).map_or("", |s| s)),
         })
@@ -1162,73 +1734,49 @@ impl TryFrom<StringRecord> for GKGTable {
 }
 
 impl EventGeography {
-    /// Parse EventGeography from GKG location parts
-    fn try_from_gkg_parts(parts: &[&str]) -> Result<Self> {
-        if parts.len() < 7 {
-            return Err(anyhow!("Insufficient location parts"));
+    /// Parse EventGeography from GKG location parts.
+    ///
+    /// V1LOCATIONS and the COUNTS locations use
+    /// `Type#FullName#CountryCode#ADM1Code#Latitude#Longitude#FeatureID`;
+    /// V2ENHANCEDLOCATIONS adds ADM2Code after ADM1Code. The layout is chosen by the
+    /// caller rather than guessed from the number of parts.
+    fn try_from_gkg_parts(parts: &[&str], with_adm2: bool) -> Result<Self> {
+        let expected = if with_adm2 { 8 } else { 7 };
+        if parts.len() < expected {
+            return Err(anyhow!(
+                "Expected {} location parts, got {}",
+                expected,
+                parts.len()
+            ));
         }
 
-        // GKG location format: Type#FullName#CountryCode#ADM1Code#Latitude#Longitude#FeatureID
-        // Enhanced format adds ADM2Code between ADM1Code and Latitude
-
-        let location_type = parts[0].parse::<u8>().unwrap_or(0);
-        let full_name = parts[1].to_string();
-        let country_code = parts[2].to_string();
-        let adm1_code = parts[3].to_string();
-
-        // Check if this is enhanced format with ADM2
-        let (adm2_code, lat_idx, lon_idx, feature_idx) = if parts.len() > 8 {
-            // Enhanced format with ADM2
-            (Some(parts[4].to_string()), 5, 6, 7)
+        let (adm2_code, lat_idx) = if with_adm2 {
+            (parse_optional_field(parts[4]), 5)
         } else {
-            // Standard format without ADM2
-            (None, 4, 5, 6)
+            (None, 4)
         };
 
-        let latitude = parts[lat_idx].parse::<f64>().unwrap_or(0.0);
-        let longitude = parts[lon_idx].parse::<f64>().unwrap_or(0.0);
-        let feature_id = parts.get(feature_idx).unwrap_or(&"").to_string();
-
-        // Parse geo_type from location_type
-        let geo_type = match location_type {
-            1 => Some(GeographyType::Country),
-            2 => Some(GeographyType::State),
-            3 => Some(GeographyType::City),
-            4 => Some(GeographyType::City),
-            5 => Some(GeographyType::State),
-            _ => None,
-        };
+        let location_type = GeographyTypeCode::try_from(Some(parts[0].trim()))
+            .ok()
+            .and_then(|code| LocationType::try_from(Some(code)).ok());
+        let fullname = parse_optional_field(parts[1]);
+        let country_code = Coded::from_field(parts[2], |code| {
+            CountryZone::try_from(Some(FIPSCountryCode(code.to_string())))
+        });
+
+        // Counts without a location carry type 0 and empty fields
+        if location_type.is_none() && fullname.is_none() && country_code.is_none() {
+            return Err(anyhow!("Empty location"));
+        }
 
         Ok(EventGeography {
-            geo_type,
-            fullname: if full_name.is_empty() {
-                None
-            } else {
-                Some(full_name)
-            },
-            country_code: if country_code.is_empty() {
-                None
-            } else {
-                match CountryZone::try_from(Some(FIPSCountryCode(country_code))) {
-                    Ok(zone) => Some(zone),
-                    Err(_) => None,
-                }
-            },
-            adm1_code: if adm1_code.is_empty() {
-                None
-            } else {
-                Some(ADM1Code(adm1_code))
-            },
+            location_type,
+            fullname,
+            country_code,
+            adm1_code: parse_optional_field(parts[3]).map(ADM1Code),
             adm2_code: adm2_code.map(ADM2Code),
-            coordinates: Some(Coordinates {
-                latitude,
-                longitude,
-            }),
-            feature_id: if feature_id.is_empty() {
-                None
-            } else {
-                Some(FeatureID(feature_id))
-            },
+            coordinates: parse_coordinates(parts[lat_idx], parts[lat_idx + 1]),
+            feature_id: parse_optional_field(parts[lat_idx + 2]).map(FeatureID),
         })
     }
 }
@@ -1236,6 +1784,7 @@ impl EventGeography {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::lookup_types::geography_type::{GeographyType, LocationPrecision};
     use csv::StringRecord;
 
     fn init_logger() {
@@ -1279,7 +1828,8 @@ mod tests {
         let counts_str = "KILL#47#jihadists#1#Country#US#US#40#-100#12345";
         let counts = parse_counts(counts_str, false).unwrap();
         assert_eq!(counts.len(), 1);
-        assert_eq!(counts[0].count_type, "KILL");
+        assert_eq!(counts[0].count_type, CountType::Kill);
+        assert_eq!(counts[0].category(), CountCategory::Violence);
         assert_eq!(counts[0].count, 47);
         assert_eq!(counts[0].object_type, Some("jihadists".to_string()));
         assert!(counts[0].offset.is_none());
@@ -1290,12 +1840,50 @@ mod tests {
         let counts_str = "PROTEST#126#protesters#2#State#US#CA#37#-122#67890#150";
         let counts = parse_counts(counts_str, true).unwrap();
         assert_eq!(counts.len(), 1);
-        assert_eq!(counts[0].count_type, "PROTEST");
+        assert_eq!(counts[0].count_type, CountType::Protest);
         assert_eq!(counts[0].count, 126);
         assert_eq!(counts[0].object_type, Some("protesters".to_string()));
         assert_eq!(counts[0].offset.as_ref().unwrap().0, 150);
     }
 
+    #[test]
+    fn test_count_aggregate() {
+        let counts = parse_counts(
+            "KILL#12#civilians#4#Maiduguri, Borno, Nigeria#NI#NI27#11.84#13.15#-2014678#40;\
+             KILL#3#soldiers#4#Maiduguri, Borno, Nigeria#NI#NI27#11.84#13.15#-2014678#90;\
+             CRISISLEX_T09_DISPLACEDRELOCATEDEVACUATED#2000#people#1#Kenya#KE#KE#1#38#KE#150;\
+             ARREST#5#protesters#1#Kenya#KE#KE#1#38#KE#200;\
+             WOUND#7#people#0#####0#0##250",
+            true,
+        )
+        .unwrap();
+        let mut aggregate = CountAggregate::new();
+        for count in &counts {
+            aggregate.add(count);
+        }
+
+        let borno = CountLocation {
+            country_code: Some("NI".to_string()),
+            adm1_code: Some("NI27".to_string()),
+        };
+        assert_eq!(aggregate.get(&CountType::Kill, &borno), 15);
+        assert_eq!(aggregate.total(&CountType::Kill), 15);
+        assert_eq!(aggregate.total_for_category(CountCategory::Violence), 22);
+        assert_eq!(aggregate.total_for_category(CountCategory::Detention), 5);
+        assert_eq!(
+            aggregate.total_in_region(CountCategory::Displacement, AfricanRegion::EAC),
+            2000
+        );
+        assert_eq!(
+            aggregate.total_in_region(CountCategory::Violence, AfricanRegion::ECOWAS),
+            15
+        );
+        assert_eq!(
+            aggregate.by_location(&CountType::Wound)[0].0,
+            &CountLocation::default()
+        );
+    }
+
     #[test]
     fn test_parse_themes_v1() {
         let themes_str = "THEME1;THEME2;THEME3";
@@ -1317,6 +1905,20 @@ mod tests {
         assert_eq!(themes[1].offset.as_ref().unwrap().0, 300);
     }
 
+    #[test]
+    fn test_theme_taxonomy() {
+        let themes = parse_themes_v2("WB_2433_CONFLICT_AND_VIOLENCE,12;TAX_FNCACT_PRESIDENT,40");
+        assert_eq!(themes[0].family(), ThemeFamily::WorldBank);
+        assert_eq!(themes[0].label(), "Conflict and Violence");
+        assert_eq!(
+            themes[0].path(),
+            vec!["WB", "2433", "CONFLICT_AND_VIOLENCE"]
+        );
+        assert!(themes[0].entry().is_some());
+        assert_eq!(themes[1].family(), ThemeFamily::Taxonomy);
+        assert_eq!(themes[1].label(), "President");
+    }
+
     #[test]
     fn test_parse_named_entities() {
         let entities_str = "John Smith,100;Jane Doe,200;Bob Johnson,300";
@@ -1340,7 +1942,7 @@ mod tests {
         assert_eq!(gcam[2].key, "c10.1");
         assert_eq!(gcam[2].value, 40.0);
         assert_eq!(gcam[3].key, "v10.1");
-        assert_eq!(gcam[3].value, 3.21111111);
+        assert_eq!(gcam[3].value, 3.211_111);
     }
 
     #[test]
@@ -1383,21 +1985,21 @@ mod tests {
         let gkg_table = GKGTable::try_from(record).expect("Failed to parse GKG table");
 
         // Verify GCAM entries were enriched
-        assert_eq!(gkg_table.gcam.len(), 4);
+        assert_eq!(gkg_table.gcam().len(), 4);
 
         // Check that known variables were enriched
-        let c1_1_entry = gkg_table.gcam.iter().find(|e| e.key == "c1.1").unwrap();
+        let c1_1_entry = gkg_table.gcam().iter().find(|e| e.key == "c1.1").unwrap();
         assert!(c1_1_entry.metadata.is_some(), "c1.1 should have metadata");
 
-        let v19_1_entry = gkg_table.gcam.iter().find(|e| e.key == "v19.1").unwrap();
+        let v19_1_entry = gkg_table.gcam().iter().find(|e| e.key == "v19.1").unwrap();
         assert!(v19_1_entry.metadata.is_some(), "v19.1 should have metadata");
 
-        let v20_1_entry = gkg_table.gcam.iter().find(|e| e.key == "v20.1").unwrap();
+        let v20_1_entry = gkg_table.gcam().iter().find(|e| e.key == "v20.1").unwrap();
         assert!(v20_1_entry.metadata.is_some(), "v20.1 should have metadata");
 
         // Check that unknown variables have no metadata
         let unknown_entry = gkg_table
-            .gcam
+            .gcam()
             .iter()
             .find(|e| e.key == "unknown_var")
             .unwrap();
@@ -1443,6 +2045,88 @@ mod tests {
         assert_eq!(amounts[0].offset.0, 100);
     }
 
+    #[test]
+    fn test_amount_aggregate() {
+        let amounts = parse_amounts(
+            "2.5,million dollars,10;500000,dollars,20;12,tons of maize,30;3,thousand tonnes of rice,40;\
+             150,naira,50;80,displaced people,60",
+        );
+        let mut aggregate = AmountAggregate::new();
+        for amount in &amounts {
+            aggregate.add(&amount.normalize());
+        }
+
+        assert_eq!(
+            aggregate.total(AmountCategory::Currency, AmountUnit::Currency(Some("USD"))),
+            3_000_000.0
+        );
+        assert_eq!(
+            aggregate.total(AmountCategory::Mass, AmountUnit::Kilograms),
+            3_012_000.0
+        );
+        assert_eq!(
+            aggregate.total(AmountCategory::People, AmountUnit::Count),
+            80.0
+        );
+        assert_eq!(
+            aggregate.currency_totals(),
+            vec![("USD", 3_000_000.0), ("NGN", 150.0)]
+        );
+    }
+
+    #[test]
+    fn test_parse_extras_xml() {
+        let extras_str = "<PAGE_LINKS>https://example.com/a;https://example.com/b</PAGE_LINKS>\
+            <PAGE_AUTHORS>By Amina Yusuf, John Okafor and Grace Mensah</PAGE_AUTHORS>\
+            <PAGE_PRECISEPUBTIMESTAMP>20240312084500</PAGE_PRECISEPUBTIMESTAMP>\
+            <PAGE_TITLE>Flooding displaces &amp; strands thousands in Borno &#8211; officials</PAGE_TITLE>\
+            <PAGE_ALTURL_AMP>https://example.com/amp/a</PAGE_ALTURL_AMP>";
+        let extras = parse_extras_xml(extras_str).unwrap();
+        assert_eq!(
+            extras.page_title.as_deref(),
+            Some("Flooding displaces & strands thousands in Borno \u{2013} officials")
+        );
+        assert_eq!(
+            extras.page_authors,
+            vec!["Amina Yusuf", "John Okafor", "Grace Mensah"]
+        );
+        assert_eq!(
+            extras.precise_pub_timestamp,
+            Some(Utc.with_ymd_and_hms(2024, 3, 12, 8, 45, 0).unwrap())
+        );
+        assert_eq!(extras.page_links.len(), 2);
+        assert_eq!(
+            extras.alt_url_amp.as_ref().map(Url::as_str),
+            Some("https://example.com/amp/a")
+        );
+        assert!(extras.other.is_empty());
+    }
+
+    #[test]
+    fn test_parse_extras_xml_tolerates_malformed_blocks() {
+        // Unclosed title, unknown block, stray text and a bad timestamp
+        let extras = parse_extras_xml(
+            "junk<PAGE_TITLE>Untitled &unknown; page<PAGE_EXTRA>x</PAGE_EXTRA>\
+             <PAGE_PRECISEPUBTIMESTAMP>not a date</PAGE_PRECISEPUBTIMESTAMP>",
+        )
+        .unwrap();
+        assert_eq!(
+            extras.page_title.as_deref(),
+            Some("Untitled &unknown; page")
+        );
+        assert_eq!(
+            extras.other,
+            vec![ExtrasBlock {
+                tag: "PAGE_EXTRA".to_string(),
+                text: "x".to_string()
+            }]
+        );
+        assert!(extras.precise_pub_timestamp.is_none());
+
+        assert!(parse_extras_xml("").is_none());
+        assert!(parse_extras_xml("<PAGE_AUTHORS></PAGE_AUTHORS>").is_none());
+    }
+
     #[test]
     fn test_parse_translation_info() {
         let trans_str = "srclc:fra;eng:Moses 2.1.1 / MosesCore Europarl fr-en / GT-FRA 1.0";
@@ -1462,19 +2146,24 @@ mod tests {
 
         // Check first location (Australia - country level)
         assert!(matches!(
-            locations[0].geo_type,
+            locations[0].geo_type(),
             Some(GeographyType::Country)
         ));
         assert_eq!(locations[0].fullname, Some("Australia".to_string()));
-        // Note: country_code is now CountryZone enum, not a string wrapper
+        // Note: country_code keeps the raw FIPS code alongside the CountryZone
         assert!(locations[0].country_code.is_some());
 
         // Check second location (Brisbane - world city)
-        assert!(matches!(locations[1].geo_type, Some(GeographyType::City)));
+        assert!(matches!(locations[1].geo_type(), Some(GeographyType::City)));
+        assert_eq!(locations[1].location_type, Some(LocationType::WorldCity));
         assert_eq!(
             locations[1].fullname,
             Some("Brisbane, Queensland, Australia".to_string())
         );
+        assert_eq!(
+            locations[1].feature_id,
+            Some(FeatureID("-1561728".to_string()))
+        );
     }
 
     #[test]
@@ -1484,20 +2173,76 @@ mod tests {
         assert_eq!(dates.len(), 2);
 
         // Check first date (day-level resolution)
-        assert_eq!(dates[0].resolution, 3);
+        assert_eq!(dates[0].resolution, DateResolution::Day);
         assert_eq!(dates[0].month, 3);
         assert_eq!(dates[0].day, 15);
         assert_eq!(dates[0].year, 2024);
         assert_eq!(dates[0].offset.0, 150);
 
         // Check second date (year-level resolution)
-        assert_eq!(dates[1].resolution, 1);
+        assert_eq!(dates[1].resolution, DateResolution::Year);
         assert_eq!(dates[1].month, 0);
         assert_eq!(dates[1].day, 0);
         assert_eq!(dates[1].year, 2023);
         assert_eq!(dates[1].offset.0, 300);
     }
 
+    #[test]
+    fn test_enhanced_date_resolution() {
+        let dates = parse_enhanced_dates("4#1#5#0#10;4#2#29#0#20;2#2#0#2024#30;9#1#1#2024#40");
+        assert_eq!(dates.len(), 3);
+        assert_eq!(dates[0].resolution, DateResolution::MonthDay);
+        assert_eq!(dates[0].partial_date(), Some(PartialDate::MonthDay(1, 5)));
+
+        // "January 5th" in a late-December article is the coming January
+        let published = NaiveDate::from_ymd_opt(2024, 12, 28).unwrap();
+        assert_eq!(
+            dates[0].to_naive_date(published),
+            NaiveDate::from_ymd_opt(2025, 1, 5)
+        );
+        // February 29th only resolves into a leap year
+        assert_eq!(
+            dates[1].to_naive_date(published),
+            NaiveDate::from_ymd_opt(2024, 2, 29)
+        );
+        assert_eq!(
+            dates[2].resolve(published).unwrap().span(),
+            Some((
+                NaiveDate::from_ymd_opt(2024, 2, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()
+            ))
+        );
+        assert_eq!(dates[2].to_naive_date(published), None);
+        assert_eq!(dates[2].partial_date().unwrap().to_string(), "2024-02");
+    }
+
+    #[test]
+    fn test_date_references() {
+        let mut fields = vec![""; 27];
+        fields[0] = "20241228120000-0";
+        fields[1] = "20241228120000";
+        fields[2] = "1";
+        fields[16] = "3#2#15#2025#100;1#0#0#2024#200;4#1#5#0#300;2#11#0#2024#400";
+        let gkg = GKGTable::try_from(StringRecord::from(fields)).unwrap();
+
+        let references = gkg.date_references();
+        assert_eq!(references.len(), 4);
+        assert_eq!(references[0].relation, DateRelation::Future);
+        assert_eq!(references[1].relation, DateRelation::Current);
+        assert_eq!(references[3].relation, DateRelation::Past);
+        assert_eq!(references[3].days_from_publication, -57);
+
+        // Soonest first: the year-less January 5th, then the February election
+        let upcoming = gkg.upcoming_dates();
+        assert_eq!(upcoming.len(), 2);
+        assert_eq!(upcoming[0].days_from_publication, 8);
+        assert_eq!(upcoming[0].offset.0, 300);
+        assert_eq!(
+            upcoming[1].date,
+            PartialDate::Date(NaiveDate::from_ymd_opt(2025, 2, 15).unwrap())
+        );
+    }
+
     #[test]
     fn test_parse_locations_v2() {
         let locations_str = "1#Australian#AS#AS##-25#135#AS#57;4#Brisbane, Queensland, Australia#AS#AS04#154654#-27.5#153.017#-1561728#98";
@@ -1506,16 +2251,50 @@ mod tests {
 
         // Check first location with offset
         assert!(matches!(
-            locations[0].0.geo_type,
+            locations[0].0.geo_type(),
             Some(GeographyType::Country)
         ));
         assert_eq!(locations[0].1.0, 57);
 
         // Check second location with offset
-        assert!(matches!(locations[1].0.geo_type, Some(GeographyType::City)));
+        assert!(matches!(
+            locations[1].0.geo_type(),
+            Some(GeographyType::City)
+        ));
+        assert_eq!(
+            locations[1].0.adm2_code,
+            Some(ADM2Code("154654".to_string()))
+        );
         assert_eq!(locations[1].1.0, 98);
     }
 
+    #[test]
+    fn test_parse_locations_without_coordinates() {
+        let locations =
+            parse_locations_v2("3#Springfield, Illinois, United States#US#USIL##a##1#20");
+        assert_eq!(locations.len(), 1);
+        let (springfield, _) = &locations[0];
+        assert_eq!(springfield.location_type, Some(LocationType::USCity));
+        assert!(springfield.location_type.unwrap().is_us());
+        assert_eq!(springfield.precision(), Some(LocationPrecision::City));
+        assert!(springfield.coordinates.is_none());
+        assert!(!springfield.is_valid());
+
+        // A count whose location is blank has no location rather than a point at 0,0
+        let counts = parse_counts("KILL#4#people#0#######120", true).unwrap();
+        assert!(counts[0].location.is_none());
+        assert_eq!(counts[0].offset.as_ref().unwrap().0, 120);
+
+        // V2.1 count locations have no ADM2 even though the offset makes 11 parts
+        let counts =
+            parse_counts("WOUND#2#people#5#Kano, Nigeria#NI#NI29#12#8.5#-2#300", true).unwrap();
+        let kano = counts[0].location.as_ref().unwrap();
+        assert_eq!(kano.location_type, Some(LocationType::WorldState));
+        assert!(kano.adm2_code.is_none());
+        assert_eq!(kano.feature_id, Some(FeatureID("-2".to_string())));
+        assert!(kano.is_valid());
+    }
+
     #[test]
     fn test_gkg_table_parsing_with_minimal_fields() {
         init_logger();
@@ -0,0 +1,309 @@
+//! Decomposition of concatenated CAMEO actor codes
+//!
+//! GDELT actor codes are built from 3-character CAMEO segments, e.g. `NGAGOVMIL`
+//! (Nigeria / government / military) or `IGOUNOKID` (IGO / United Nations / UNICEF).
+//! Each segment is resolved against the lookup tables in a fixed order, because many
+//! tokens are valid in more than one of them:
+//!
+//! - First segment: country or region, then the international role prefixes, then
+//!   religion, known group, role and finally ethnicity. `PSE` and `SHN` are therefore
+//!   read as countries here.
+//! - Later segments: role, then known group, religion, country and ethnicity. `AMN`,
+//!   `IRC` and `UNO` are both roles and known groups and come back as roles. `COP`,
+//!   `MNC` and `NGO` are also ethnic codes but are read as roles. Religion codes win
+//!   over the ethnic codes they collide with (`CHR`, `MOS`, `CTH`, ...).
+
+use std::fmt;
+
+use super::country::CountryZone;
+use super::ethnicity::Ethnicity;
+use super::known_group::KnownGroup;
+use super::religion::Religion;
+use super::role::ActorRole;
+use crate::types::event_table::{
+    CAMEOCode, CAMEOCountryCode, CAMEOEthnicCode, CAMEOKnownGroupCode, CAMEOReligionCode,
+    actor::CAMEORoleCode,
+};
+
+/// Role codes that mark an actor as international/transnational when they lead the code
+const INTERNATIONAL_PREFIXES: &[&str] = &["IGO", "NGO", "NGM", "IMG", "INT", "MNC"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ActorCodeSegment {
+    Country(CountryZone),
+    KnownGroup(KnownGroup),
+    Ethnicity(Ethnicity),
+    Religion(Religion),
+    Role(ActorRole),
+    /// A segment none of the lookup tables recognise, kept verbatim
+    Unknown(String),
+}
+
+/// Whether an actor is tied to a single state, a CAMEO region, or is international
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActorScope {
+    Domestic,
+    Regional,
+    International,
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedActorCode {
+    pub segments: Vec<ActorCodeSegment>,
+    pub scope: ActorScope,
+}
+
+fn as_country(token: &str) -> Option<ActorCodeSegment> {
+    CountryZone::try_from(Some(CAMEOCountryCode(token.to_string())))
+        .ok()
+        .map(ActorCodeSegment::Country)
+}
+
+fn as_known_group(token: &str) -> Option<ActorCodeSegment> {
+    KnownGroup::try_from(Some(CAMEOKnownGroupCode(token.to_string())))
+        .ok()
+        .map(ActorCodeSegment::KnownGroup)
+}
+
+fn as_ethnicity(token: &str) -> Option<ActorCodeSegment> {
+    // The ethnic codebook is lowercase
+    Ethnicity::try_from(Some(CAMEOEthnicCode(token.to_lowercase())))
+        .ok()
+        .map(ActorCodeSegment::Ethnicity)
+}
+
+fn as_religion(token: &str) -> Option<ActorCodeSegment> {
+    Religion::try_from(Some(CAMEOReligionCode(token.to_string())))
+        .ok()
+        .map(ActorCodeSegment::Religion)
+}
+
+fn as_role(token: &str) -> Option<ActorCodeSegment> {
+    ActorRole::try_from(Some(CAMEORoleCode(token.to_string())))
+        .ok()
+        .map(ActorCodeSegment::Role)
+}
+
+fn resolve_segment(token: &str, first: bool) -> ActorCodeSegment {
+    let resolved = if first {
+        as_country(token)
+            .or_else(|| {
+                INTERNATIONAL_PREFIXES
+                    .contains(&token)
+                    .then(|| as_role(token))
+                    .flatten()
+            })
+            .or_else(|| as_religion(token))
+            .or_else(|| as_known_group(token))
+            .or_else(|| as_role(token))
+            .or_else(|| as_ethnicity(token))
+    } else {
+        as_role(token)
+            .or_else(|| as_known_group(token))
+            .or_else(|| as_religion(token))
+            .or_else(|| as_country(token))
+            .or_else(|| as_ethnicity(token))
+    };
+    resolved.unwrap_or_else(|| ActorCodeSegment::Unknown(token.to_string()))
+}
+
+impl ParsedActorCode {
+    pub fn parse(code: &str) -> Self {
+        let code = code.trim().to_uppercase();
+        let segments: Vec<ActorCodeSegment> = code
+            .as_bytes()
+            .chunks(3)
+            .enumerate()
+            .map(|(i, chunk)| match std::str::from_utf8(chunk) {
+                Ok(token) if chunk.len() == 3 => resolve_segment(token, i == 0),
+                Ok(token) => ActorCodeSegment::Unknown(token.to_string()),
+                Err(_) => ActorCodeSegment::Unknown(String::from_utf8_lossy(chunk).into_owned()),
+            })
+            .collect();
+
+        let scope = match segments.first() {
+            Some(ActorCodeSegment::Country(zone)) if zone.is_supranational() => {
+                ActorScope::Regional
+            }
+            Some(ActorCodeSegment::Country(_)) => ActorScope::Domestic,
+            Some(ActorCodeSegment::Role(_)) if INTERNATIONAL_PREFIXES.contains(&&code[..3]) => {
+                ActorScope::International
+            }
+            Some(ActorCodeSegment::KnownGroup(_)) => ActorScope::International,
+            _ => ActorScope::Unknown,
+        };
+
+        ParsedActorCode { segments, scope }
+    }
+
+    pub fn country(&self) -> Option<CountryZone> {
+        self.segments.iter().find_map(|segment| match segment {
+            ActorCodeSegment::Country(zone) => Some(*zone),
+            _ => None,
+        })
+    }
+
+    pub fn roles(&self) -> Vec<&ActorRole> {
+        self.segments
+            .iter()
+            .filter_map(|segment| match segment {
+                ActorCodeSegment::Role(role) => Some(role),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn known_groups(&self) -> Vec<&KnownGroup> {
+        self.segments
+            .iter()
+            .filter_map(|segment| match segment {
+                ActorCodeSegment::KnownGroup(group) => Some(group),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn religions(&self) -> Vec<&Religion> {
+        self.segments
+            .iter()
+            .filter_map(|segment| match segment {
+                ActorCodeSegment::Religion(religion) => Some(religion),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn ethnicities(&self) -> Vec<&Ethnicity> {
+        self.segments
+            .iter()
+            .filter_map(|segment| match segment {
+                ActorCodeSegment::Ethnicity(ethnicity) => Some(ethnicity),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn has_role(&self, role: &ActorRole) -> bool {
+        self.roles().contains(&role)
+    }
+
+    /// True when every segment was recognised
+    pub fn is_fully_resolved(&self) -> bool {
+        !self
+            .segments
+            .iter()
+            .any(|segment| matches!(segment, ActorCodeSegment::Unknown(_)))
+    }
+}
+
+impl CAMEOCode {
+    pub fn parse(&self) -> ParsedActorCode {
+        ParsedActorCode::parse(&self.0)
+    }
+}
+
+impl fmt::Display for ActorScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ActorScope::Domestic => write!(f, "Domestic"),
+            ActorScope::Regional => write!(f, "Regional"),
+            ActorScope::International => write!(f, "International"),
+            ActorScope::Unknown => write!(f, "Unknown"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use log::info;
+
+    #[allow(dead_code)]
+    fn init_logger() {
+        static INIT: std::sync::Once = std::sync::Once::new();
+        INIT.call_once(|| {
+            env_logger::init();
+        });
+    }
+
+    #[test]
+    fn test_parse_domestic_actor_code() {
+        // init_logger();
+
+        let parsed = ParsedActorCode::parse("NGAGOVMIL");
+        info!("NGAGOVMIL parsed as {:?}", parsed);
+        assert_eq!(
+            parsed.segments,
+            vec![
+                ActorCodeSegment::Country(CountryZone::Nigeria),
+                ActorCodeSegment::Role(ActorRole::Government),
+                ActorCodeSegment::Role(ActorRole::Military),
+            ]
+        );
+        assert_eq!(parsed.scope, ActorScope::Domestic);
+        assert_eq!(parsed.country(), Some(CountryZone::Nigeria));
+        assert!(parsed.has_role(&ActorRole::Military));
+        assert!(parsed.is_fully_resolved());
+    }
+
+    #[test]
+    fn test_parse_regional_and_international_codes() {
+        // init_logger();
+
+        let rebels = ParsedActorCode::parse("AFRREB");
+        assert_eq!(rebels.scope, ActorScope::Regional);
+        assert_eq!(rebels.country(), Some(CountryZone::Africa));
+        assert_eq!(rebels.roles(), vec![&ActorRole::Rebels]);
+
+        let un = CAMEOCode("IGOUNO".to_string()).parse();
+        assert_eq!(un.scope, ActorScope::International);
+        assert_eq!(
+            un.segments,
+            vec![
+                ActorCodeSegment::Role(ActorRole::InterGovernmentalOrganization),
+                ActorCodeSegment::Role(ActorRole::UnitedNations),
+            ]
+        );
+        assert_eq!(un.country(), None);
+    }
+
+    #[test]
+    fn test_parse_ambiguous_tokens() {
+        // init_logger();
+
+        // Religion beats the colliding ethnic code, and the sub-denomination follows
+        let catholic = ParsedActorCode::parse("USACHRCTH");
+        assert_eq!(
+            catholic.religions(),
+            vec![&Religion::Christianity, &Religion::Catholic]
+        );
+
+        // PSE leads as a country, not the known group
+        let palestine = ParsedActorCode::parse("PSEGOV");
+        assert_eq!(palestine.country(), CountryZone::from_cameo("PSE"));
+
+        // COP is a role in later positions even though it is also an ethnic code
+        assert!(ParsedActorCode::parse("KENCOP").has_role(&ActorRole::Policeforces));
+
+        let hamas = ParsedActorCode::parse("PSEHMS");
+        assert_eq!(hamas.known_groups(), vec![&KnownGroup::Hamas]);
+    }
+
+    #[test]
+    fn test_parse_unknown_segments() {
+        // init_logger();
+
+        let parsed = ParsedActorCode::parse("NGAZZZGO");
+        assert!(!parsed.is_fully_resolved());
+        assert_eq!(
+            parsed.segments[1..],
+            [
+                ActorCodeSegment::Unknown("ZZZ".to_string()),
+                ActorCodeSegment::Unknown("GO".to_string()),
+            ]
+        );
+        assert_eq!(ParsedActorCode::parse("").segments, vec![]);
+        assert_eq!(ParsedActorCode::parse("").scope, ActorScope::Unknown);
+    }
+}
@@ -0,0 +1,225 @@
+//! FIPS 10-4 ADM1 (first-order administrative division) lookup
+//!
+//! The table is embedded at compile time from `FIPS-ADM1-CODEBOOK.tsv`, which is
+//! derived from FIPS 10-4 change notice 414 (`.reference_docs/fips-414.txt`). GDELT
+//! writes ADM1 codes as the 2-character FIPS country code followed by the 2-character
+//! division code, e.g. `NI05` for Lagos State.
+//!
+//! ISO 3166-2 codes are filled in for every African country and for the US, Canada
+//! and Australia, but only where the FIPS and ISO divisions line up one to one.
+//! Divisions that were split, merged or replaced since change notice 414 leave it empty:
+//! Kenya's provinces (ISO now lists counties), Morocco's pre-2015 regions, Côte
+//! d'Ivoire's pre-2011 regions (ISO lists the post-2011 districts, whose boundaries
+//! differ even where the names match), the DRC provinces split in 2015 (Équateur and
+//! Kasaï-Oriental among them), the South Sudan states still filed under Sudan, and
+//! single divisions split since, such as Ghana's Brong-Ahafo and Namibia's Okavango.
+//! The one Kenyan exception is `KE05` Nairobi Area, which is exactly Nairobi County
+//! (`KE-30`). Countries outside Africa, other than the three above, are not covered.
+
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+use super::country::CountryZone;
+use crate::types::event_table::ADM1Code;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ADM1Entry {
+    pub code: &'static str,
+    /// English division type, e.g. "state", "province", "region"
+    pub division_type: &'static str,
+    pub name: &'static str,
+    /// Conventional English name where it differs from `name`
+    pub conventional_name: Option<&'static str>,
+    /// Variant or other-language spelling
+    pub variant_name: Option<&'static str>,
+    /// ISO 3166-2 code of the same division; only African countries plus the US, Canada and Australia
+    /// are covered, and divisions without a one-to-one ISO equivalent are `None`
+    pub iso_3166_2: Option<&'static str>,
+}
+
+fn non_empty(field: &'static str) -> Option<&'static str> {
+    let trimmed = field.trim();
+    if trimmed.is_empty() {
+        None
+    } else {
+        Some(trimmed)
+    }
+}
+
+static ADM1_MAP: OnceLock<BTreeMap<&'static str, ADM1Entry>> = OnceLock::new();
+
+fn adm1_map() -> &'static BTreeMap<&'static str, ADM1Entry> {
+    ADM1_MAP.get_or_init(|| {
+        let tsv_content: &'static str = include_str!("../../../../FIPS-ADM1-CODEBOOK.tsv");
+        let mut map = BTreeMap::new();
+
+        // Skip header line
+        for line in tsv_content.lines().skip(1) {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let fields: Vec<&'static str> = line.split('\t').collect();
+            if fields.len() < 6 || fields[0].len() != 4 {
+                continue; // Skip malformed lines
+            }
+
+            let entry = ADM1Entry {
+                code: fields[0],
+                division_type: fields[1],
+                name: fields[2],
+                conventional_name: non_empty(fields[3]),
+                variant_name: non_empty(fields[4]),
+                iso_3166_2: non_empty(fields[5]),
+            };
+            map.insert(entry.code, entry);
+        }
+
+        map
+    })
+}
+
+impl ADM1Entry {
+    pub fn lookup(code: &str) -> Option<&'static ADM1Entry> {
+        adm1_map().get(code.trim()).or_else(|| {
+            let upper = code.trim().to_ascii_uppercase();
+            adm1_map().get(upper.as_str())
+        })
+    }
+
+    pub fn from_iso_3166_2(code: &str) -> Option<&'static ADM1Entry> {
+        let code = code.trim();
+        adm1_map().values().find(|entry| {
+            entry
+                .iso_3166_2
+                .is_some_and(|iso| iso.eq_ignore_ascii_case(code))
+        })
+    }
+
+    /// All ADM1 divisions of a country, ordered by code
+    pub fn for_country(zone: CountryZone) -> Vec<&'static ADM1Entry> {
+        match zone.fips_code() {
+            Some(fips) => adm1_map()
+                .range(fips..)
+                .take_while(|(code, _)| code.starts_with(fips))
+                .map(|(_, entry)| entry)
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    pub fn all() -> impl Iterator<Item = &'static ADM1Entry> {
+        adm1_map().values()
+    }
+
+    /// FIPS country prefix of the code
+    pub fn country_fips(&self) -> &'static str {
+        &self.code[..2]
+    }
+
+    pub fn country(&self) -> Option<CountryZone> {
+        CountryZone::from_fips(self.country_fips())
+    }
+}
+
+impl ADM1Code {
+    /// FIPS country prefix, if the code is long enough to have one
+    pub fn country_fips(&self) -> Option<&str> {
+        self.0.get(..2)
+    }
+
+    /// True for country-level codes (just the FIPS country code, no division)
+    pub fn is_country_level(&self) -> bool {
+        self.0.trim().len() == 2
+    }
+
+    pub fn entry(&self) -> Option<&'static ADM1Entry> {
+        ADM1Entry::lookup(&self.0)
+    }
+
+    pub fn name(&self) -> Option<&'static str> {
+        self.entry().map(|entry| entry.name)
+    }
+
+    pub fn country(&self) -> Option<CountryZone> {
+        self.country_fips().and_then(CountryZone::from_fips)
+    }
+
+    pub fn iso_3166_2(&self) -> Option<&'static str> {
+        self.entry().and_then(|entry| entry.iso_3166_2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use log::info;
+
+    #[allow(dead_code)]
+    fn init_logger() {
+        static INIT: std::sync::Once = std::sync::Once::new();
+        INIT.call_once(|| {
+            env_logger::init();
+        });
+    }
+
+    #[test]
+    fn test_adm1_lookup() {
+        // init_logger();
+
+        let lagos = ADM1Code("NI05".to_string());
+        info!("NI05 resolves to {:?}", lagos.entry());
+        assert_eq!(lagos.name(), Some("Lagos"));
+        assert_eq!(lagos.country(), Some(CountryZone::Nigeria));
+        assert_eq!(lagos.iso_3166_2(), Some("NG-LA"));
+        assert_eq!(lagos.entry().unwrap().division_type, "state");
+
+        let newfoundland = ADM1Entry::lookup("CA05").unwrap();
+        assert_eq!(newfoundland.name, "Newfoundland and Labrador");
+        assert_eq!(newfoundland.variant_name, Some("Terre-Neuve-et-Labrador"));
+
+        assert!(ADM1Entry::lookup("ZZ99").is_none());
+        assert!(ADM1Code("NI".to_string()).is_country_level());
+    }
+
+    #[test]
+    fn test_adm1_table_is_consistent() {
+        // init_logger();
+
+        let entries: Vec<_> = ADM1Entry::all().collect();
+        info!("Loaded {} ADM1 entries", entries.len());
+        assert!(entries.len() > 3900);
+
+        for entry in entries {
+            assert!(!entry.name.is_empty(), "{}", entry.code);
+            assert_ne!(&entry.code[2..], "00", "{}", entry.code);
+            if let Some(iso) = entry.iso_3166_2 {
+                // The ISO country prefix must match the FIPS country
+                let country = entry.country().unwrap();
+                assert_eq!(Some(&iso[..2]), country.iso_alpha2(), "{}", entry.code);
+                assert_eq!(ADM1Entry::from_iso_3166_2(iso), Some(entry));
+            }
+        }
+    }
+
+    #[test]
+    fn test_adm1_for_country() {
+        // init_logger();
+
+        let provinces = ADM1Entry::for_country(CountryZone::SouthAfrica);
+        assert_eq!(provinces.len(), 9);
+        assert!(provinces.iter().all(|entry| entry.code.starts_with("SF")));
+        assert!(provinces.iter().all(|entry| entry.iso_3166_2.is_some()));
+
+        assert_eq!(ADM1Entry::for_country(CountryZone::Nigeria).len(), 37);
+        assert_eq!(
+            ADM1Entry::from_iso_3166_2("TN-42").map(|entry| entry.code),
+            Some("TS02")
+        );
+        assert_eq!(ADM1Entry::lookup("KE05").unwrap().iso_3166_2, Some("KE-30"));
+        // Split since change notice 414, so no single ISO equivalent
+        assert_eq!(ADM1Entry::lookup("CG02").unwrap().iso_3166_2, None);
+        assert_eq!(ADM1Entry::lookup("IV82").unwrap().iso_3166_2, None);
+        assert!(ADM1Entry::for_country(CountryZone::Africa).is_empty());
+    }
+}
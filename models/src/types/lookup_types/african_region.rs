@@ -0,0 +1,533 @@
+use std::fmt;
+
+use super::country::CountryZone;
+
+/// African Union, the Regional Economic Communities it recognises, and the five AU
+/// geographic regions.
+///
+/// Membership is as of 2025. Notable changes:
+/// - Burkina Faso, Mali and Niger left ECOWAS in January 2025.
+/// - Rwanda announced its withdrawal from ECCAS in June 2025.
+/// - Somalia joined the EAC in 2024.
+/// - Eritrea withdrew from IGAD in December 2023.
+///
+/// Suspended members (AU and ECOWAS suspensions after coups) are still listed as members.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AfricanRegion {
+    AfricanUnion,
+    ECOWAS,
+    SADC,
+    EAC,
+    ECCAS,
+    IGAD,
+    COMESA,
+    UMA,
+    AUNorthernAfrica,
+    AUWesternAfrica,
+    AUCentralAfrica,
+    AUEasternAfrica,
+    AUSouthernAfrica,
+}
+
+const AU_NORTHERN_AFRICA: &[CountryZone] = &[
+    CountryZone::Algeria,
+    CountryZone::Egypt,
+    CountryZone::Libya,
+    CountryZone::Mauritania,
+    CountryZone::Morocco,
+    // Sahrawi Arab Democratic Republic
+    CountryZone::WesternSahara,
+    CountryZone::Tunisia,
+];
+
+const AU_WESTERN_AFRICA: &[CountryZone] = &[
+    CountryZone::Benin,
+    CountryZone::BurkinaFaso,
+    CountryZone::CapeVerde,
+    CountryZone::IvoryCoast,
+    CountryZone::Gambia,
+    CountryZone::Ghana,
+    CountryZone::Guinea,
+    CountryZone::GuineaBissau,
+    CountryZone::Liberia,
+    CountryZone::Mali,
+    CountryZone::Niger,
+    CountryZone::Nigeria,
+    CountryZone::Senegal,
+    CountryZone::SierraLeone,
+    CountryZone::Togo,
+];
+
+const AU_CENTRAL_AFRICA: &[CountryZone] = &[
+    CountryZone::Burundi,
+    CountryZone::Cameroon,
+    CountryZone::CentralAfricanRepublic,
+    CountryZone::Chad,
+    CountryZone::PeoplesRepublicoftheCongo,
+    CountryZone::DemocraticRepublicoftheCongo,
+    CountryZone::EquatorialGuinea,
+    CountryZone::Gabon,
+    CountryZone::SaoTomeandPrincipe,
+];
+
+const AU_EASTERN_AFRICA: &[CountryZone] = &[
+    CountryZone::Comoros,
+    CountryZone::Djibouti,
+    CountryZone::Eritrea,
+    CountryZone::Ethiopia,
+    CountryZone::Kenya,
+    CountryZone::Madagascar,
+    CountryZone::Mauritius,
+    CountryZone::Rwanda,
+    CountryZone::Seychelles,
+    CountryZone::Somalia,
+    CountryZone::SouthSudan,
+    CountryZone::Sudan,
+    CountryZone::Tanzania,
+    CountryZone::Uganda,
+];
+
+const AU_SOUTHERN_AFRICA: &[CountryZone] = &[
+    CountryZone::Angola,
+    CountryZone::Botswana,
+    CountryZone::Swaziland,
+    CountryZone::Lesotho,
+    CountryZone::Malawi,
+    CountryZone::Mozambique,
+    CountryZone::Namibia,
+    CountryZone::SouthAfrica,
+    CountryZone::Zambia,
+    CountryZone::Zimbabwe,
+];
+
+const AFRICAN_UNION: &[CountryZone] = &[
+    CountryZone::Algeria,
+    CountryZone::Angola,
+    CountryZone::Benin,
+    CountryZone::Botswana,
+    CountryZone::BurkinaFaso,
+    CountryZone::Burundi,
+    CountryZone::Cameroon,
+    CountryZone::CapeVerde,
+    CountryZone::CentralAfricanRepublic,
+    CountryZone::Chad,
+    CountryZone::Comoros,
+    CountryZone::PeoplesRepublicoftheCongo,
+    CountryZone::DemocraticRepublicoftheCongo,
+    CountryZone::IvoryCoast,
+    CountryZone::Djibouti,
+    CountryZone::Egypt,
+    CountryZone::EquatorialGuinea,
+    CountryZone::Eritrea,
+    CountryZone::Swaziland,
+    CountryZone::Ethiopia,
+    CountryZone::Gabon,
+    CountryZone::Gambia,
+    CountryZone::Ghana,
+    CountryZone::Guinea,
+    CountryZone::GuineaBissau,
+    CountryZone::Kenya,
+    CountryZone::Lesotho,
+    CountryZone::Liberia,
+    CountryZone::Libya,
+    CountryZone::Madagascar,
+    CountryZone::Malawi,
+    CountryZone::Mali,
+    CountryZone::Mauritania,
+    CountryZone::Mauritius,
+    CountryZone::Morocco,
+    CountryZone::Mozambique,
+    CountryZone::Namibia,
+    CountryZone::Niger,
+    CountryZone::Nigeria,
+    CountryZone::Rwanda,
+    CountryZone::WesternSahara,
+    CountryZone::SaoTomeandPrincipe,
+    CountryZone::Senegal,
+    CountryZone::Seychelles,
+    CountryZone::SierraLeone,
+    CountryZone::Somalia,
+    CountryZone::SouthAfrica,
+    CountryZone::SouthSudan,
+    CountryZone::Sudan,
+    CountryZone::Tanzania,
+    CountryZone::Togo,
+    CountryZone::Tunisia,
+    CountryZone::Uganda,
+    CountryZone::Zambia,
+    CountryZone::Zimbabwe,
+];
+
+const ECOWAS: &[CountryZone] = &[
+    CountryZone::Benin,
+    CountryZone::CapeVerde,
+    CountryZone::IvoryCoast,
+    CountryZone::Gambia,
+    CountryZone::Ghana,
+    CountryZone::Guinea,
+    CountryZone::GuineaBissau,
+    CountryZone::Liberia,
+    CountryZone::Nigeria,
+    CountryZone::Senegal,
+    CountryZone::SierraLeone,
+    CountryZone::Togo,
+];
+
+const SADC: &[CountryZone] = &[
+    CountryZone::Angola,
+    CountryZone::Botswana,
+    CountryZone::Comoros,
+    CountryZone::DemocraticRepublicoftheCongo,
+    CountryZone::Swaziland,
+    CountryZone::Lesotho,
+    CountryZone::Madagascar,
+    CountryZone::Malawi,
+    CountryZone::Mauritius,
+    CountryZone::Mozambique,
+    CountryZone::Namibia,
+    CountryZone::Seychelles,
+    CountryZone::SouthAfrica,
+    CountryZone::Tanzania,
+    CountryZone::Zambia,
+    CountryZone::Zimbabwe,
+];
+
+const EAC: &[CountryZone] = &[
+    CountryZone::Burundi,
+    CountryZone::DemocraticRepublicoftheCongo,
+    CountryZone::Kenya,
+    CountryZone::Rwanda,
+    CountryZone::Somalia,
+    CountryZone::SouthSudan,
+    CountryZone::Tanzania,
+    CountryZone::Uganda,
+];
+
+const ECCAS: &[CountryZone] = &[
+    CountryZone::Angola,
+    CountryZone::Burundi,
+    CountryZone::Cameroon,
+    CountryZone::CentralAfricanRepublic,
+    CountryZone::Chad,
+    CountryZone::PeoplesRepublicoftheCongo,
+    CountryZone::DemocraticRepublicoftheCongo,
+    CountryZone::EquatorialGuinea,
+    CountryZone::Gabon,
+    CountryZone::SaoTomeandPrincipe,
+];
+
+const IGAD: &[CountryZone] = &[
+    CountryZone::Djibouti,
+    CountryZone::Ethiopia,
+    CountryZone::Kenya,
+    CountryZone::Somalia,
+    CountryZone::SouthSudan,
+    CountryZone::Sudan,
+    CountryZone::Uganda,
+];
+
+const COMESA: &[CountryZone] = &[
+    CountryZone::Burundi,
+    CountryZone::Comoros,
+    CountryZone::DemocraticRepublicoftheCongo,
+    CountryZone::Djibouti,
+    CountryZone::Egypt,
+    CountryZone::Eritrea,
+    CountryZone::Swaziland,
+    CountryZone::Ethiopia,
+    CountryZone::Kenya,
+    CountryZone::Libya,
+    CountryZone::Madagascar,
+    CountryZone::Malawi,
+    CountryZone::Mauritius,
+    CountryZone::Rwanda,
+    CountryZone::Seychelles,
+    CountryZone::Somalia,
+    CountryZone::Sudan,
+    CountryZone::Tunisia,
+    CountryZone::Uganda,
+    CountryZone::Zambia,
+    CountryZone::Zimbabwe,
+];
+
+const UMA: &[CountryZone] = &[
+    CountryZone::Algeria,
+    CountryZone::Libya,
+    CountryZone::Mauritania,
+    CountryZone::Morocco,
+    CountryZone::Tunisia,
+];
+
+impl AfricanRegion {
+    pub const ALL: &'static [AfricanRegion] = &[
+        AfricanRegion::AfricanUnion,
+        AfricanRegion::ECOWAS,
+        AfricanRegion::SADC,
+        AfricanRegion::EAC,
+        AfricanRegion::ECCAS,
+        AfricanRegion::IGAD,
+        AfricanRegion::COMESA,
+        AfricanRegion::UMA,
+        AfricanRegion::AUNorthernAfrica,
+        AfricanRegion::AUWesternAfrica,
+        AfricanRegion::AUCentralAfrica,
+        AfricanRegion::AUEasternAfrica,
+        AfricanRegion::AUSouthernAfrica,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            AfricanRegion::AfricanUnion => "African Union",
+            AfricanRegion::ECOWAS => "Economic Community of West African States",
+            AfricanRegion::SADC => "Southern African Development Community",
+            AfricanRegion::EAC => "East African Community",
+            AfricanRegion::ECCAS => "Economic Community of Central African States",
+            AfricanRegion::IGAD => "Intergovernmental Authority on Development",
+            AfricanRegion::COMESA => "Common Market for Eastern and Southern Africa",
+            AfricanRegion::UMA => "Arab Maghreb Union",
+            AfricanRegion::AUNorthernAfrica => "Northern Africa (AU)",
+            AfricanRegion::AUWesternAfrica => "Western Africa (AU)",
+            AfricanRegion::AUCentralAfrica => "Central Africa (AU)",
+            AfricanRegion::AUEasternAfrica => "Eastern Africa (AU)",
+            AfricanRegion::AUSouthernAfrica => "Southern Africa (AU)",
+        }
+    }
+
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            AfricanRegion::AfricanUnion => "AU",
+            AfricanRegion::ECOWAS => "ECOWAS",
+            AfricanRegion::SADC => "SADC",
+            AfricanRegion::EAC => "EAC",
+            AfricanRegion::ECCAS => "ECCAS",
+            AfricanRegion::IGAD => "IGAD",
+            AfricanRegion::COMESA => "COMESA",
+            AfricanRegion::UMA => "UMA",
+            AfricanRegion::AUNorthernAfrica => "AU-N",
+            AfricanRegion::AUWesternAfrica => "AU-W",
+            AfricanRegion::AUCentralAfrica => "AU-C",
+            AfricanRegion::AUEasternAfrica => "AU-E",
+            AfricanRegion::AUSouthernAfrica => "AU-S",
+        }
+    }
+
+    /// True for the five AU geographic regions, which partition the AU membership
+    pub fn is_au_geographic_region(&self) -> bool {
+        matches!(
+            self,
+            AfricanRegion::AUNorthernAfrica
+                | AfricanRegion::AUWesternAfrica
+                | AfricanRegion::AUCentralAfrica
+                | AfricanRegion::AUEasternAfrica
+                | AfricanRegion::AUSouthernAfrica
+        )
+    }
+
+    /// Member states
+    pub fn members(&self) -> &'static [CountryZone] {
+        match self {
+            AfricanRegion::AfricanUnion => AFRICAN_UNION,
+            AfricanRegion::ECOWAS => ECOWAS,
+            AfricanRegion::SADC => SADC,
+            AfricanRegion::EAC => EAC,
+            AfricanRegion::ECCAS => ECCAS,
+            AfricanRegion::IGAD => IGAD,
+            AfricanRegion::COMESA => COMESA,
+            AfricanRegion::UMA => UMA,
+            AfricanRegion::AUNorthernAfrica => AU_NORTHERN_AFRICA,
+            AfricanRegion::AUWesternAfrica => AU_WESTERN_AFRICA,
+            AfricanRegion::AUCentralAfrica => AU_CENTRAL_AFRICA,
+            AfricanRegion::AUEasternAfrica => AU_EASTERN_AFRICA,
+            AfricanRegion::AUSouthernAfrica => AU_SOUTHERN_AFRICA,
+        }
+    }
+
+    /// The CAMEO supra-national zone that corresponds to this region, if there is one
+    pub fn zone(&self) -> Option<CountryZone> {
+        match self {
+            AfricanRegion::AfricanUnion => Some(CountryZone::Africa),
+            AfricanRegion::AUNorthernAfrica => Some(CountryZone::NorthAfrica),
+            AfricanRegion::AUWesternAfrica => Some(CountryZone::WestAfrica),
+            AfricanRegion::AUCentralAfrica => Some(CountryZone::CentralAfrica),
+            AfricanRegion::AUEasternAfrica => Some(CountryZone::EasternAfrica),
+            AfricanRegion::AUSouthernAfrica => Some(CountryZone::SouthernAfrica),
+            _ => None,
+        }
+    }
+
+    /// Map a CAMEO supra-national zone (`Africa`, `WestAfrica`, ...) onto the registry
+    pub fn from_zone(zone: CountryZone) -> Option<Self> {
+        AfricanRegion::ALL
+            .iter()
+            .find(|region| region.zone() == Some(zone))
+            .copied()
+    }
+
+    /// Whether `zone` falls inside this region.
+    ///
+    /// Member states match directly. CAMEO's African zones match the region they map to,
+    /// and all of them fall inside the African Union, so an actor coded `WAF` is counted
+    /// for `AUWesternAfrica` and `AfricanUnion` but not for `ECOWAS`.
+    pub fn contains(&self, zone: CountryZone) -> bool {
+        if self.members().contains(&zone) {
+            return true;
+        }
+        match AfricanRegion::from_zone(zone) {
+            Some(region) => region == *self || *self == AfricanRegion::AfricanUnion,
+            None => false,
+        }
+    }
+}
+
+impl fmt::Display for AfricanRegion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.abbreviation())
+    }
+}
+
+impl TryFrom<&str> for AfricanRegion {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = value.trim();
+        AfricanRegion::ALL
+            .iter()
+            .find(|region| region.abbreviation().eq_ignore_ascii_case(value))
+            .copied()
+            .ok_or_else(|| anyhow::anyhow!("Unknown African region: {}", value))
+    }
+}
+
+impl CountryZone {
+    /// Every African region this zone belongs to
+    pub fn regions(&self) -> Vec<AfricanRegion> {
+        AfricanRegion::ALL
+            .iter()
+            .filter(|region| region.contains(*self))
+            .copied()
+            .collect()
+    }
+
+    pub fn is_in(&self, region: AfricanRegion) -> bool {
+        region.contains(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use log::info;
+
+    #[allow(dead_code)]
+    fn init_logger() {
+        static INIT: std::sync::Once = std::sync::Once::new();
+        INIT.call_once(|| {
+            env_logger::init();
+        });
+    }
+
+    #[test]
+    fn test_au_regions_partition_membership() {
+        // init_logger();
+
+        let mut from_regions: Vec<CountryZone> = AfricanRegion::ALL
+            .iter()
+            .filter(|region| region.is_au_geographic_region())
+            .flat_map(|region| region.members().iter().copied())
+            .collect();
+        let total = from_regions.len();
+        from_regions.sort_by_key(|zone| *zone as usize);
+        from_regions.dedup();
+        assert_eq!(total, from_regions.len(), "AU regions overlap");
+
+        let mut members = AFRICAN_UNION.to_vec();
+        members.sort_by_key(|zone| *zone as usize);
+        assert_eq!(members.len(), 55);
+        assert_eq!(members, from_regions);
+    }
+
+    #[test]
+    fn test_bloc_members_are_au_members() {
+        // init_logger();
+
+        for region in AfricanRegion::ALL {
+            info!("{} has {} members", region, region.members().len());
+            for zone in region.members() {
+                assert!(!zone.is_supranational(), "{:?}", zone);
+                assert!(
+                    AfricanRegion::AfricanUnion.contains(*zone),
+                    "{:?} in {:?}",
+                    zone,
+                    region
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_country_regions() {
+        // init_logger();
+
+        let regions = CountryZone::Kenya.regions();
+        info!("Kenya regions: {:?}", regions);
+        assert_eq!(
+            regions,
+            vec![
+                AfricanRegion::AfricanUnion,
+                AfricanRegion::EAC,
+                AfricanRegion::IGAD,
+                AfricanRegion::COMESA,
+                AfricanRegion::AUEasternAfrica,
+            ]
+        );
+        assert!(CountryZone::Nigeria.is_in(AfricanRegion::ECOWAS));
+        assert!(!CountryZone::Mali.is_in(AfricanRegion::ECOWAS));
+        assert!(CountryZone::Mali.is_in(AfricanRegion::AUWesternAfrica));
+        assert!(!CountryZone::Eritrea.is_in(AfricanRegion::IGAD));
+        assert!(CountryZone::Eritrea.is_in(AfricanRegion::COMESA));
+        assert!(CountryZone::France.regions().is_empty());
+        assert!(CountryZone::Reunion.regions().is_empty());
+    }
+
+    #[test]
+    fn test_supranational_zones() {
+        // init_logger();
+
+        assert_eq!(
+            AfricanRegion::from_zone(CountryZone::WestAfrica),
+            Some(AfricanRegion::AUWesternAfrica)
+        );
+        assert_eq!(
+            AfricanRegion::from_zone(CountryZone::Africa),
+            Some(AfricanRegion::AfricanUnion)
+        );
+        assert_eq!(AfricanRegion::from_zone(CountryZone::MiddleEast), None);
+
+        assert_eq!(
+            CountryZone::EasternAfrica.regions(),
+            vec![AfricanRegion::AfricanUnion, AfricanRegion::AUEasternAfrica]
+        );
+        assert!(!CountryZone::WestAfrica.is_in(AfricanRegion::ECOWAS));
+        assert_eq!(
+            CountryZone::Africa.regions(),
+            vec![AfricanRegion::AfricanUnion]
+        );
+    }
+
+    #[test]
+    fn test_region_from_abbreviation() {
+        // init_logger();
+
+        assert_eq!(
+            AfricanRegion::try_from("ecowas").unwrap(),
+            AfricanRegion::ECOWAS
+        );
+        assert!(AfricanRegion::try_from("NATO").is_err());
+        for region in AfricanRegion::ALL {
+            assert_eq!(
+                AfricanRegion::try_from(region.abbreviation()).unwrap(),
+                *region
+            );
+        }
+    }
+}
@@ -0,0 +1,458 @@
+//! Normalization of V2.1AMOUNTS objects
+//!
+//! GDELT gives each amount a free-text object (`people`, `million dollars`,
+//! `tons of maize`). The normalizer reads, from the front of the object:
+//! - scale words (`thousand`, `million`, `bn`, `crore`, ...), multiplied into the value
+//! - a unit phrase (currency, mass, area, volume, distance), converted to a base unit;
+//!   whatever follows it (minus a leading `of`) is kept as the subject
+//!
+//! Objects with no unit phrase are classified by the counted noun (`people`, `trucks`,
+//! `rifles`, ...) and keep their value as a plain count.
+//!
+//! Bare `dollars` is read as USD and bare `pounds` as GBP; `pounds of ...` is mass.
+//! Currency names shared by several countries (`shillings`, `CFA francs`, `kwacha`, ...)
+//! are kept as currency with no ISO code.
+
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AmountCategory {
+    People,
+    Currency,
+    Mass,
+    Area,
+    Volume,
+    Distance,
+    Vehicles,
+    Weapons,
+    Other,
+}
+
+/// Unit a normalized value is expressed in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AmountUnit {
+    /// Plain count of the object
+    Count,
+    /// ISO 4217 code, `None` when the currency name is ambiguous
+    Currency(Option<&'static str>),
+    Kilograms,
+    Hectares,
+    Litres,
+    Kilometres,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NormalizedAmount {
+    pub value: f64,
+    pub category: AmountCategory,
+    pub unit: AmountUnit,
+    /// What was measured or counted, when the object names it (`maize` in `tons of maize`)
+    pub subject: Option<String>,
+}
+
+const SCALE_WORDS: &[(&str, f64)] = &[
+    ("hundred", 1e2),
+    ("thousand", 1e3),
+    ("k", 1e3),
+    ("lakh", 1e5),
+    ("lakhs", 1e5),
+    ("million", 1e6),
+    ("millions", 1e6),
+    ("mn", 1e6),
+    ("crore", 1e7),
+    ("crores", 1e7),
+    ("billion", 1e9),
+    ("billions", 1e9),
+    ("bn", 1e9),
+    ("trillion", 1e12),
+    ("dozen", 12.0),
+];
+
+const CURRENCIES: &[(&str, Option<&str>)] = &[
+    ("us dollars", Some("USD")),
+    ("u.s. dollars", Some("USD")),
+    ("american dollars", Some("USD")),
+    ("canadian dollars", Some("CAD")),
+    ("australian dollars", Some("AUD")),
+    ("namibian dollars", Some("NAD")),
+    ("liberian dollars", Some("LRD")),
+    ("zimbabwe dollars", Some("ZWL")),
+    ("dollars", Some("USD")),
+    ("dollar", Some("USD")),
+    ("usd", Some("USD")),
+    ("euros", Some("EUR")),
+    ("euro", Some("EUR")),
+    ("eur", Some("EUR")),
+    ("egyptian pounds", Some("EGP")),
+    ("sudanese pounds", Some("SDG")),
+    ("south sudanese pounds", Some("SSP")),
+    ("pounds sterling", Some("GBP")),
+    ("british pounds", Some("GBP")),
+    ("sterling", Some("GBP")),
+    ("pounds", Some("GBP")),
+    ("gbp", Some("GBP")),
+    ("naira", Some("NGN")),
+    ("rand", Some("ZAR")),
+    ("cedis", Some("GHS")),
+    ("cedi", Some("GHS")),
+    ("kenyan shillings", Some("KES")),
+    ("ugandan shillings", Some("UGX")),
+    ("tanzanian shillings", Some("TZS")),
+    ("somali shillings", Some("SOS")),
+    ("shillings", None),
+    ("ksh", Some("KES")),
+    ("birr", Some("ETB")),
+    ("dalasi", Some("GMD")),
+    ("leones", Some("SLE")),
+    ("ariary", Some("MGA")),
+    ("pula", Some("BWP")),
+    ("meticais", Some("MZN")),
+    ("kwanza", Some("AOA")),
+    ("nakfa", Some("ERN")),
+    ("moroccan dirhams", Some("MAD")),
+    ("emirati dirhams", Some("AED")),
+    ("dirhams", None),
+    ("cfa francs", None),
+    ("francs cfa", None),
+    ("cfa", None),
+    ("swiss francs", Some("CHF")),
+    ("francs", None),
+    ("kwacha", None),
+    ("yuan", Some("CNY")),
+    ("renminbi", Some("CNY")),
+    ("yen", Some("JPY")),
+    ("indian rupees", Some("INR")),
+    ("pakistani rupees", Some("PKR")),
+    ("rupees", None),
+    ("rubles", Some("RUB")),
+    ("roubles", Some("RUB")),
+    ("saudi riyals", Some("SAR")),
+    ("riyals", None),
+];
+
+/// (phrase, unit, factor to the unit)
+const MEASURES: &[(&str, AmountUnit, f64)] = &[
+    // Checked before the `pounds` currency entry
+    ("pounds of", AmountUnit::Kilograms, 0.453_592_37),
+    ("lbs", AmountUnit::Kilograms, 0.453_592_37),
+    ("metric tons", AmountUnit::Kilograms, 1000.0),
+    ("metric tonnes", AmountUnit::Kilograms, 1000.0),
+    ("tons", AmountUnit::Kilograms, 1000.0),
+    ("tonnes", AmountUnit::Kilograms, 1000.0),
+    ("ton", AmountUnit::Kilograms, 1000.0),
+    ("tonne", AmountUnit::Kilograms, 1000.0),
+    ("quintals", AmountUnit::Kilograms, 100.0),
+    ("kilograms", AmountUnit::Kilograms, 1.0),
+    ("kilogrammes", AmountUnit::Kilograms, 1.0),
+    ("kilos", AmountUnit::Kilograms, 1.0),
+    ("kg", AmountUnit::Kilograms, 1.0),
+    ("grams", AmountUnit::Kilograms, 0.001),
+    ("square kilometres", AmountUnit::Hectares, 100.0),
+    ("square kilometers", AmountUnit::Hectares, 100.0),
+    ("sq km", AmountUnit::Hectares, 100.0),
+    ("km2", AmountUnit::Hectares, 100.0),
+    ("square miles", AmountUnit::Hectares, 258.998_811),
+    ("hectares", AmountUnit::Hectares, 1.0),
+    ("ha", AmountUnit::Hectares, 1.0),
+    ("acres", AmountUnit::Hectares, 0.404_685_642),
+    ("litres", AmountUnit::Litres, 1.0),
+    ("liters", AmountUnit::Litres, 1.0),
+    ("cubic metres", AmountUnit::Litres, 1000.0),
+    ("cubic meters", AmountUnit::Litres, 1000.0),
+    ("barrels", AmountUnit::Litres, 158.987_294_928),
+    ("gallons", AmountUnit::Litres, 3.785_411_784),
+    ("kilometres", AmountUnit::Kilometres, 1.0),
+    ("kilometers", AmountUnit::Kilometres, 1.0),
+    ("km", AmountUnit::Kilometres, 1.0),
+    ("miles", AmountUnit::Kilometres, 1.609_344),
+    ("metres", AmountUnit::Kilometres, 0.001),
+    ("meters", AmountUnit::Kilometres, 0.001),
+];
+
+const PEOPLE_NOUNS: &[&str] = &[
+    "people",
+    "persons",
+    "person",
+    "individuals",
+    "residents",
+    "civilians",
+    "refugees",
+    "migrants",
+    "children",
+    "women",
+    "men",
+    "girls",
+    "boys",
+    "students",
+    "workers",
+    "soldiers",
+    "troops",
+    "police",
+    "officers",
+    "fighters",
+    "militants",
+    "protesters",
+    "demonstrators",
+    "villagers",
+    "farmers",
+    "patients",
+    "victims",
+    "voters",
+    "pupils",
+    "teachers",
+    "nurses",
+    "doctors",
+    "employees",
+    "passengers",
+    "prisoners",
+    "inmates",
+    "detainees",
+    "hostages",
+    "pilgrims",
+    "displaced",
+    "idps",
+    "survivors",
+    "casualties",
+    "dead",
+    "injured",
+];
+
+const VEHICLE_NOUNS: &[&str] = &[
+    "vehicles",
+    "cars",
+    "trucks",
+    "lorries",
+    "buses",
+    "motorcycles",
+    "motorbikes",
+    "tankers",
+    "boats",
+    "ships",
+    "vessels",
+    "aircraft",
+    "planes",
+    "jets",
+    "helicopters",
+    "drones",
+    "trains",
+];
+
+const WEAPON_NOUNS: &[&str] = &[
+    "weapons",
+    "guns",
+    "rifles",
+    "firearms",
+    "pistols",
+    "missiles",
+    "rockets",
+    "bombs",
+    "grenades",
+    "mines",
+    "landmines",
+    "rounds",
+    "bullets",
+    "cartridges",
+    "shells",
+    "ammunition",
+    "explosives",
+    "warheads",
+    "artillery",
+    "tanks",
+];
+
+/// Length in tokens of `phrase` if `tokens` start with it
+fn phrase_at_start(tokens: &[&str], phrase: &str) -> Option<usize> {
+    let words: Vec<&str> = phrase.split(' ').collect();
+    (tokens.len() >= words.len() && tokens[..words.len()] == words[..]).then_some(words.len())
+}
+
+/// Remaining tokens as a subject, dropping a leading `of`
+fn subject_from(tokens: &[&str]) -> Option<String> {
+    let tokens = match tokens.first() {
+        Some(&"of") => &tokens[1..],
+        _ => tokens,
+    };
+    (!tokens.is_empty()).then(|| tokens.join(" "))
+}
+
+/// Normalize an amount and its object text
+pub fn normalize_amount(amount: f64, object: Option<&str>) -> NormalizedAmount {
+    let lowered = object.unwrap_or_default().trim().to_lowercase();
+    let tokens: Vec<&str> = lowered.split_whitespace().collect();
+
+    let mut value = amount;
+    let mut rest = tokens.as_slice();
+    while let Some((_, scale)) = rest
+        .first()
+        .and_then(|token| SCALE_WORDS.iter().find(|(word, _)| word == token))
+    {
+        value *= scale;
+        rest = &rest[1..];
+    }
+
+    for (phrase, unit, factor) in MEASURES {
+        if let Some(length) = phrase_at_start(rest, phrase) {
+            return NormalizedAmount {
+                value: value * factor,
+                category: unit.category(),
+                unit: *unit,
+                subject: subject_from(&rest[length..]),
+            };
+        }
+    }
+
+    for (phrase, code) in CURRENCIES {
+        if let Some(length) = phrase_at_start(rest, phrase) {
+            return NormalizedAmount {
+                value,
+                category: AmountCategory::Currency,
+                unit: AmountUnit::Currency(*code),
+                subject: subject_from(&rest[length..]),
+            };
+        }
+    }
+
+    let has_noun = |nouns: &[&str]| rest.iter().any(|token| nouns.contains(token));
+    let category = if has_noun(WEAPON_NOUNS) {
+        AmountCategory::Weapons
+    } else if has_noun(VEHICLE_NOUNS) {
+        AmountCategory::Vehicles
+    } else if has_noun(PEOPLE_NOUNS) {
+        AmountCategory::People
+    } else {
+        AmountCategory::Other
+    };
+
+    NormalizedAmount {
+        value,
+        category,
+        unit: AmountUnit::Count,
+        subject: subject_from(rest),
+    }
+}
+
+impl AmountCategory {
+    pub fn name(&self) -> &'static str {
+        match self {
+            AmountCategory::People => "People",
+            AmountCategory::Currency => "Currency",
+            AmountCategory::Mass => "Mass",
+            AmountCategory::Area => "Area",
+            AmountCategory::Volume => "Volume",
+            AmountCategory::Distance => "Distance",
+            AmountCategory::Vehicles => "Vehicles",
+            AmountCategory::Weapons => "Weapons",
+            AmountCategory::Other => "Other",
+        }
+    }
+}
+
+impl fmt::Display for AmountCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl AmountUnit {
+    /// Category a unit measures. `Count` is shared by every counted category, so it
+    /// comes back as `Other`.
+    pub fn category(&self) -> AmountCategory {
+        match self {
+            AmountUnit::Count => AmountCategory::Other,
+            AmountUnit::Currency(_) => AmountCategory::Currency,
+            AmountUnit::Kilograms => AmountCategory::Mass,
+            AmountUnit::Hectares => AmountCategory::Area,
+            AmountUnit::Litres => AmountCategory::Volume,
+            AmountUnit::Kilometres => AmountCategory::Distance,
+        }
+    }
+}
+
+impl fmt::Display for AmountUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AmountUnit::Count => write!(f, "count"),
+            AmountUnit::Currency(Some(code)) => write!(f, "{}", code),
+            AmountUnit::Currency(None) => write!(f, "currency"),
+            AmountUnit::Kilograms => write!(f, "kg"),
+            AmountUnit::Hectares => write!(f, "ha"),
+            AmountUnit::Litres => write!(f, "L"),
+            AmountUnit::Kilometres => write!(f, "km"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use log::info;
+
+    #[allow(dead_code)]
+    fn init_logger() {
+        static INIT: std::sync::Once = std::sync::Once::new();
+        INIT.call_once(|| {
+            env_logger::init();
+        });
+    }
+
+    #[test]
+    fn test_normalize_currency_and_scale() {
+        // init_logger();
+
+        let budget = normalize_amount(2.5, Some("million dollars"));
+        info!("2.5 million dollars -> {:?}", budget);
+        assert_eq!(budget.value, 2_500_000.0);
+        assert_eq!(budget.unit, AmountUnit::Currency(Some("USD")));
+
+        let naira = normalize_amount(3.0, Some("billion naira in relief funds"));
+        assert_eq!(naira.value, 3e9);
+        assert_eq!(naira.unit, AmountUnit::Currency(Some("NGN")));
+        assert_eq!(naira.subject.as_deref(), Some("in relief funds"));
+
+        let shillings = normalize_amount(40.0, Some("Shillings"));
+        assert_eq!(shillings.category, AmountCategory::Currency);
+        assert_eq!(shillings.unit, AmountUnit::Currency(None));
+        assert_eq!(shillings.unit.to_string(), "currency");
+    }
+
+    #[test]
+    fn test_normalize_measures() {
+        // init_logger();
+
+        let maize = normalize_amount(12.0, Some("thousand tons of maize"));
+        assert_eq!(maize.category, AmountCategory::Mass);
+        assert_eq!(maize.unit, AmountUnit::Kilograms);
+        assert_eq!(maize.value, 12_000_000.0);
+        assert_eq!(maize.subject.as_deref(), Some("maize"));
+
+        let rice = normalize_amount(100.0, Some("pounds of rice"));
+        assert_eq!(rice.category, AmountCategory::Mass);
+        assert!((rice.value - 45.359_237).abs() < 1e-9);
+        assert_eq!(rice.subject.as_deref(), Some("rice"));
+
+        let farmland = normalize_amount(5.0, Some("square kilometres of farmland"));
+        assert_eq!(farmland.unit, AmountUnit::Hectares);
+        assert_eq!(farmland.value, 500.0);
+    }
+
+    #[test]
+    fn test_normalize_counted_objects() {
+        // init_logger();
+
+        let displaced = normalize_amount(2000.0, Some("displaced people"));
+        assert_eq!(displaced.category, AmountCategory::People);
+        assert_eq!(displaced.unit, AmountUnit::Count);
+        assert_eq!(displaced.subject.as_deref(), Some("displaced people"));
+
+        assert_eq!(
+            normalize_amount(30.0, Some("armoured trucks")).category,
+            AmountCategory::Vehicles
+        );
+        assert_eq!(normalize_amount(4.0, Some("dozen rifles")).value, 48.0);
+        assert_eq!(
+            normalize_amount(7.0, Some("schools")).category,
+            AmountCategory::Other
+        );
+        assert_eq!(normalize_amount(7.0, None).subject, None);
+    }
+}
@@ -0,0 +1,107 @@
+use std::fmt;
+
+/// A code as it appeared in the source data, paired with its typed lookup value.
+///
+/// GDELT occasionally introduces codes that the lookup enums do not know about yet.
+/// Keeping the raw string next to the parsed value means those codes stay visible
+/// (and countable) instead of silently collapsing into `None`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Coded<T> {
+    pub raw: String,
+    pub parsed: Option<T>,
+}
+
+impl<T> Coded<T> {
+    pub fn new(raw: impl Into<String>, parsed: Option<T>) -> Self {
+        Self {
+            raw: raw.into(),
+            parsed,
+        }
+    }
+
+    /// Build from a raw field, returning `None` when the field is empty.
+    ///
+    /// A lookup failure is not an error: the raw code is kept and `parsed` is left empty.
+    pub fn from_field<E>(field: &str, lookup: impl FnOnce(&str) -> Result<T, E>) -> Option<Self> {
+        let trimmed = field.trim();
+        if trimmed.is_empty() {
+            return None;
+        }
+        Some(Self::new(trimmed, lookup(trimmed).ok()))
+    }
+
+    pub fn raw(&self) -> &str {
+        &self.raw
+    }
+
+    pub fn parsed(&self) -> Option<&T> {
+        self.parsed.as_ref()
+    }
+
+    /// True when the raw code was recognised by the lookup table
+    pub fn is_mapped(&self) -> bool {
+        self.parsed.is_some()
+    }
+
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Coded<U> {
+        Coded {
+            raw: self.raw,
+            parsed: self.parsed.map(f),
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq<T> for Coded<T> {
+    fn eq(&self, other: &T) -> bool {
+        self.parsed.as_ref() == Some(other)
+    }
+}
+
+impl<T> fmt::Display for Coded<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use log::info;
+
+    #[allow(dead_code)]
+    fn init_logger() {
+        static INIT: std::sync::Once = std::sync::Once::new();
+        INIT.call_once(|| {
+            env_logger::init();
+        });
+    }
+
+    #[test]
+    fn test_coded_keeps_unmapped_raw_value() {
+        // init_logger();
+
+        let coded: Option<Coded<u8>> = Coded::from_field(" XYZ ", |s| s.parse::<u8>());
+        info!("Unmapped coded value: {:?}", coded);
+        let coded = coded.unwrap();
+        assert_eq!(coded.raw(), "XYZ");
+        assert!(!coded.is_mapped());
+    }
+
+    #[test]
+    fn test_coded_empty_field_is_none() {
+        // init_logger();
+
+        let coded: Option<Coded<u8>> = Coded::from_field("   ", |s| s.parse::<u8>());
+        assert!(coded.is_none());
+    }
+
+    #[test]
+    fn test_coded_compares_against_parsed_value() {
+        // init_logger();
+
+        let coded = Coded::from_field("42", |s| s.parse::<u8>()).unwrap();
+        assert!(coded.is_mapped());
+        assert_eq!(coded, 42u8);
+        assert_eq!(coded.to_string(), "42");
+    }
+}
@@ -0,0 +1,184 @@
+//! GKG count types
+//!
+//! The COUNTS fields tag each number with a count type: GDELT's original set
+//! (`KILL`, `WOUND`, `ARREST`, `KIDNAP`, `SEIZE`, `AFFECT`, `PROTEST`) plus the CrisisLex
+//! themes that carry a number (`CRISISLEX_T03_DEAD`, ...). Anything else is kept as
+//! `Other` so new types stay countable.
+
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CountType {
+    Affect,
+    Arrest,
+    Kidnap,
+    Kill,
+    Protest,
+    Seize,
+    Wound,
+    /// `CRISISLEX_T03_DEAD`
+    Dead,
+    /// `CRISISLEX_T02_INJURED`
+    Injured,
+    /// `CRISISLEX_T08_MISSINGFOUNDTRAPPEDPEOPLE`
+    MissingFoundTrapped,
+    /// `CRISISLEX_T09_DISPLACEDRELOCATEDEVACUATED`
+    DisplacedRelocatedEvacuated,
+    /// A count type not listed above, kept verbatim
+    Other(String),
+}
+
+/// Broad grouping of count types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CountCategory {
+    /// Deaths and injuries
+    Violence,
+    Detention,
+    Displacement,
+    Protest,
+    /// People affected, missing or trapped
+    Affected,
+    Other,
+}
+
+impl CountType {
+    pub const KNOWN: &'static [CountType] = &[
+        CountType::Affect,
+        CountType::Arrest,
+        CountType::Kidnap,
+        CountType::Kill,
+        CountType::Protest,
+        CountType::Seize,
+        CountType::Wound,
+        CountType::Dead,
+        CountType::Injured,
+        CountType::MissingFoundTrapped,
+        CountType::DisplacedRelocatedEvacuated,
+    ];
+
+    /// The count type as it appears in the COUNTS field
+    pub fn code(&self) -> &str {
+        match self {
+            CountType::Affect => "AFFECT",
+            CountType::Arrest => "ARREST",
+            CountType::Kidnap => "KIDNAP",
+            CountType::Kill => "KILL",
+            CountType::Protest => "PROTEST",
+            CountType::Seize => "SEIZE",
+            CountType::Wound => "WOUND",
+            CountType::Dead => "CRISISLEX_T03_DEAD",
+            CountType::Injured => "CRISISLEX_T02_INJURED",
+            CountType::MissingFoundTrapped => "CRISISLEX_T08_MISSINGFOUNDTRAPPEDPEOPLE",
+            CountType::DisplacedRelocatedEvacuated => "CRISISLEX_T09_DISPLACEDRELOCATEDEVACUATED",
+            CountType::Other(code) => code,
+        }
+    }
+
+    pub fn category(&self) -> CountCategory {
+        match self {
+            CountType::Kill | CountType::Wound | CountType::Dead | CountType::Injured => {
+                CountCategory::Violence
+            }
+            CountType::Arrest | CountType::Kidnap | CountType::Seize => CountCategory::Detention,
+            CountType::DisplacedRelocatedEvacuated => CountCategory::Displacement,
+            CountType::Protest => CountCategory::Protest,
+            CountType::Affect | CountType::MissingFoundTrapped => CountCategory::Affected,
+            CountType::Other(_) => CountCategory::Other,
+        }
+    }
+
+    /// True for deaths and injuries
+    pub fn is_casualty(&self) -> bool {
+        self.category() == CountCategory::Violence
+    }
+
+    pub fn is_known(&self) -> bool {
+        !matches!(self, CountType::Other(_))
+    }
+}
+
+impl From<&str> for CountType {
+    fn from(value: &str) -> Self {
+        let code = value.trim();
+        CountType::KNOWN
+            .iter()
+            .find(|count_type| count_type.code() == code)
+            .cloned()
+            .unwrap_or_else(|| CountType::Other(code.to_string()))
+    }
+}
+
+impl fmt::Display for CountType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl CountCategory {
+    pub fn name(&self) -> &'static str {
+        match self {
+            CountCategory::Violence => "Violence",
+            CountCategory::Detention => "Detention",
+            CountCategory::Displacement => "Displacement",
+            CountCategory::Protest => "Protest",
+            CountCategory::Affected => "Affected",
+            CountCategory::Other => "Other",
+        }
+    }
+}
+
+impl fmt::Display for CountCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use log::info;
+
+    #[allow(dead_code)]
+    fn init_logger() {
+        static INIT: std::sync::Once = std::sync::Once::new();
+        INIT.call_once(|| {
+            env_logger::init();
+        });
+    }
+
+    #[test]
+    fn test_count_type_round_trip() {
+        // init_logger();
+
+        for count_type in CountType::KNOWN {
+            info!("{} -> {:?}", count_type, count_type.category());
+            assert_eq!(&CountType::from(count_type.code()), count_type);
+        }
+
+        let other = CountType::from("CRISISLEX_CRISISLEXREC");
+        assert_eq!(
+            other,
+            CountType::Other("CRISISLEX_CRISISLEXREC".to_string())
+        );
+        assert_eq!(other.to_string(), "CRISISLEX_CRISISLEXREC");
+        assert!(!other.is_known());
+    }
+
+    #[test]
+    fn test_count_type_categories() {
+        // init_logger();
+
+        assert!(CountType::Kill.is_casualty());
+        assert!(CountType::Injured.is_casualty());
+        assert_eq!(CountType::Kidnap.category(), CountCategory::Detention);
+        assert_eq!(
+            CountType::from("CRISISLEX_T09_DISPLACEDRELOCATEDEVACUATED").category(),
+            CountCategory::Displacement
+        );
+        assert_eq!(
+            CountType::from("PROTEST").category(),
+            CountCategory::Protest
+        );
+        assert_eq!(CountType::from("ZZZ").category(), CountCategory::Other);
+    }
+}
@@ -1,615 +1,754 @@
 use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::OnceLock;
 
 use crate::types::event_table::{actor::CAMEOCountryCode, event_geography::FIPSCountryCode};
 
-#[derive(Debug, Clone, PartialOrd, PartialEq)]
-pub enum CountryZone {
-    Unspecified,
-    WestBank,
-    Baghdad,
-    GazaStrip,
+/// UN M49 macro-geographical regions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum M49Region {
     Africa,
+    Americas,
     Asia,
-    Balkans,
+    Europe,
+    Oceania,
+}
+
+impl M49Region {
+    pub fn code(&self) -> u16 {
+        match self {
+            M49Region::Africa => 2,
+            M49Region::Americas => 19,
+            M49Region::Asia => 142,
+            M49Region::Europe => 150,
+            M49Region::Oceania => 9,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            M49Region::Africa => "Africa",
+            M49Region::Americas => "Americas",
+            M49Region::Asia => "Asia",
+            M49Region::Europe => "Europe",
+            M49Region::Oceania => "Oceania",
+        }
+    }
+}
+
+/// UN M49 sub-regions. Africa and the Americas use the intermediate regions
+/// (Western Africa, Caribbean, ...) since those are the ones people actually filter on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum M49SubRegion {
+    NorthernAfrica,
+    EasternAfrica,
+    MiddleAfrica,
+    SouthernAfrica,
+    WesternAfrica,
     Caribbean,
-    Caucasus,
-    CentralAfrica,
+    CentralAmerica,
+    SouthAmerica,
+    NorthernAmerica,
     CentralAsia,
-    CentralEurope,
-    EastIndies,
-    EasternAfrica,
+    EasternAsia,
+    SouthEasternAsia,
+    SouthernAsia,
+    WesternAsia,
     EasternEurope,
-    Europe,
-    LatinAmerica,
-    MiddleEast,
-    Mediterranean,
-    NorthAfrica,
-    NorthAmerica,
-    PersianGulf,
-    Scandinavia,
-    SouthAmerica,
-    SouthAsia,
-    SoutheastAsia,
-    SouthernAfrica,
-    WestAfrica,
-    TheWest,
-    Afghanistan,
-    AlandIslands,
-    Albania,
-    Algeria,
-    AmericanSamoa,
-    Andorra,
-    Angola,
-    Anguilla,
-    AntiguaandBarbuda,
-    Argentina,
-    Armenia,
-    Aruba,
-    Australia,
-    Austria,
-    Azerbaijan,
-    Bahamas,
-    Bahrain,
-    Bangladesh,
-    Barbados,
-    Belarus,
-    Belgium,
-    Belize,
-    Benin,
-    Bermuda,
-    Bhutan,
-    Bolivia,
-    BosniaandHerzegovina,
-    Botswana,
-    Brazil,
-    BritishVirginIslands,
-    BruneiDarussalam,
-    Bulgaria,
-    BurkinaFaso,
-    Burundi,
-    Cambodia,
-    Cameroon,
-    Canada,
-    CapeVerde,
-    CaymanIslands,
-    CentralAfricanRepublic,
-    Chad,
-    Chile,
-    China,
-    Columbia,
-    Comoros,
-    DemocraticRepublicoftheCongo,
-    PeoplesRepublicoftheCongo,
-    CookIslands,
-    CostaRica,
-    IvoryCoast,
-    Croatia,
-    Cuba,
-    Cyprus,
-    CzechRepublic,
-    Denmark,
-    Djibouti,
-    Dominica,
-    DominicanRepublic,
-    EastTimor,
-    Ecuador,
-    Egypt,
-    ElSalvador,
-    EquatorialGuinea,
-    Eritrea,
-    Estonia,
-    Ethiopia,
-    FaeroeIslands,
-    FalklandIslands,
-    Fiji,
-    Finland,
-    France,
-    FrenchGuiana,
-    FrenchPolynesia,
-    Gabon,
-    Gambia,
-    Georgia,
-    Germany,
-    Ghana,
-    Gibraltar,
-    Greece,
-    Greenland,
-    Grenada,
-    Guadeloupe,
-    Guam,
-    Guatemala,
-    Guinea,
-    GuineaBissau,
-    Guyana,
-    Haiti,
-    VaticanCity,
-    Honduras,
-    HongKong,
-    Hungary,
-    Iceland,
-    India,
-    Indonesia,
-    Iran,
-    Iraq,
-    Ireland,
-    IsleofMan,
-    Israel,
-    Italy,
-    Jamaica,
-    Japan,
-    Jordan,
-    Kazakhstan,
-    Kenya,
-    Kiribati,
-    NorthKorea,
-    SouthKorea,
-    Kuwait,
-    Kyrgyzstan,
-    Laos,
-    Latvia,
-    Lebanon,
-    Lesotho,
-    Liberia,
-    Libya,
-    Liechtenstein,
-    Lithuania,
-    Luxembourg,
-    Macao,
-    Macedonia,
-    Madagascar,
-    Malawi,
-    Malaysia,
-    Maldives,
-    Mali,
-    Malta,
-    MarshallIslands,
-    Martinique,
-    Mauritania,
-    Mauritius,
-    Mayotte,
-    Mexico,
+    NorthernEurope,
+    SouthernEurope,
+    WesternEurope,
+    AustraliaNewZealand,
+    Melanesia,
     Micronesia,
-    Moldova,
-    Monaco,
-    Mongolia,
-    Montenegro,
-    Montserrat,
-    Morocco,
-    Mozambique,
-    Myanmar,
-    Namibia,
-    Nauru,
-    Nepal,
-    Netherlands,
-    NetherlandsAntilles,
-    NewCaledonia,
-    NewZealand,
-    Nicaragua,
-    Niger,
-    Nigeria,
-    Niue,
-    NorfolkIsland,
-    NorthernMarianaIslands,
-    Norway,
-    OccupiedPalestinianTerritory,
-    Oman,
-    Pakistan,
-    Palau,
-    Panama,
-    PapuaNewGuinea,
-    Paraguay,
-    Peru,
-    Philippines,
-    Pitcairn,
-    Poland,
-    Portugal,
-    PuertoRico,
-    Qatar,
-    Runion,
-    Romania,
-    Russia,
-    Rwanda,
-    SaintHelena,
-    SaintKittsNevis,
-    SaintLucia,
-    SaintPierreandMiquelon,
-    SaintVincentandtheGrenadines,
-    Samoa,
-    SanMarino,
-    SaoTomeandPrincipe,
-    SaudiArabia,
-    Senegal,
-    Serbia,
-    Seychelles,
-    SierraLeone,
-    Singapore,
-    Slovakia,
-    Slovenia,
-    SolomonIslands,
-    Somalia,
-    SouthAfrica,
-    Spain,
-    SriLanka,
-    Sudan,
-    Suriname,
-    SvalbardandJanMayenIslands,
-    Swaziland,
-    Sweden,
-    Switzerland,
-    Syria,
-    Taiwan,
-    Tajikistan,
-    Tanzania,
-    Thailand,
-    Togo,
-    Tokelau,
-    Tonga,
-    TrinidadandTobago,
-    Tunisia,
-    Turkey,
-    Turkmenistan,
-    TurksandCaicosIslands,
-    Tuvalu,
-    Uganda,
-    Ukraine,
-    UnitedArabEmirates,
-    UnitedKingdom,
-    UnitedStates,
-    UnitedStatesVirginIslands,
-    Uruguay,
-    Uzbekistan,
-    Vanuatu,
-    Venezuela,
-    Vietnam,
-    WallisandFutunaIslands,
-    WesternSahara,
-    Yemen,
-    Zambia,
-    Zimbabwe,
-
-    AkrotiriSovereignBaseArea,
-    Antarctica,
-    AshmoreandCartierIslands,
-
-    BahamasThe,
-    BakerIsland,
-    BassasdaIndia,
-    BosniaHerzegovina,
-    BouvetIsland,
-    BritishIndianOceanTerritory,
-    Brunei,
-    ChristmasIsland,
-    ClippertonIsland,
-    CocosKeelingIslands,
-    Colombia,
-    Congo,
-    CoralSeaIslands,
-    CotedIvoire,
-    Czechoslovakia,
-    DhekeliaSovereignBaseArea,
-    EtorofuHabomaiKunashiriandShikotanIslands,
-    EuropaIsland,
-    FalklandIslandsIslasMalvinas,
-    FaroeIslands,
-    FrenchSouthernandAntarcticLands,
-    GloriosoIslands,
-    Guernsey,
-    HeardIslandandMcDonaldIslands,
-    HowlandIsland,
-    JanMayen,
-    JarvisIsland,
-    Jersey,
-    JohnstonAtoll,
-    JuandeNovaIsland,
-    KingmanReef,
-    Kosovo,
-    Macau,
-    MidwayIslands,
-    NavassaIsland,
-    NoMansLand,
-    Oceans,
-    PalmyraAtoll,
-    ParacelIslands,
-    PitcairnIslands,
-    Reunion,
-    SaintKittsandNevis,
-    SaintMartin,
-    SaintBarthelemyIsland,
-    SouthGeorgiaandtheSouthSandwichIslands,
-    SouthSudan,
-    SpratlyIslands,
-    Svalbard,
-    TromelinIsland,
-    UnderseaFeatures,
-    UndesignatedSovereignty,
-    VietnamDemocraticRepublicof,
-    VirginIslands,
-    WakeIsland,
-    WallisandFutuna,
+    Polynesia,
+}
+
+impl M49SubRegion {
+    pub const fn region(&self) -> M49Region {
+        match self {
+            M49SubRegion::NorthernAfrica
+            | M49SubRegion::EasternAfrica
+            | M49SubRegion::MiddleAfrica
+            | M49SubRegion::SouthernAfrica
+            | M49SubRegion::WesternAfrica => M49Region::Africa,
+            M49SubRegion::Caribbean
+            | M49SubRegion::CentralAmerica
+            | M49SubRegion::SouthAmerica
+            | M49SubRegion::NorthernAmerica => M49Region::Americas,
+            M49SubRegion::CentralAsia
+            | M49SubRegion::EasternAsia
+            | M49SubRegion::SouthEasternAsia
+            | M49SubRegion::SouthernAsia
+            | M49SubRegion::WesternAsia => M49Region::Asia,
+            M49SubRegion::EasternEurope
+            | M49SubRegion::NorthernEurope
+            | M49SubRegion::SouthernEurope
+            | M49SubRegion::WesternEurope => M49Region::Europe,
+            M49SubRegion::AustraliaNewZealand
+            | M49SubRegion::Melanesia
+            | M49SubRegion::Micronesia
+            | M49SubRegion::Polynesia => M49Region::Oceania,
+        }
+    }
+
+    pub fn code(&self) -> u16 {
+        match self {
+            M49SubRegion::NorthernAfrica => 15,
+            M49SubRegion::EasternAfrica => 14,
+            M49SubRegion::MiddleAfrica => 17,
+            M49SubRegion::SouthernAfrica => 18,
+            M49SubRegion::WesternAfrica => 11,
+            M49SubRegion::Caribbean => 29,
+            M49SubRegion::CentralAmerica => 13,
+            M49SubRegion::SouthAmerica => 5,
+            M49SubRegion::NorthernAmerica => 21,
+            M49SubRegion::CentralAsia => 143,
+            M49SubRegion::EasternAsia => 30,
+            M49SubRegion::SouthEasternAsia => 35,
+            M49SubRegion::SouthernAsia => 34,
+            M49SubRegion::WesternAsia => 145,
+            M49SubRegion::EasternEurope => 151,
+            M49SubRegion::NorthernEurope => 154,
+            M49SubRegion::SouthernEurope => 39,
+            M49SubRegion::WesternEurope => 155,
+            M49SubRegion::AustraliaNewZealand => 53,
+            M49SubRegion::Melanesia => 54,
+            M49SubRegion::Micronesia => 57,
+            M49SubRegion::Polynesia => 61,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            M49SubRegion::NorthernAfrica => "Northern Africa",
+            M49SubRegion::EasternAfrica => "Eastern Africa",
+            M49SubRegion::MiddleAfrica => "Middle Africa",
+            M49SubRegion::SouthernAfrica => "Southern Africa",
+            M49SubRegion::WesternAfrica => "Western Africa",
+            M49SubRegion::Caribbean => "Caribbean",
+            M49SubRegion::CentralAmerica => "Central America",
+            M49SubRegion::SouthAmerica => "South America",
+            M49SubRegion::NorthernAmerica => "Northern America",
+            M49SubRegion::CentralAsia => "Central Asia",
+            M49SubRegion::EasternAsia => "Eastern Asia",
+            M49SubRegion::SouthEasternAsia => "South-eastern Asia",
+            M49SubRegion::SouthernAsia => "Southern Asia",
+            M49SubRegion::WesternAsia => "Western Asia",
+            M49SubRegion::EasternEurope => "Eastern Europe",
+            M49SubRegion::NorthernEurope => "Northern Europe",
+            M49SubRegion::SouthernEurope => "Southern Europe",
+            M49SubRegion::WesternEurope => "Western Europe",
+            M49SubRegion::AustraliaNewZealand => "Australia and New Zealand",
+            M49SubRegion::Melanesia => "Melanesia",
+            M49SubRegion::Micronesia => "Micronesia",
+            M49SubRegion::Polynesia => "Polynesia",
+        }
+    }
+}
+
+struct CountryRecord {
+    name: &'static str,
+    cameo: Option<&'static str>,
+    fips: Option<&'static str>,
+    iso_alpha2: Option<&'static str>,
+    iso_alpha3: Option<&'static str>,
+    iso_numeric: Option<u16>,
+    region: Option<M49Region>,
+    subregion: Option<M49SubRegion>,
+    supranational: bool,
+}
+
+const fn region_of(subregion: Option<M49SubRegion>) -> Option<M49Region> {
+    match subregion {
+        Some(subregion) => Some(subregion.region()),
+        None => None,
+    }
+}
+
+macro_rules! code {
+    (-) => {
+        None
+    };
+    ($code:literal) => {
+        Some($code)
+    };
+}
+
+macro_rules! region {
+    (-) => {
+        None
+    };
+    ($region:ident) => {
+        Some(M49Region::$region)
+    };
+}
+
+macro_rules! subregion {
+    (-) => {
+        None
+    };
+    ($subregion:ident) => {
+        Some(M49SubRegion::$subregion)
+    };
+}
+
+/// Builds `CountryZone` and its crosswalk from a single table so the two can't drift apart.
+macro_rules! country_zones {
+    (
+        supranational {
+            $( $zone:ident => $zone_name:literal, $zone_cameo:literal, $zone_region:tt, $zone_subregion:tt; )*
+        }
+        territories {
+            $( $(#[$attr:meta])* $variant:ident => $name:literal, $cameo:tt, $fips:tt, $iso2:tt, $iso3:tt, $numeric:tt, $subregion:tt; )*
+        }
+    ) => {
+        #[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Eq, Hash, Serialize, Deserialize)]
+        pub enum CountryZone {
+            $( $zone, )*
+            $( $(#[$attr])* $variant, )*
+        }
+
+        impl CountryZone {
+            /// Every zone, in declaration order
+            pub const ALL: &'static [CountryZone] = &[
+                $( CountryZone::$zone, )*
+                $( CountryZone::$variant, )*
+            ];
+        }
+
+        // Indexed by discriminant, so the order must match the enum above
+        const COUNTRY_RECORDS: &[CountryRecord] = &[
+            $(
+                CountryRecord {
+                    name: $zone_name,
+                    cameo: Some($zone_cameo),
+                    fips: None,
+                    iso_alpha2: None,
+                    iso_alpha3: None,
+                    iso_numeric: None,
+                    region: region!($zone_region),
+                    subregion: subregion!($zone_subregion),
+                    supranational: true,
+                },
+            )*
+            $(
+                CountryRecord {
+                    name: $name,
+                    cameo: code!($cameo),
+                    fips: code!($fips),
+                    iso_alpha2: code!($iso2),
+                    iso_alpha3: code!($iso3),
+                    iso_numeric: code!($numeric),
+                    region: region_of(subregion!($subregion)),
+                    subregion: subregion!($subregion),
+                    supranational: false,
+                },
+            )*
+        ];
+    };
+}
+
+// Crosswalk between CAMEO, FIPS 10-4, ISO 3166-1 and UN M49.
+//
+// CAMEO still uses a handful of pre-2000 ISO codes (ROM for Romania, TMP for East Timor,
+// IMY for Isle of Man, MTN for Montenegro), so the CAMEO and ISO alpha-3 columns differ there.
+// Kosovo has no ISO assignment; XK/XKX are the user-assigned codes in common use.
+// Small FIPS-only territories (US minor islands, French Southern islands, ...) have no
+// ISO code of their own and are left without one rather than mapped onto their parent.
+country_zones! {
+    supranational {
+        // variant => name, CAMEO, M49 region, M49 sub-region
+        Africa => "Africa", "AFR", Africa, -;
+        Asia => "Asia", "ASA", Asia, -;
+        Balkans => "Balkans", "BLK", Europe, SouthernEurope;
+        Caribbean => "Caribbean", "CRB", Americas, Caribbean;
+        Caucasus => "Caucasus", "CAU", Asia, WesternAsia;
+        CentralAfrica => "Central Africa", "CFR", Africa, MiddleAfrica;
+        CentralAsia => "Central Asia", "CAS", Asia, CentralAsia;
+        CentralEurope => "Central Europe", "CEU", Europe, -;
+        EastIndies => "East Indies", "EIN", Asia, SouthEasternAsia;
+        EasternAfrica => "Eastern Africa", "EAF", Africa, EasternAfrica;
+        EasternEurope => "Eastern Europe", "EEU", Europe, EasternEurope;
+        Europe => "Europe", "EUR", Europe, -;
+        LatinAmerica => "Latin America", "LAM", Americas, -;
+        MiddleEast => "Middle East", "MEA", Asia, WesternAsia;
+        Mediterranean => "Mediterranean", "MDT", -, -;
+        NorthAfrica => "North Africa", "NAF", Africa, NorthernAfrica;
+        NorthAmerica => "North America", "NMR", Americas, NorthernAmerica;
+        PersianGulf => "Persian Gulf", "PGS", Asia, WesternAsia;
+        Scandinavia => "Scandinavia", "SCN", Europe, NorthernEurope;
+        SouthAmerica => "South America", "SAM", Americas, SouthAmerica;
+        SouthAsia => "South Asia", "SAS", Asia, SouthernAsia;
+        SoutheastAsia => "Southeast Asia", "SEA", Asia, SouthEasternAsia;
+        SouthernAfrica => "Southern Africa", "SAF", Africa, SouthernAfrica;
+        WestAfrica => "West Africa", "WAF", Africa, WesternAfrica;
+        TheWest => "The West", "WST", -, -;
+    }
+    territories {
+        // variant => name, CAMEO, FIPS, ISO alpha-2, ISO alpha-3, ISO numeric, M49 sub-region
+        Unspecified => "Unspecified", -, -, -, -, -, -;
+        WestBank => "West Bank", "WSB", "WE", -, -, -, WesternAsia;
+        Baghdad => "Baghdad", "BAG", -, -, -, -, WesternAsia;
+        GazaStrip => "Gaza Strip", "GZS", "GZ", -, -, -, WesternAsia;
+        Afghanistan => "Afghanistan", "AFG", "AF", "AF", "AFG", 4, SouthernAsia;
+        AlandIslands => "Aland Islands", "ALA", -, "AX", "ALA", 248, NorthernEurope;
+        Albania => "Albania", "ALB", "AL", "AL", "ALB", 8, SouthernEurope;
+        Algeria => "Algeria", "DZA", "AG", "DZ", "DZA", 12, NorthernAfrica;
+        AmericanSamoa => "American Samoa", "ASM", "AQ", "AS", "ASM", 16, Polynesia;
+        Andorra => "Andorra", "AND", "AN", "AD", "AND", 20, SouthernEurope;
+        Angola => "Angola", "AGO", "AO", "AO", "AGO", 24, MiddleAfrica;
+        Anguilla => "Anguilla", "AIA", "AV", "AI", "AIA", 660, Caribbean;
+        AntiguaandBarbuda => "Antigua and Barbuda", "ATG", "AC", "AG", "ATG", 28, Caribbean;
+        Argentina => "Argentina", "ARG", "AR", "AR", "ARG", 32, SouthAmerica;
+        Armenia => "Armenia", "ARM", "AM", "AM", "ARM", 51, WesternAsia;
+        Aruba => "Aruba", "ABW", "AA", "AW", "ABW", 533, Caribbean;
+        Australia => "Australia", "AUS", "AS", "AU", "AUS", 36, AustraliaNewZealand;
+        Austria => "Austria", "AUT", "AU", "AT", "AUT", 40, WesternEurope;
+        Azerbaijan => "Azerbaijan", "AZE", "AJ", "AZ", "AZE", 31, WesternAsia;
+        #[serde(alias = "BahamasThe")]
+        Bahamas => "Bahamas", "BHS", "BF", "BS", "BHS", 44, Caribbean;
+        Bahrain => "Bahrain", "BHR", "BA", "BH", "BHR", 48, WesternAsia;
+        Bangladesh => "Bangladesh", "BGD", "BG", "BD", "BGD", 50, SouthernAsia;
+        Barbados => "Barbados", "BRB", "BB", "BB", "BRB", 52, Caribbean;
+        Belarus => "Belarus", "BLR", "BO", "BY", "BLR", 112, EasternEurope;
+        Belgium => "Belgium", "BEL", "BE", "BE", "BEL", 56, WesternEurope;
+        Belize => "Belize", "BLZ", "BH", "BZ", "BLZ", 84, CentralAmerica;
+        Benin => "Benin", "BEN", "BN", "BJ", "BEN", 204, WesternAfrica;
+        Bermuda => "Bermuda", "BMU", "BD", "BM", "BMU", 60, NorthernAmerica;
+        Bhutan => "Bhutan", "BTN", "BT", "BT", "BTN", 64, SouthernAsia;
+        Bolivia => "Bolivia", "BOL", "BL", "BO", "BOL", 68, SouthAmerica;
+        #[serde(alias = "BosniaHerzegovina")]
+        BosniaandHerzegovina => "Bosnia and Herzegovina", "BIH", "BK", "BA", "BIH", 70, SouthernEurope;
+        Botswana => "Botswana", "BWA", "BC", "BW", "BWA", 72, SouthernAfrica;
+        Brazil => "Brazil", "BRA", "BR", "BR", "BRA", 76, SouthAmerica;
+        BritishVirginIslands => "British Virgin Islands", "VGB", "VI", "VG", "VGB", 92, Caribbean;
+        #[serde(alias = "Brunei")]
+        BruneiDarussalam => "Brunei Darussalam", "BRN", "BX", "BN", "BRN", 96, SouthEasternAsia;
+        Bulgaria => "Bulgaria", "BGR", "BU", "BG", "BGR", 100, EasternEurope;
+        BurkinaFaso => "Burkina Faso", "BFA", "UV", "BF", "BFA", 854, WesternAfrica;
+        Burundi => "Burundi", "BDI", "BY", "BI", "BDI", 108, EasternAfrica;
+        Cambodia => "Cambodia", "KHM", "CB", "KH", "KHM", 116, SouthEasternAsia;
+        Cameroon => "Cameroon", "CMR", "CM", "CM", "CMR", 120, MiddleAfrica;
+        Canada => "Canada", "CAN", "CA", "CA", "CAN", 124, NorthernAmerica;
+        CapeVerde => "Cape Verde", "CPV", "CV", "CV", "CPV", 132, WesternAfrica;
+        CaymanIslands => "Cayman Islands", "CYM", "CJ", "KY", "CYM", 136, Caribbean;
+        CentralAfricanRepublic => "Central African Republic", "CAF", "CT", "CF", "CAF", 140, MiddleAfrica;
+        Chad => "Chad", "TCD", "CD", "TD", "TCD", 148, MiddleAfrica;
+        Chile => "Chile", "CHL", "CI", "CL", "CHL", 152, SouthAmerica;
+        China => "China", "CHN", "CH", "CN", "CHN", 156, EasternAsia;
+        #[serde(alias = "Columbia")]
+        Colombia => "Colombia", "COL", "CO", "CO", "COL", 170, SouthAmerica;
+        Comoros => "Comoros", "COM", "CN", "KM", "COM", 174, EasternAfrica;
+        DemocraticRepublicoftheCongo => "Democratic Republic of the Congo", "COD", "CG", "CD", "COD", 180, MiddleAfrica;
+        #[serde(alias = "Congo")]
+        PeoplesRepublicoftheCongo => "Republic of the Congo", "COG", "CF", "CG", "COG", 178, MiddleAfrica;
+        CookIslands => "Cook Islands", "COK", "CW", "CK", "COK", 184, Polynesia;
+        CostaRica => "Costa Rica", "CRI", "CS", "CR", "CRI", 188, CentralAmerica;
+        #[serde(alias = "CotedIvoire")]
+        IvoryCoast => "Ivory Coast", "CIV", "IV", "CI", "CIV", 384, WesternAfrica;
+        Croatia => "Croatia", "HRV", "HR", "HR", "HRV", 191, SouthernEurope;
+        Cuba => "Cuba", "CUB", "CU", "CU", "CUB", 192, Caribbean;
+        Cyprus => "Cyprus", "CYP", "CY", "CY", "CYP", 196, WesternAsia;
+        CzechRepublic => "Czech Republic", "CZE", "EZ", "CZ", "CZE", 203, EasternEurope;
+        Denmark => "Denmark", "DNK", "DA", "DK", "DNK", 208, NorthernEurope;
+        Djibouti => "Djibouti", "DJI", "DJ", "DJ", "DJI", 262, EasternAfrica;
+        Dominica => "Dominica", "DMA", "DO", "DM", "DMA", 212, Caribbean;
+        DominicanRepublic => "Dominican Republic", "DOM", "DR", "DO", "DOM", 214, Caribbean;
+        EastTimor => "East Timor", "TMP", "TT", "TL", "TLS", 626, SouthEasternAsia;
+        Ecuador => "Ecuador", "ECU", "EC", "EC", "ECU", 218, SouthAmerica;
+        Egypt => "Egypt", "EGY", "EG", "EG", "EGY", 818, NorthernAfrica;
+        ElSalvador => "El Salvador", "SLV", "ES", "SV", "SLV", 222, CentralAmerica;
+        EquatorialGuinea => "Equatorial Guinea", "GNQ", "EK", "GQ", "GNQ", 226, MiddleAfrica;
+        Eritrea => "Eritrea", "ERI", "ER", "ER", "ERI", 232, EasternAfrica;
+        Estonia => "Estonia", "EST", "EN", "EE", "EST", 233, NorthernEurope;
+        Ethiopia => "Ethiopia", "ETH", "ET", "ET", "ETH", 231, EasternAfrica;
+        #[serde(alias = "FaeroeIslands")]
+        FaroeIslands => "Faroe Islands", "FRO", "FO", "FO", "FRO", 234, NorthernEurope;
+        #[serde(alias = "FalklandIslandsIslasMalvinas")]
+        FalklandIslands => "Falkland Islands", "FLK", "FK", "FK", "FLK", 238, SouthAmerica;
+        Fiji => "Fiji", "FJI", "FJ", "FJ", "FJI", 242, Melanesia;
+        Finland => "Finland", "FIN", "FI", "FI", "FIN", 246, NorthernEurope;
+        France => "France", "FRA", "FR", "FR", "FRA", 250, WesternEurope;
+        FrenchGuiana => "French Guiana", "GUF", "FG", "GF", "GUF", 254, SouthAmerica;
+        FrenchPolynesia => "French Polynesia", "PYF", "FP", "PF", "PYF", 258, Polynesia;
+        Gabon => "Gabon", "GAB", "GB", "GA", "GAB", 266, MiddleAfrica;
+        Gambia => "Gambia", "GMB", "GA", "GM", "GMB", 270, WesternAfrica;
+        Georgia => "Georgia", "GEO", "GG", "GE", "GEO", 268, WesternAsia;
+        Germany => "Germany", "DEU", "GM", "DE", "DEU", 276, WesternEurope;
+        Ghana => "Ghana", "GHA", "GH", "GH", "GHA", 288, WesternAfrica;
+        Gibraltar => "Gibraltar", "GIB", "GI", "GI", "GIB", 292, SouthernEurope;
+        Greece => "Greece", "GRC", "GR", "GR", "GRC", 300, SouthernEurope;
+        Greenland => "Greenland", "GRL", "GL", "GL", "GRL", 304, NorthernAmerica;
+        Grenada => "Grenada", "GRD", "GJ", "GD", "GRD", 308, Caribbean;
+        Guadeloupe => "Guadeloupe", "GLP", "GP", "GP", "GLP", 312, Caribbean;
+        Guam => "Guam", "GUM", "GQ", "GU", "GUM", 316, Micronesia;
+        Guatemala => "Guatemala", "GTM", "GT", "GT", "GTM", 320, CentralAmerica;
+        Guinea => "Guinea", "GIN", "GV", "GN", "GIN", 324, WesternAfrica;
+        GuineaBissau => "Guinea-Bissau", "GNB", "PU", "GW", "GNB", 624, WesternAfrica;
+        Guyana => "Guyana", "GUY", "GY", "GY", "GUY", 328, SouthAmerica;
+        Haiti => "Haiti", "HTI", "HA", "HT", "HTI", 332, Caribbean;
+        VaticanCity => "Vatican City", "VAT", "VT", "VA", "VAT", 336, SouthernEurope;
+        Honduras => "Honduras", "HND", "HO", "HN", "HND", 340, CentralAmerica;
+        HongKong => "Hong Kong", "HKG", "HK", "HK", "HKG", 344, EasternAsia;
+        Hungary => "Hungary", "HUN", "HU", "HU", "HUN", 348, EasternEurope;
+        Iceland => "Iceland", "ISL", "IC", "IS", "ISL", 352, NorthernEurope;
+        India => "India", "IND", "IN", "IN", "IND", 356, SouthernAsia;
+        Indonesia => "Indonesia", "IDN", "ID", "ID", "IDN", 360, SouthEasternAsia;
+        Iran => "Iran", "IRN", "IR", "IR", "IRN", 364, SouthernAsia;
+        Iraq => "Iraq", "IRQ", "IZ", "IQ", "IRQ", 368, WesternAsia;
+        Ireland => "Ireland", "IRL", "EI", "IE", "IRL", 372, NorthernEurope;
+        IsleofMan => "Isle of Man", "IMY", "IM", "IM", "IMN", 833, NorthernEurope;
+        Israel => "Israel", "ISR", "IS", "IL", "ISR", 376, WesternAsia;
+        Italy => "Italy", "ITA", "IT", "IT", "ITA", 380, SouthernEurope;
+        Jamaica => "Jamaica", "JAM", "JM", "JM", "JAM", 388, Caribbean;
+        Japan => "Japan", "JPN", "JA", "JP", "JPN", 392, EasternAsia;
+        Jordan => "Jordan", "JOR", "JO", "JO", "JOR", 400, WesternAsia;
+        Kazakhstan => "Kazakhstan", "KAZ", "KZ", "KZ", "KAZ", 398, CentralAsia;
+        Kenya => "Kenya", "KEN", "KE", "KE", "KEN", 404, EasternAfrica;
+        Kiribati => "Kiribati", "KIR", "KR", "KI", "KIR", 296, Micronesia;
+        NorthKorea => "North Korea", "PRK", "KN", "KP", "PRK", 408, EasternAsia;
+        SouthKorea => "South Korea", "KOR", "KS", "KR", "KOR", 410, EasternAsia;
+        Kuwait => "Kuwait", "KWT", "KU", "KW", "KWT", 414, WesternAsia;
+        Kyrgyzstan => "Kyrgyzstan", "KGZ", "KG", "KG", "KGZ", 417, CentralAsia;
+        Laos => "Laos", "LAO", "LA", "LA", "LAO", 418, SouthEasternAsia;
+        Latvia => "Latvia", "LVA", "LG", "LV", "LVA", 428, NorthernEurope;
+        Lebanon => "Lebanon", "LBN", "LE", "LB", "LBN", 422, WesternAsia;
+        Lesotho => "Lesotho", "LSO", "LT", "LS", "LSO", 426, SouthernAfrica;
+        Liberia => "Liberia", "LBR", "LI", "LR", "LBR", 430, WesternAfrica;
+        Libya => "Libya", "LBY", "LY", "LY", "LBY", 434, NorthernAfrica;
+        Liechtenstein => "Liechtenstein", "LIE", "LS", "LI", "LIE", 438, WesternEurope;
+        Lithuania => "Lithuania", "LTU", "LH", "LT", "LTU", 440, NorthernEurope;
+        Luxembourg => "Luxembourg", "LUX", "LU", "LU", "LUX", 442, WesternEurope;
+        #[serde(alias = "Macau")]
+        Macao => "Macao", "MAC", "MC", "MO", "MAC", 446, EasternAsia;
+        Macedonia => "Macedonia", "MKD", "MK", "MK", "MKD", 807, SouthernEurope;
+        Madagascar => "Madagascar", "MDG", "MA", "MG", "MDG", 450, EasternAfrica;
+        Malawi => "Malawi", "MWI", "MI", "MW", "MWI", 454, EasternAfrica;
+        Malaysia => "Malaysia", "MYS", "MY", "MY", "MYS", 458, SouthEasternAsia;
+        Maldives => "Maldives", "MDV", "MV", "MV", "MDV", 462, SouthernAsia;
+        Mali => "Mali", "MLI", "ML", "ML", "MLI", 466, WesternAfrica;
+        Malta => "Malta", "MLT", "MT", "MT", "MLT", 470, SouthernEurope;
+        MarshallIslands => "Marshall Islands", "MHL", "RM", "MH", "MHL", 584, Micronesia;
+        Martinique => "Martinique", "MTQ", "MB", "MQ", "MTQ", 474, Caribbean;
+        Mauritania => "Mauritania", "MRT", "MR", "MR", "MRT", 478, WesternAfrica;
+        Mauritius => "Mauritius", "MUS", "MP", "MU", "MUS", 480, EasternAfrica;
+        Mayotte => "Mayotte", "MYT", "MF", "YT", "MYT", 175, EasternAfrica;
+        Mexico => "Mexico", "MEX", "MX", "MX", "MEX", 484, CentralAmerica;
+        Micronesia => "Micronesia", "FSM", "FM", "FM", "FSM", 583, Micronesia;
+        Moldova => "Moldova", "MDA", "MD", "MD", "MDA", 498, EasternEurope;
+        Monaco => "Monaco", "MCO", "MN", "MC", "MCO", 492, WesternEurope;
+        Mongolia => "Mongolia", "MNG", "MG", "MN", "MNG", 496, EasternAsia;
+        Montenegro => "Montenegro", "MTN", "MJ", "ME", "MNE", 499, SouthernEurope;
+        Montserrat => "Montserrat", "MSR", "MH", "MS", "MSR", 500, Caribbean;
+        Morocco => "Morocco", "MAR", "MO", "MA", "MAR", 504, NorthernAfrica;
+        Mozambique => "Mozambique", "MOZ", "MZ", "MZ", "MOZ", 508, EasternAfrica;
+        Myanmar => "Myanmar", "MMR", "BM", "MM", "MMR", 104, SouthEasternAsia;
+        Namibia => "Namibia", "NAM", "WA", "NA", "NAM", 516, SouthernAfrica;
+        Nauru => "Nauru", "NRU", "NR", "NR", "NRU", 520, Micronesia;
+        Nepal => "Nepal", "NPL", "NP", "NP", "NPL", 524, SouthernAsia;
+        Netherlands => "Netherlands", "NLD", "NL", "NL", "NLD", 528, WesternEurope;
+        NetherlandsAntilles => "Netherlands Antilles", "ANT", "NT", "AN", "ANT", 530, Caribbean;
+        NewCaledonia => "New Caledonia", "NCL", "NC", "NC", "NCL", 540, Melanesia;
+        NewZealand => "New Zealand", "NZL", "NZ", "NZ", "NZL", 554, AustraliaNewZealand;
+        Nicaragua => "Nicaragua", "NIC", "NU", "NI", "NIC", 558, CentralAmerica;
+        Niger => "Niger", "NER", "NG", "NE", "NER", 562, WesternAfrica;
+        Nigeria => "Nigeria", "NGA", "NI", "NG", "NGA", 566, WesternAfrica;
+        Niue => "Niue", "NIU", "NE", "NU", "NIU", 570, Polynesia;
+        NorfolkIsland => "Norfolk Island", "NFK", "NF", "NF", "NFK", 574, AustraliaNewZealand;
+        NorthernMarianaIslands => "Northern Mariana Islands", "MNP", "CQ", "MP", "MNP", 580, Micronesia;
+        Norway => "Norway", "NOR", "NO", "NO", "NOR", 578, NorthernEurope;
+        OccupiedPalestinianTerritory => "Occupied Palestinian Territory", "PSE", -, "PS", "PSE", 275, WesternAsia;
+        Oman => "Oman", "OMN", "MU", "OM", "OMN", 512, WesternAsia;
+        Pakistan => "Pakistan", "PAK", "PK", "PK", "PAK", 586, SouthernAsia;
+        Palau => "Palau", "PLW", "PS", "PW", "PLW", 585, Micronesia;
+        Panama => "Panama", "PAN", "PM", "PA", "PAN", 591, CentralAmerica;
+        PapuaNewGuinea => "Papua New Guinea", "PNG", "PP", "PG", "PNG", 598, Melanesia;
+        Paraguay => "Paraguay", "PRY", "PA", "PY", "PRY", 600, SouthAmerica;
+        Peru => "Peru", "PER", "PE", "PE", "PER", 604, SouthAmerica;
+        Philippines => "Philippines", "PHL", "RP", "PH", "PHL", 608, SouthEasternAsia;
+        #[serde(alias = "PitcairnIslands")]
+        Pitcairn => "Pitcairn", "PCN", "PC", "PN", "PCN", 612, Polynesia;
+        Poland => "Poland", "POL", "PL", "PL", "POL", 616, EasternEurope;
+        Portugal => "Portugal", "PRT", "PO", "PT", "PRT", 620, SouthernEurope;
+        PuertoRico => "Puerto Rico", "PRI", "RQ", "PR", "PRI", 630, Caribbean;
+        Qatar => "Qatar", "QAT", "QA", "QA", "QAT", 634, WesternAsia;
+        #[serde(alias = "Runion")]
+        Reunion => "Reunion", "REU", "RE", "RE", "REU", 638, EasternAfrica;
+        Romania => "Romania", "ROM", "RO", "RO", "ROU", 642, EasternEurope;
+        Russia => "Russia", "RUS", "RS", "RU", "RUS", 643, EasternEurope;
+        Rwanda => "Rwanda", "RWA", "RW", "RW", "RWA", 646, EasternAfrica;
+        SaintHelena => "Saint Helena", "SHN", "SH", "SH", "SHN", 654, WesternAfrica;
+        #[serde(alias = "SaintKittsNevis")]
+        SaintKittsandNevis => "Saint Kitts and Nevis", "KNA", "SC", "KN", "KNA", 659, Caribbean;
+        SaintLucia => "Saint Lucia", "LCA", "ST", "LC", "LCA", 662, Caribbean;
+        SaintPierreandMiquelon => "Saint Pierre and Miquelon", "SPM", "SB", "PM", "SPM", 666, NorthernAmerica;
+        SaintVincentandtheGrenadines => "Saint Vincent and the Grenadines", "VCT", "VC", "VC", "VCT", 670, Caribbean;
+        Samoa => "Samoa", "WSM", "WS", "WS", "WSM", 882, Polynesia;
+        SanMarino => "San Marino", "SMR", "SM", "SM", "SMR", 674, SouthernEurope;
+        SaoTomeandPrincipe => "Sao Tome and Principe", "STP", "TP", "ST", "STP", 678, MiddleAfrica;
+        SaudiArabia => "Saudi Arabia", "SAU", "SA", "SA", "SAU", 682, WesternAsia;
+        Senegal => "Senegal", "SEN", "SG", "SN", "SEN", 686, WesternAfrica;
+        Serbia => "Serbia", "SRB", "RI", "RS", "SRB", 688, SouthernEurope;
+        Seychelles => "Seychelles", "SYC", "SE", "SC", "SYC", 690, EasternAfrica;
+        SierraLeone => "Sierra Leone", "SLE", "SL", "SL", "SLE", 694, WesternAfrica;
+        Singapore => "Singapore", "SGP", "SN", "SG", "SGP", 702, SouthEasternAsia;
+        #[serde(alias = "Czechoslovakia")]
+        Slovakia => "Slovakia", "SVK", "LO", "SK", "SVK", 703, EasternEurope;
+        Slovenia => "Slovenia", "SVN", "SI", "SI", "SVN", 705, SouthernEurope;
+        SolomonIslands => "Solomon Islands", "SLB", "BP", "SB", "SLB", 90, Melanesia;
+        Somalia => "Somalia", "SOM", "SO", "SO", "SOM", 706, EasternAfrica;
+        SouthAfrica => "South Africa", "ZAF", "SF", "ZA", "ZAF", 710, SouthernAfrica;
+        SouthSudan => "South Sudan", "SSD", "OD", "SS", "SSD", 728, EasternAfrica;
+        Spain => "Spain", "ESP", "SP", "ES", "ESP", 724, SouthernEurope;
+        SriLanka => "Sri Lanka", "LKA", "CE", "LK", "LKA", 144, SouthernAsia;
+        Sudan => "Sudan", "SDN", "SU", "SD", "SDN", 729, NorthernAfrica;
+        Suriname => "Suriname", "SUR", "NS", "SR", "SUR", 740, SouthAmerica;
+        SvalbardandJanMayenIslands => "Svalbard and Jan Mayen Islands", "SJM", -, "SJ", "SJM", 744, NorthernEurope;
+        Swaziland => "Swaziland", "SWZ", "WZ", "SZ", "SWZ", 748, SouthernAfrica;
+        Sweden => "Sweden", "SWE", "SW", "SE", "SWE", 752, NorthernEurope;
+        Switzerland => "Switzerland", "CHE", "SZ", "CH", "CHE", 756, WesternEurope;
+        Syria => "Syria", "SYR", "SY", "SY", "SYR", 760, WesternAsia;
+        Taiwan => "Taiwan", "TWN", "TW", "TW", "TWN", 158, EasternAsia;
+        Tajikistan => "Tajikistan", "TJK", "TI", "TJ", "TJK", 762, CentralAsia;
+        Tanzania => "Tanzania", "TZA", "TZ", "TZ", "TZA", 834, EasternAfrica;
+        Thailand => "Thailand", "THA", "TH", "TH", "THA", 764, SouthEasternAsia;
+        Togo => "Togo", "TGO", "TO", "TG", "TGO", 768, WesternAfrica;
+        Tokelau => "Tokelau", "TKL", "TL", "TK", "TKL", 772, Polynesia;
+        Tonga => "Tonga", "TON", "TN", "TO", "TON", 776, Polynesia;
+        TrinidadandTobago => "Trinidad and Tobago", "TTO", "TD", "TT", "TTO", 780, Caribbean;
+        Tunisia => "Tunisia", "TUN", "TS", "TN", "TUN", 788, NorthernAfrica;
+        Turkey => "Turkey", "TUR", "TU", "TR", "TUR", 792, WesternAsia;
+        Turkmenistan => "Turkmenistan", "TKM", "TX", "TM", "TKM", 795, CentralAsia;
+        TurksandCaicosIslands => "Turks and Caicos Islands", "TCA", "TK", "TC", "TCA", 796, Caribbean;
+        Tuvalu => "Tuvalu", "TUV", "TV", "TV", "TUV", 798, Polynesia;
+        Uganda => "Uganda", "UGA", "UG", "UG", "UGA", 800, EasternAfrica;
+        Ukraine => "Ukraine", "UKR", "UP", "UA", "UKR", 804, EasternEurope;
+        UnitedArabEmirates => "United Arab Emirates", "ARE", "AE", "AE", "ARE", 784, WesternAsia;
+        UnitedKingdom => "United Kingdom", "GBR", "UK", "GB", "GBR", 826, NorthernEurope;
+        UnitedStates => "United States", "USA", "US", "US", "USA", 840, NorthernAmerica;
+        #[serde(alias = "VirginIslands")]
+        UnitedStatesVirginIslands => "United States Virgin Islands", "VIR", "VQ", "VI", "VIR", 850, Caribbean;
+        Uruguay => "Uruguay", "URY", "UY", "UY", "URY", 858, SouthAmerica;
+        Uzbekistan => "Uzbekistan", "UZB", "UZ", "UZ", "UZB", 860, CentralAsia;
+        Vanuatu => "Vanuatu", "VUT", "NH", "VU", "VUT", 548, Melanesia;
+        Venezuela => "Venezuela", "VEN", "VE", "VE", "VEN", 862, SouthAmerica;
+        #[serde(alias = "VietnamDemocraticRepublicof")]
+        Vietnam => "Vietnam", "VNM", "VM", "VN", "VNM", 704, SouthEasternAsia;
+        #[serde(alias = "WallisandFutunaIslands")]
+        WallisandFutuna => "Wallis and Futuna", "WLF", "WF", "WF", "WLF", 876, Polynesia;
+        WesternSahara => "Western Sahara", "ESH", "WI", "EH", "ESH", 732, NorthernAfrica;
+        Yemen => "Yemen", "YEM", "YM", "YE", "YEM", 887, WesternAsia;
+        Zambia => "Zambia", "ZMB", "ZA", "ZM", "ZMB", 894, EasternAfrica;
+        Zimbabwe => "Zimbabwe", "ZWE", "ZI", "ZW", "ZWE", 716, EasternAfrica;
+        AkrotiriSovereignBaseArea => "Akrotiri Sovereign Base Area", -, "AX", -, -, -, WesternAsia;
+        Antarctica => "Antarctica", -, "AY", "AQ", "ATA", 10, -;
+        AshmoreandCartierIslands => "Ashmore and Cartier Islands", -, "AT", -, -, -, AustraliaNewZealand;
+        BakerIsland => "Baker Island", -, "FQ", -, -, -, Micronesia;
+        BassasdaIndia => "Bassas da India", -, "BS", -, -, -, EasternAfrica;
+        BouvetIsland => "Bouvet Island", -, "BV", "BV", "BVT", 74, SouthAmerica;
+        BritishIndianOceanTerritory => "British Indian Ocean Territory", -, "IO", "IO", "IOT", 86, EasternAfrica;
+        ChristmasIsland => "Christmas Island", -, "KT", "CX", "CXR", 162, AustraliaNewZealand;
+        ClippertonIsland => "Clipperton Island", -, "IP", -, -, -, -;
+        CocosKeelingIslands => "Cocos (Keeling) Islands", -, "CK", "CC", "CCK", 166, AustraliaNewZealand;
+        CoralSeaIslands => "Coral Sea Islands", -, "CR", -, -, -, AustraliaNewZealand;
+        DhekeliaSovereignBaseArea => "Dhekelia Sovereign Base Area", -, "DX", -, -, -, WesternAsia;
+        EtorofuHabomaiKunashiriandShikotanIslands => "Etorofu, Habomai, Kunashiri and Shikotan Islands", -, "PJ", -, -, -, EasternAsia;
+        EuropaIsland => "Europa Island", -, "EU", -, -, -, EasternAfrica;
+        FrenchSouthernandAntarcticLands => "French Southern and Antarctic Lands", -, "FS", "TF", "ATF", 260, EasternAfrica;
+        GloriosoIslands => "Glorioso Islands", -, "GO", -, -, -, EasternAfrica;
+        Guernsey => "Guernsey", -, "GK", "GG", "GGY", 831, NorthernEurope;
+        HeardIslandandMcDonaldIslands => "Heard Island and McDonald Islands", -, "HM", "HM", "HMD", 334, AustraliaNewZealand;
+        HowlandIsland => "Howland Island", -, "HQ", -, -, -, Micronesia;
+        JanMayen => "Jan Mayen", -, "JN", -, -, -, NorthernEurope;
+        JarvisIsland => "Jarvis Island", -, "DQ", -, -, -, Micronesia;
+        Jersey => "Jersey", -, "JE", "JE", "JEY", 832, NorthernEurope;
+        JohnstonAtoll => "Johnston Atoll", -, "JQ", -, -, -, Micronesia;
+        JuandeNovaIsland => "Juan de Nova Island", -, "JU", -, -, -, EasternAfrica;
+        KingmanReef => "Kingman Reef", -, "KQ", -, -, -, Micronesia;
+        Kosovo => "Kosovo", -, "KV", "XK", "XKX", -, SouthernEurope;
+        MidwayIslands => "Midway Islands", -, "MQ", -, -, -, Micronesia;
+        NavassaIsland => "Navassa Island", -, "BQ", -, -, -, Caribbean;
+        NoMansLand => "No Man's Land", -, "NM", -, -, -, -;
+        Oceans => "Oceans", -, "OS", -, -, -, -;
+        PalmyraAtoll => "Palmyra Atoll", -, "LQ", -, -, -, Micronesia;
+        ParacelIslands => "Paracel Islands", -, "PF", -, -, -, SouthEasternAsia;
+        SaintMartin => "Saint Martin", -, "RN", "MF", "MAF", 663, Caribbean;
+        SaintBarthelemyIsland => "Saint Barthelemy", -, "TB", "BL", "BLM", 652, Caribbean;
+        SouthGeorgiaandtheSouthSandwichIslands => "South Georgia and the South Sandwich Islands", -, "SX", "GS", "SGS", 239, SouthAmerica;
+        SpratlyIslands => "Spratly Islands", -, "PG", -, -, -, SouthEasternAsia;
+        Svalbard => "Svalbard", -, "SV", -, -, -, NorthernEurope;
+        TromelinIsland => "Tromelin Island", -, "TE", -, -, -, EasternAfrica;
+        UnderseaFeatures => "Undersea Features", -, "UF", -, -, -, -;
+        UndesignatedSovereignty => "Undesignated Sovereignty", -, "UU", -, -, -, -;
+        WakeIsland => "Wake Island", -, "WQ", -, -, -, Micronesia;
+    }
+}
+
+fn code_index(
+    select: fn(&CountryRecord) -> Option<&'static str>,
+) -> HashMap<&'static str, CountryZone> {
+    CountryZone::ALL
+        .iter()
+        .filter_map(|zone| select(zone.record()).map(|code| (code, *zone)))
+        .collect()
+}
+
+impl CountryZone {
+    fn record(&self) -> &'static CountryRecord {
+        &COUNTRY_RECORDS[*self as usize]
+    }
+
+    /// English short name
+    pub fn name(&self) -> &'static str {
+        self.record().name
+    }
+
+    pub fn cameo_code(&self) -> Option<&'static str> {
+        self.record().cameo
+    }
+
+    pub fn fips_code(&self) -> Option<&'static str> {
+        self.record().fips
+    }
+
+    pub fn iso_alpha2(&self) -> Option<&'static str> {
+        self.record().iso_alpha2
+    }
+
+    pub fn iso_alpha3(&self) -> Option<&'static str> {
+        self.record().iso_alpha3
+    }
+
+    pub fn iso_numeric(&self) -> Option<u16> {
+        self.record().iso_numeric
+    }
+
+    pub fn m49_region(&self) -> Option<M49Region> {
+        self.record().region
+    }
+
+    pub fn m49_subregion(&self) -> Option<M49SubRegion> {
+        self.record().subregion
+    }
+
+    /// True for CAMEO's regional zones (`Africa`, `MiddleEast`, `TheWest`, ...)
+    /// which don't correspond to a single country or territory
+    pub fn is_supranational(&self) -> bool {
+        self.record().supranational
+    }
+
+    pub fn to_cameo(&self) -> Option<CAMEOCountryCode> {
+        self.cameo_code()
+            .map(|code| CAMEOCountryCode(code.to_string()))
+    }
+
+    pub fn to_fips(&self) -> Option<FIPSCountryCode> {
+        self.fips_code()
+            .map(|code| FIPSCountryCode(code.to_string()))
+    }
+
+    pub fn from_cameo(code: &str) -> Option<Self> {
+        static INDEX: OnceLock<HashMap<&'static str, CountryZone>> = OnceLock::new();
+        INDEX
+            .get_or_init(|| code_index(|record| record.cameo))
+            .get(code.trim())
+            .copied()
+    }
+
+    pub fn from_fips(code: &str) -> Option<Self> {
+        static INDEX: OnceLock<HashMap<&'static str, CountryZone>> = OnceLock::new();
+        INDEX
+            .get_or_init(|| code_index(|record| record.fips))
+            .get(code.trim())
+            .copied()
+    }
+
+    pub fn from_iso_alpha2(code: &str) -> Option<Self> {
+        static INDEX: OnceLock<HashMap<&'static str, CountryZone>> = OnceLock::new();
+        INDEX
+            .get_or_init(|| code_index(|record| record.iso_alpha2))
+            .get(code.trim().to_ascii_uppercase().as_str())
+            .copied()
+    }
+
+    pub fn from_iso_alpha3(code: &str) -> Option<Self> {
+        static INDEX: OnceLock<HashMap<&'static str, CountryZone>> = OnceLock::new();
+        INDEX
+            .get_or_init(|| code_index(|record| record.iso_alpha3))
+            .get(code.trim().to_ascii_uppercase().as_str())
+            .copied()
+    }
+
+    pub fn from_iso_numeric(code: u16) -> Option<Self> {
+        CountryZone::ALL
+            .iter()
+            .find(|zone| zone.iso_numeric() == Some(code))
+            .copied()
+    }
+
+    /// Case-insensitive match on the English short name
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim();
+        CountryZone::ALL
+            .iter()
+            .find(|zone| zone.name().eq_ignore_ascii_case(name))
+            .copied()
+    }
+}
+
+// Variants renamed or merged when the crosswalk replaced the hand-written enum. The old
+// names still compile (with a warning) and still deserialize, via the aliases above.
+#[allow(non_upper_case_globals)]
+impl CountryZone {
+    #[deprecated(note = "renamed to `CountryZone::Colombia`")]
+    pub const Columbia: CountryZone = CountryZone::Colombia;
+    #[deprecated(note = "renamed to `CountryZone::FaroeIslands`")]
+    pub const FaeroeIslands: CountryZone = CountryZone::FaroeIslands;
+    #[deprecated(note = "renamed to `CountryZone::Reunion`")]
+    pub const Runion: CountryZone = CountryZone::Reunion;
+    #[deprecated(note = "renamed to `CountryZone::SaintKittsandNevis`")]
+    pub const SaintKittsNevis: CountryZone = CountryZone::SaintKittsandNevis;
+    #[deprecated(note = "renamed to `CountryZone::WallisandFutuna`")]
+    pub const WallisandFutunaIslands: CountryZone = CountryZone::WallisandFutuna;
+    #[deprecated(note = "renamed to `CountryZone::Bahamas`")]
+    pub const BahamasThe: CountryZone = CountryZone::Bahamas;
+    #[deprecated(note = "renamed to `CountryZone::BosniaandHerzegovina`")]
+    pub const BosniaHerzegovina: CountryZone = CountryZone::BosniaandHerzegovina;
+    #[deprecated(note = "renamed to `CountryZone::BruneiDarussalam`")]
+    pub const Brunei: CountryZone = CountryZone::BruneiDarussalam;
+    #[deprecated(note = "renamed to `CountryZone::PeoplesRepublicoftheCongo`")]
+    pub const Congo: CountryZone = CountryZone::PeoplesRepublicoftheCongo;
+    #[deprecated(note = "renamed to `CountryZone::IvoryCoast`")]
+    pub const CotedIvoire: CountryZone = CountryZone::IvoryCoast;
+    #[deprecated(note = "renamed to `CountryZone::Slovakia`")]
+    pub const Czechoslovakia: CountryZone = CountryZone::Slovakia;
+    #[deprecated(note = "renamed to `CountryZone::FalklandIslands`")]
+    pub const FalklandIslandsIslasMalvinas: CountryZone = CountryZone::FalklandIslands;
+    #[deprecated(note = "renamed to `CountryZone::Macao`")]
+    pub const Macau: CountryZone = CountryZone::Macao;
+    #[deprecated(note = "renamed to `CountryZone::Pitcairn`")]
+    pub const PitcairnIslands: CountryZone = CountryZone::Pitcairn;
+    #[deprecated(note = "renamed to `CountryZone::Vietnam`")]
+    pub const VietnamDemocraticRepublicof: CountryZone = CountryZone::Vietnam;
+    #[deprecated(note = "renamed to `CountryZone::UnitedStatesVirginIslands`")]
+    pub const VirginIslands: CountryZone = CountryZone::UnitedStatesVirginIslands;
+}
+
+impl fmt::Display for CountryZone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
 }
 
 impl TryFrom<Option<FIPSCountryCode>> for CountryZone {
     type Error = anyhow::Error;
 
     fn try_from(value: Option<FIPSCountryCode>) -> Result<Self, Self::Error> {
-        let value = value.ok_or_else(|| anyhow!("CAMEOCountryCode is None"))?;
-        match value.0.as_str() {
-            "AF" => Ok(CountryZone::Afghanistan),
-            "AX" => Ok(CountryZone::AkrotiriSovereignBaseArea),
-            "AL" => Ok(CountryZone::Albania),
-            "AG" => Ok(CountryZone::Algeria),
-            "AQ" => Ok(CountryZone::AmericanSamoa),
-            "AN" => Ok(CountryZone::Andorra),
-            "AO" => Ok(CountryZone::Angola),
-            "AV" => Ok(CountryZone::Anguilla),
-            "AY" => Ok(CountryZone::Antarctica),
-            "AC" => Ok(CountryZone::AntiguaandBarbuda),
-            "AR" => Ok(CountryZone::Argentina),
-            "AM" => Ok(CountryZone::Armenia),
-            "AA" => Ok(CountryZone::Aruba),
-            "AT" => Ok(CountryZone::AshmoreandCartierIslands),
-            "AS" => Ok(CountryZone::Australia),
-            "AU" => Ok(CountryZone::Austria),
-            "AJ" => Ok(CountryZone::Azerbaijan),
-            "BF" => Ok(CountryZone::BahamasThe),
-            "BA" => Ok(CountryZone::Bahrain),
-            "FQ" => Ok(CountryZone::BakerIsland),
-            "BG" => Ok(CountryZone::Bangladesh),
-            "BB" => Ok(CountryZone::Barbados),
-            "BS" => Ok(CountryZone::BassasdaIndia),
-            "BO" => Ok(CountryZone::Belarus),
-            "BE" => Ok(CountryZone::Belgium),
-            "BH" => Ok(CountryZone::Belize),
-            "BN" => Ok(CountryZone::Benin),
-            "BD" => Ok(CountryZone::Bermuda),
-            "BT" => Ok(CountryZone::Bhutan),
-            "BL" => Ok(CountryZone::Bolivia),
-            "BK" => Ok(CountryZone::BosniaHerzegovina),
-            "BC" => Ok(CountryZone::Botswana),
-            "BV" => Ok(CountryZone::BouvetIsland),
-            "BR" => Ok(CountryZone::Brazil),
-            "IO" => Ok(CountryZone::BritishIndianOceanTerritory),
-            "VI" => Ok(CountryZone::BritishVirginIslands),
-            "BX" => Ok(CountryZone::Brunei),
-            "BU" => Ok(CountryZone::Bulgaria),
-            "UV" => Ok(CountryZone::BurkinaFaso),
-            "BY" => Ok(CountryZone::Burundi),
-            "CB" => Ok(CountryZone::Cambodia),
-            "CM" => Ok(CountryZone::Cameroon),
-            "CA" => Ok(CountryZone::Canada),
-            "CV" => Ok(CountryZone::CapeVerde),
-            "CJ" => Ok(CountryZone::CaymanIslands),
-            "CT" => Ok(CountryZone::CentralAfricanRepublic),
-            "CD" => Ok(CountryZone::Chad),
-            "CI" => Ok(CountryZone::Chile),
-            "CH" => Ok(CountryZone::China),
-            "KT" => Ok(CountryZone::ChristmasIsland),
-            "IP" => Ok(CountryZone::ClippertonIsland),
-            "CK" => Ok(CountryZone::CocosKeelingIslands),
-            "CO" => Ok(CountryZone::Colombia),
-            "CN" => Ok(CountryZone::Comoros),
-            "CF" => Ok(CountryZone::Congo),
-            "CW" => Ok(CountryZone::CookIslands),
-            "CR" => Ok(CountryZone::CoralSeaIslands),
-            "CS" => Ok(CountryZone::CostaRica),
-            "IV" => Ok(CountryZone::CotedIvoire),
-            "HR" => Ok(CountryZone::Croatia),
-            "CU" => Ok(CountryZone::Cuba),
-            "CY" => Ok(CountryZone::Cyprus),
-            "EZ" => Ok(CountryZone::CzechRepublic),
-            "LO" => Ok(CountryZone::Czechoslovakia),
-            "CG" => Ok(CountryZone::DemocraticRepublicoftheCongo),
-            "DA" => Ok(CountryZone::Denmark),
-            "DX" => Ok(CountryZone::DhekeliaSovereignBaseArea),
-            "DJ" => Ok(CountryZone::Djibouti),
-            "DO" => Ok(CountryZone::Dominica),
-            "DR" => Ok(CountryZone::DominicanRepublic),
-            "TT" => Ok(CountryZone::EastTimor),
-            "EC" => Ok(CountryZone::Ecuador),
-            "EG" => Ok(CountryZone::Egypt),
-            "ES" => Ok(CountryZone::ElSalvador),
-            "GV" => Ok(CountryZone::EquatorialGuinea),
-            "EK" => Ok(CountryZone::EquatorialGuinea),
-            "ER" => Ok(CountryZone::Eritrea),
-            "EN" => Ok(CountryZone::Estonia),
-            "ET" => Ok(CountryZone::Ethiopia),
-            "PJ" => Ok(CountryZone::EtorofuHabomaiKunashiriandShikotanIslands),
-            "EU" => Ok(CountryZone::EuropaIsland),
-            "FK" => Ok(CountryZone::FalklandIslandsIslasMalvinas),
-            "FO" => Ok(CountryZone::FaroeIslands),
-            "FJ" => Ok(CountryZone::Fiji),
-            "FI" => Ok(CountryZone::Finland),
-            "FR" => Ok(CountryZone::France),
-            "FG" => Ok(CountryZone::FrenchGuiana),
-            "FP" => Ok(CountryZone::FrenchPolynesia),
-            "FS" => Ok(CountryZone::FrenchSouthernandAntarcticLands),
-            "GB" => Ok(CountryZone::Gabon),
-            "GA" => Ok(CountryZone::Gambia),
-            "GZ" => Ok(CountryZone::GazaStrip),
-            "GG" => Ok(CountryZone::Georgia),
-            "GM" => Ok(CountryZone::Germany),
-            "GH" => Ok(CountryZone::Ghana),
-            "GI" => Ok(CountryZone::Gibraltar),
-            "GO" => Ok(CountryZone::GloriosoIslands),
-            "GR" => Ok(CountryZone::Greece),
-            "GL" => Ok(CountryZone::Greenland),
-            "GJ" => Ok(CountryZone::Grenada),
-            "GP" => Ok(CountryZone::Guadeloupe),
-            "GQ" => Ok(CountryZone::Guam),
-            "GT" => Ok(CountryZone::Guatemala),
-            "GK" => Ok(CountryZone::Guernsey),
-            "PU" => Ok(CountryZone::GuineaBissau),
-            "GY" => Ok(CountryZone::Guyana),
-            "HA" => Ok(CountryZone::Haiti),
-            "HM" => Ok(CountryZone::HeardIslandandMcDonaldIslands),
-            "HO" => Ok(CountryZone::Honduras),
-            "HK" => Ok(CountryZone::HongKong),
-            "HQ" => Ok(CountryZone::HowlandIsland),
-            "HU" => Ok(CountryZone::Hungary),
-            "IC" => Ok(CountryZone::Iceland),
-            "IN" => Ok(CountryZone::India),
-            "ID" => Ok(CountryZone::Indonesia),
-            "IR" => Ok(CountryZone::Iran),
-            "IZ" => Ok(CountryZone::Iraq),
-            "EI" => Ok(CountryZone::Ireland),
-            "IM" => Ok(CountryZone::IsleofMan),
-            "IS" => Ok(CountryZone::Israel),
-            "IT" => Ok(CountryZone::Italy),
-            "JM" => Ok(CountryZone::Jamaica),
-            "JN" => Ok(CountryZone::JanMayen),
-            "JA" => Ok(CountryZone::Japan),
-            "DQ" => Ok(CountryZone::JarvisIsland),
-            "JE" => Ok(CountryZone::Jersey),
-            "JQ" => Ok(CountryZone::JohnstonAtoll),
-            "JO" => Ok(CountryZone::Jordan),
-            "JU" => Ok(CountryZone::JuandeNovaIsland),
-            "KZ" => Ok(CountryZone::Kazakhstan),
-            "KE" => Ok(CountryZone::Kenya),
-            "KQ" => Ok(CountryZone::KingmanReef),
-            "KR" => Ok(CountryZone::Kiribati),
-            "KV" => Ok(CountryZone::Kosovo),
-            "KU" => Ok(CountryZone::Kuwait),
-            "KG" => Ok(CountryZone::Kyrgyzstan),
-            "LA" => Ok(CountryZone::Laos),
-            "LG" => Ok(CountryZone::Latvia),
-            "LE" => Ok(CountryZone::Lebanon),
-            "LT" => Ok(CountryZone::Lesotho),
-            "LI" => Ok(CountryZone::Liberia),
-            "LY" => Ok(CountryZone::Libya),
-            "LS" => Ok(CountryZone::Liechtenstein),
-            "LH" => Ok(CountryZone::Lithuania),
-            "LU" => Ok(CountryZone::Luxembourg),
-            "MC" => Ok(CountryZone::Macau),
-            "MK" => Ok(CountryZone::Macedonia),
-            "MA" => Ok(CountryZone::Madagascar),
-            "MI" => Ok(CountryZone::Malawi),
-            "MY" => Ok(CountryZone::Malaysia),
-            "MV" => Ok(CountryZone::Maldives),
-            "ML" => Ok(CountryZone::Mali),
-            "MT" => Ok(CountryZone::Malta),
-            "RM" => Ok(CountryZone::MarshallIslands),
-            "MB" => Ok(CountryZone::Martinique),
-            "MR" => Ok(CountryZone::Mauritania),
-            "MP" => Ok(CountryZone::Mauritius),
-            "MF" => Ok(CountryZone::Mayotte),
-            "MX" => Ok(CountryZone::Mexico),
-            "FM" => Ok(CountryZone::Micronesia),
-            "MQ" => Ok(CountryZone::MidwayIslands),
-            "MD" => Ok(CountryZone::Moldova),
-            "MN" => Ok(CountryZone::Monaco),
-            "MG" => Ok(CountryZone::Mongolia),
-            "MJ" => Ok(CountryZone::Montenegro),
-            "MH" => Ok(CountryZone::Montserrat),
-            "MO" => Ok(CountryZone::Morocco),
-            "MZ" => Ok(CountryZone::Mozambique),
-            "BM" => Ok(CountryZone::Myanmar),
-            "WA" => Ok(CountryZone::Namibia),
-            "NR" => Ok(CountryZone::Nauru),
-            "BQ" => Ok(CountryZone::NavassaIsland),
-            "NP" => Ok(CountryZone::Nepal),
-            "NL" => Ok(CountryZone::Netherlands),
-            "NT" => Ok(CountryZone::NetherlandsAntilles),
-            "NC" => Ok(CountryZone::NewCaledonia),
-            "NZ" => Ok(CountryZone::NewZealand),
-            "NU" => Ok(CountryZone::Nicaragua),
-            "NG" => Ok(CountryZone::Niger),
-            "NI" => Ok(CountryZone::Nigeria),
-            "NE" => Ok(CountryZone::Niue),
-            "NM" => Ok(CountryZone::NoMansLand),
-            "NF" => Ok(CountryZone::NorfolkIsland),
-            "KN" => Ok(CountryZone::NorthKorea),
-            "CQ" => Ok(CountryZone::NorthernMarianaIslands),
-            "NO" => Ok(CountryZone::Norway),
-            "OS" => Ok(CountryZone::Oceans),
-            "MU" => Ok(CountryZone::Oman),
-            "PK" => Ok(CountryZone::Pakistan),
-            "PS" => Ok(CountryZone::Palau),
-            "LQ" => Ok(CountryZone::PalmyraAtoll),
-            "PM" => Ok(CountryZone::Panama),
-            "PP" => Ok(CountryZone::PapuaNewGuinea),
-            "PF" => Ok(CountryZone::ParacelIslands),
-            "PA" => Ok(CountryZone::Paraguay),
-            "PE" => Ok(CountryZone::Peru),
-            "RP" => Ok(CountryZone::Philippines),
-            "PC" => Ok(CountryZone::PitcairnIslands),
-            "PL" => Ok(CountryZone::Poland),
-            "PO" => Ok(CountryZone::Portugal),
-            "RQ" => Ok(CountryZone::PuertoRico),
-            "QA" => Ok(CountryZone::Qatar),
-            "RE" => Ok(CountryZone::Reunion),
-            "RO" => Ok(CountryZone::Romania),
-            "RS" => Ok(CountryZone::Russia),
-            "RW" => Ok(CountryZone::Rwanda),
-            "SH" => Ok(CountryZone::SaintHelena),
-            "SC" => Ok(CountryZone::SaintKittsandNevis),
-            "ST" => Ok(CountryZone::SaintLucia),
-            "RN" => Ok(CountryZone::SaintMartin),
-            "SB" => Ok(CountryZone::SaintPierreandMiquelon),
-            "VC" => Ok(CountryZone::SaintVincentandtheGrenadines),
-            "TB" => Ok(CountryZone::SaintBarthelemyIsland),
-            "WS" => Ok(CountryZone::Samoa),
-            "SM" => Ok(CountryZone::SanMarino),
-            "TP" => Ok(CountryZone::SaoTomeandPrincipe),
-            "SA" => Ok(CountryZone::SaudiArabia),
-            "SG" => Ok(CountryZone::Senegal),
-            "RI" => Ok(CountryZone::Serbia),
-            "SE" => Ok(CountryZone::Seychelles),
-            "SL" => Ok(CountryZone::SierraLeone),
-            "SN" => Ok(CountryZone::Singapore),
-            "SI" => Ok(CountryZone::Slovenia),
-            "BP" => Ok(CountryZone::SolomonIslands),
-            "SO" => Ok(CountryZone::Somalia),
-            "SF" => Ok(CountryZone::SouthAfrica),
-            "SX" => Ok(CountryZone::SouthGeorgiaandtheSouthSandwichIslands),
-            "KS" => Ok(CountryZone::SouthKorea),
-            "OD" => Ok(CountryZone::SouthSudan),
-            "SP" => Ok(CountryZone::Spain),
-            "PG" => Ok(CountryZone::SpratlyIslands),
-            "CE" => Ok(CountryZone::SriLanka),
-            "SU" => Ok(CountryZone::Sudan),
-            "NS" => Ok(CountryZone::Suriname),
-            "SV" => Ok(CountryZone::Svalbard),
-            "WZ" => Ok(CountryZone::Swaziland),
-            "SW" => Ok(CountryZone::Sweden),
-            "SZ" => Ok(CountryZone::Switzerland),
-            "SY" => Ok(CountryZone::Syria),
-            "TW" => Ok(CountryZone::Taiwan),
-            "TI" => Ok(CountryZone::Tajikistan),
-            "TZ" => Ok(CountryZone::Tanzania),
-            "TH" => Ok(CountryZone::Thailand),
-            "TO" => Ok(CountryZone::Togo),
-            "TL" => Ok(CountryZone::Tokelau),
-            "TN" => Ok(CountryZone::Tonga),
-            "TD" => Ok(CountryZone::TrinidadandTobago),
-            "TE" => Ok(CountryZone::TromelinIsland),
-            "TS" => Ok(CountryZone::Tunisia),
-            "TU" => Ok(CountryZone::Turkey),
-            "TX" => Ok(CountryZone::Turkmenistan),
-            "TK" => Ok(CountryZone::TurksandCaicosIslands),
-            "TV" => Ok(CountryZone::Tuvalu),
-            "UG" => Ok(CountryZone::Uganda),
-            "UP" => Ok(CountryZone::Ukraine),
-            "UF" => Ok(CountryZone::UnderseaFeatures),
-            "UU" => Ok(CountryZone::UndesignatedSovereignty),
-            "AE" => Ok(CountryZone::UnitedArabEmirates),
-            "UK" => Ok(CountryZone::UnitedKingdom),
-            "US" => Ok(CountryZone::UnitedStates),
-            "UY" => Ok(CountryZone::Uruguay),
-            "UZ" => Ok(CountryZone::Uzbekistan),
-            "NH" => Ok(CountryZone::Vanuatu),
-            "VT" => Ok(CountryZone::VaticanCity),
-            "VE" => Ok(CountryZone::Venezuela),
-            "VM" => Ok(CountryZone::VietnamDemocraticRepublicof),
-            "VQ" => Ok(CountryZone::VirginIslands),
-            "WQ" => Ok(CountryZone::WakeIsland),
-            "WF" => Ok(CountryZone::WallisandFutuna),
-            "WE" => Ok(CountryZone::WestBank),
-            "WI" => Ok(CountryZone::WesternSahara),
-            "YM" => Ok(CountryZone::Yemen),
-            "ZA" => Ok(CountryZone::Zambia),
-            "ZI" => Ok(CountryZone::Zimbabwe),
-            _ => Err(anyhow!("Invalid FIPS Code")),
-        }
+        let value = value.ok_or_else(|| anyhow!("FIPSCountryCode is None"))?;
+        CountryZone::from_fips(&value.0).ok_or_else(|| anyhow!("Invalid FIPS Code"))
     }
 }
 
@@ -618,270 +757,7 @@ impl TryFrom<Option<CAMEOCountryCode>> for CountryZone {
 
     fn try_from(value: Option<CAMEOCountryCode>) -> Result<Self, Self::Error> {
         let value = value.ok_or_else(|| anyhow!("CAMEOCountryCode is None"))?;
-        match value.0.as_str() {
-            "WSB" => Ok(CountryZone::WestBank),
-            "BAG" => Ok(CountryZone::Baghdad),
-            "GZS" => Ok(CountryZone::GazaStrip),
-            "AFR" => Ok(CountryZone::Africa),
-            "ASA" => Ok(CountryZone::Asia),
-            "BLK" => Ok(CountryZone::Balkans),
-            "CRB" => Ok(CountryZone::Caribbean),
-            "CAU" => Ok(CountryZone::Caucasus),
-            "CFR" => Ok(CountryZone::CentralAfrica),
-            "CAS" => Ok(CountryZone::CentralAsia),
-            "CEU" => Ok(CountryZone::CentralEurope),
-            "EIN" => Ok(CountryZone::EastIndies),
-            "EAF" => Ok(CountryZone::EasternAfrica),
-            "EEU" => Ok(CountryZone::EasternEurope),
-            "EUR" => Ok(CountryZone::Europe),
-            "LAM" => Ok(CountryZone::LatinAmerica),
-            "MEA" => Ok(CountryZone::MiddleEast),
-            "MDT" => Ok(CountryZone::Mediterranean),
-            "NAF" => Ok(CountryZone::NorthAfrica),
-            "NMR" => Ok(CountryZone::NorthAmerica),
-            "PGS" => Ok(CountryZone::PersianGulf),
-            "SCN" => Ok(CountryZone::Scandinavia),
-            "SAM" => Ok(CountryZone::SouthAmerica),
-            "SAS" => Ok(CountryZone::SouthAsia),
-            "SEA" => Ok(CountryZone::SoutheastAsia),
-            "SAF" => Ok(CountryZone::SouthernAfrica),
-            "WAF" => Ok(CountryZone::WestAfrica),
-            "WST" => Ok(CountryZone::TheWest),
-            "AFG" => Ok(CountryZone::Afghanistan),
-            "ALA" => Ok(CountryZone::AlandIslands),
-            "ALB" => Ok(CountryZone::Albania),
-            "DZA" => Ok(CountryZone::Algeria),
-            "ASM" => Ok(CountryZone::AmericanSamoa),
-            "AND" => Ok(CountryZone::Andorra),
-            "AGO" => Ok(CountryZone::Angola),
-            "AIA" => Ok(CountryZone::Anguilla),
-            "ATG" => Ok(CountryZone::AntiguaandBarbuda),
-            "ARG" => Ok(CountryZone::Argentina),
-            "ARM" => Ok(CountryZone::Armenia),
-            "ABW" => Ok(CountryZone::Aruba),
-            "AUS" => Ok(CountryZone::Australia),
-            "AUT" => Ok(CountryZone::Austria),
-            "AZE" => Ok(CountryZone::Azerbaijan),
-            "BHS" => Ok(CountryZone::Bahamas),
-            "BHR" => Ok(CountryZone::Bahrain),
-            "BGD" => Ok(CountryZone::Bangladesh),
-            "BRB" => Ok(CountryZone::Barbados),
-            "BLR" => Ok(CountryZone::Belarus),
-            "BEL" => Ok(CountryZone::Belgium),
-            "BLZ" => Ok(CountryZone::Belize),
-            "BEN" => Ok(CountryZone::Benin),
-            "BMU" => Ok(CountryZone::Bermuda),
-            "BTN" => Ok(CountryZone::Bhutan),
-            "BOL" => Ok(CountryZone::Bolivia),
-            "BIH" => Ok(CountryZone::BosniaandHerzegovina),
-            "BWA" => Ok(CountryZone::Botswana),
-            "BRA" => Ok(CountryZone::Brazil),
-            "VGB" => Ok(CountryZone::BritishVirginIslands),
-            "BRN" => Ok(CountryZone::BruneiDarussalam),
-            "BGR" => Ok(CountryZone::Bulgaria),
-            "BFA" => Ok(CountryZone::BurkinaFaso),
-            "BDI" => Ok(CountryZone::Burundi),
-            "KHM" => Ok(CountryZone::Cambodia),
-            "CMR" => Ok(CountryZone::Cameroon),
-            "CAN" => Ok(CountryZone::Canada),
-            "CPV" => Ok(CountryZone::CapeVerde),
-            "CYM" => Ok(CountryZone::CaymanIslands),
-            "CAF" => Ok(CountryZone::CentralAfricanRepublic),
-            "TCD" => Ok(CountryZone::Chad),
-            "CHL" => Ok(CountryZone::Chile),
-            "CHN" => Ok(CountryZone::China),
-            "COL" => Ok(CountryZone::Columbia),
-            "COM" => Ok(CountryZone::Comoros),
-            "COD" => Ok(CountryZone::DemocraticRepublicoftheCongo),
-            "COG" => Ok(CountryZone::PeoplesRepublicoftheCongo),
-            "COK" => Ok(CountryZone::CookIslands),
-            "CRI" => Ok(CountryZone::CostaRica),
-            "CIV" => Ok(CountryZone::IvoryCoast),
-            "HRV" => Ok(CountryZone::Croatia),
-            "CUB" => Ok(CountryZone::Cuba),
-            "CYP" => Ok(CountryZone::Cyprus),
-            "CZE" => Ok(CountryZone::CzechRepublic),
-            "DNK" => Ok(CountryZone::Denmark),
-            "DJI" => Ok(CountryZone::Djibouti),
-            "DMA" => Ok(CountryZone::Dominica),
-            "DOM" => Ok(CountryZone::DominicanRepublic),
-            "TMP" => Ok(CountryZone::EastTimor),
-            "ECU" => Ok(CountryZone::Ecuador),
-            "EGY" => Ok(CountryZone::Egypt),
-            "SLV" => Ok(CountryZone::ElSalvador),
-            "GNQ" => Ok(CountryZone::EquatorialGuinea),
-            "ERI" => Ok(CountryZone::Eritrea),
-            "EST" => Ok(CountryZone::Estonia),
-            "ETH" => Ok(CountryZone::Ethiopia),
-            "FRO" => Ok(CountryZone::FaeroeIslands),
-            "FLK" => Ok(CountryZone::FalklandIslands),
-            "FJI" => Ok(CountryZone::Fiji),
-            "FIN" => Ok(CountryZone::Finland),
-            "FRA" => Ok(CountryZone::France),
-            "GUF" => Ok(CountryZone::FrenchGuiana),
-            "PYF" => Ok(CountryZone::FrenchPolynesia),
-            "GAB" => Ok(CountryZone::Gabon),
-            "GMB" => Ok(CountryZone::Gambia),
-            "GEO" => Ok(CountryZone::Georgia),
-            "DEU" => Ok(CountryZone::Germany),
-            "GHA" => Ok(CountryZone::Ghana),
-            "GIB" => Ok(CountryZone::Gibraltar),
-            "GRC" => Ok(CountryZone::Greece),
-            "GRL" => Ok(CountryZone::Greenland),
-            "GRD" => Ok(CountryZone::Grenada),
-            "GLP" => Ok(CountryZone::Guadeloupe),
-            "GUM" => Ok(CountryZone::Guam),
-            "GTM" => Ok(CountryZone::Guatemala),
-            "GIN" => Ok(CountryZone::Guinea),
-            "GNB" => Ok(CountryZone::GuineaBissau),
-            "GUY" => Ok(CountryZone::Guyana),
-            "HTI" => Ok(CountryZone::Haiti),
-            "VAT" => Ok(CountryZone::VaticanCity),
-            "HND" => Ok(CountryZone::Honduras),
-            "HKG" => Ok(CountryZone::HongKong),
-            "HUN" => Ok(CountryZone::Hungary),
-            "ISL" => Ok(CountryZone::Iceland),
-            "IND" => Ok(CountryZone::India),
-            "IDN" => Ok(CountryZone::Indonesia),
-            "IRN" => Ok(CountryZone::Iran),
-            "IRQ" => Ok(CountryZone::Iraq),
-            "IRL" => Ok(CountryZone::Ireland),
-            "IMY" => Ok(CountryZone::IsleofMan),
-            "ISR" => Ok(CountryZone::Israel),
-            "ITA" => Ok(CountryZone::Italy),
-            "JAM" => Ok(CountryZone::Jamaica),
-            "JPN" => Ok(CountryZone::Japan),
-            "JOR" => Ok(CountryZone::Jordan),
-            "KAZ" => Ok(CountryZone::Kazakhstan),
-            "KEN" => Ok(CountryZone::Kenya),
-            "KIR" => Ok(CountryZone::Kiribati),
-            "PRK" => Ok(CountryZone::NorthKorea),
-            "KOR" => Ok(CountryZone::SouthKorea),
-            "KWT" => Ok(CountryZone::Kuwait),
-            "KGZ" => Ok(CountryZone::Kyrgyzstan),
-            "LAO" => Ok(CountryZone::Laos),
-            "LVA" => Ok(CountryZone::Latvia),
-            "LBN" => Ok(CountryZone::Lebanon),
-            "LSO" => Ok(CountryZone::Lesotho),
-            "LBR" => Ok(CountryZone::Liberia),
-            "LBY" => Ok(CountryZone::Libya),
-            "LIE" => Ok(CountryZone::Liechtenstein),
-            "LTU" => Ok(CountryZone::Lithuania),
-            "LUX" => Ok(CountryZone::Luxembourg),
-            "MAC" => Ok(CountryZone::Macao),
-            "MKD" => Ok(CountryZone::Macedonia),
-            "MDG" => Ok(CountryZone::Madagascar),
-            "MWI" => Ok(CountryZone::Malawi),
-            "MYS" => Ok(CountryZone::Malaysia),
-            "MDV" => Ok(CountryZone::Maldives),
-            "MLI" => Ok(CountryZone::Mali),
-            "MLT" => Ok(CountryZone::Malta),
-            "MHL" => Ok(CountryZone::MarshallIslands),
-            "MTQ" => Ok(CountryZone::Martinique),
-            "MRT" => Ok(CountryZone::Mauritania),
-            "MUS" => Ok(CountryZone::Mauritius),
-            "MYT" => Ok(CountryZone::Mayotte),
-            "MEX" => Ok(CountryZone::Mexico),
-            "FSM" => Ok(CountryZone::Micronesia),
-            "MDA" => Ok(CountryZone::Moldova),
-            "MCO" => Ok(CountryZone::Monaco),
-            "MNG" => Ok(CountryZone::Mongolia),
-            "MTN" => Ok(CountryZone::Montenegro),
-            "MSR" => Ok(CountryZone::Montserrat),
-            "MAR" => Ok(CountryZone::Morocco),
-            "MOZ" => Ok(CountryZone::Mozambique),
-            "MMR" => Ok(CountryZone::Myanmar),
-            "NAM" => Ok(CountryZone::Namibia),
-            "NRU" => Ok(CountryZone::Nauru),
-            "NPL" => Ok(CountryZone::Nepal),
-            "NLD" => Ok(CountryZone::Netherlands),
-            "ANT" => Ok(CountryZone::NetherlandsAntilles),
-            "NCL" => Ok(CountryZone::NewCaledonia),
-            "NZL" => Ok(CountryZone::NewZealand),
-            "NIC" => Ok(CountryZone::Nicaragua),
-            "NER" => Ok(CountryZone::Niger),
-            "NGA" => Ok(CountryZone::Nigeria),
-            "NIU" => Ok(CountryZone::Niue),
-            "NFK" => Ok(CountryZone::NorfolkIsland),
-            "MNP" => Ok(CountryZone::NorthernMarianaIslands),
-            "NOR" => Ok(CountryZone::Norway),
-            "PSE" => Ok(CountryZone::OccupiedPalestinianTerritory),
-            "OMN" => Ok(CountryZone::Oman),
-            "PAK" => Ok(CountryZone::Pakistan),
-            "PLW" => Ok(CountryZone::Palau),
-            "PAN" => Ok(CountryZone::Panama),
-            "PNG" => Ok(CountryZone::PapuaNewGuinea),
-            "PRY" => Ok(CountryZone::Paraguay),
-            "PER" => Ok(CountryZone::Peru),
-            "PHL" => Ok(CountryZone::Philippines),
-            "PCN" => Ok(CountryZone::Pitcairn),
-            "POL" => Ok(CountryZone::Poland),
-            "PRT" => Ok(CountryZone::Portugal),
-            "PRI" => Ok(CountryZone::PuertoRico),
-            "QAT" => Ok(CountryZone::Qatar),
-            "REU" => Ok(CountryZone::Runion),
-            "ROM" => Ok(CountryZone::Romania),
-            "RUS" => Ok(CountryZone::Russia),
-            "RWA" => Ok(CountryZone::Rwanda),
-            "SHN" => Ok(CountryZone::SaintHelena),
-            "KNA" => Ok(CountryZone::SaintKittsNevis),
-            "LCA" => Ok(CountryZone::SaintLucia),
-            "SPM" => Ok(CountryZone::SaintPierreandMiquelon),
-            "VCT" => Ok(CountryZone::SaintVincentandtheGrenadines),
-            "WSM" => Ok(CountryZone::Samoa),
-            "SMR" => Ok(CountryZone::SanMarino),
-            "STP" => Ok(CountryZone::SaoTomeandPrincipe),
-            "SAU" => Ok(CountryZone::SaudiArabia),
-            "SEN" => Ok(CountryZone::Senegal),
-            "SRB" => Ok(CountryZone::Serbia),
-            "SYC" => Ok(CountryZone::Seychelles),
-            "SLE" => Ok(CountryZone::SierraLeone),
-            "SGP" => Ok(CountryZone::Singapore),
-            "SVK" => Ok(CountryZone::Slovakia),
-            "SVN" => Ok(CountryZone::Slovenia),
-            "SLB" => Ok(CountryZone::SolomonIslands),
-            "SOM" => Ok(CountryZone::Somalia),
-            "ZAF" => Ok(CountryZone::SouthAfrica),
-            "ESP" => Ok(CountryZone::Spain),
-            "LKA" => Ok(CountryZone::SriLanka),
-            "SDN" => Ok(CountryZone::Sudan),
-            "SUR" => Ok(CountryZone::Suriname),
-            "SJM" => Ok(CountryZone::SvalbardandJanMayenIslands),
-            "SWZ" => Ok(CountryZone::Swaziland),
-            "SWE" => Ok(CountryZone::Sweden),
-            "CHE" => Ok(CountryZone::Switzerland),
-            "SYR" => Ok(CountryZone::Syria),
-            "TWN" => Ok(CountryZone::Taiwan),
-            "TJK" => Ok(CountryZone::Tajikistan),
-            "TZA" => Ok(CountryZone::Tanzania),
-            "THA" => Ok(CountryZone::Thailand),
-            "TGO" => Ok(CountryZone::Togo),
-            "TKL" => Ok(CountryZone::Tokelau),
-            "TON" => Ok(CountryZone::Tonga),
-            "TTO" => Ok(CountryZone::TrinidadandTobago),
-            "TUN" => Ok(CountryZone::Tunisia),
-            "TUR" => Ok(CountryZone::Turkey),
-            "TKM" => Ok(CountryZone::Turkmenistan),
-            "TCA" => Ok(CountryZone::TurksandCaicosIslands),
-            "TUV" => Ok(CountryZone::Tuvalu),
-            "UGA" => Ok(CountryZone::Uganda),
-            "UKR" => Ok(CountryZone::Ukraine),
-            "ARE" => Ok(CountryZone::UnitedArabEmirates),
-            "GBR" => Ok(CountryZone::UnitedKingdom),
-            "USA" => Ok(CountryZone::UnitedStates),
-            "VIR" => Ok(CountryZone::UnitedStatesVirginIslands),
-            "URY" => Ok(CountryZone::Uruguay),
-            "UZB" => Ok(CountryZone::Uzbekistan),
-            "VUT" => Ok(CountryZone::Vanuatu),
-            "VEN" => Ok(CountryZone::Venezuela),
-            "VNM" => Ok(CountryZone::Vietnam),
-            "WLF" => Ok(CountryZone::WallisandFutunaIslands),
-            "ESH" => Ok(CountryZone::WesternSahara),
-            "YEM" => Ok(CountryZone::Yemen),
-            "ZMB" => Ok(CountryZone::Zambia),
-            "ZWE" => Ok(CountryZone::Zimbabwe),
-            _ => Err(anyhow!("Invalid CAMEO Country Code")),
-        }
+        CountryZone::from_cameo(&value.0).ok_or_else(|| anyhow!("Invalid CAMEO Country Code"))
     }
 }
 
@@ -891,8 +767,10 @@ mod tests {
     use log::info;
     use std::sync::Once;
 
+    #[allow(dead_code)]
     static INIT: Once = Once::new();
 
+    #[allow(dead_code)]
     fn init_logger() {
         INIT.call_once(|| {
             env_logger::init();
@@ -952,4 +830,124 @@ mod tests {
         let long_result = FIPSCountryCode::try_from(Some(long_code));
         assert!(long_result.is_err());
     }
+
+    #[test]
+    fn test_crosswalk_round_trips_every_zone() {
+        // init_logger();
+
+        for zone in CountryZone::ALL {
+            info!("Checking crosswalk for {:?}", zone);
+            if let Some(cameo) = zone.to_cameo() {
+                assert_eq!(CountryZone::try_from(Some(cameo)).ok(), Some(*zone));
+            }
+            if let Some(fips) = zone.to_fips() {
+                assert_eq!(CountryZone::try_from(Some(fips)).ok(), Some(*zone));
+            }
+            if let Some(iso2) = zone.iso_alpha2() {
+                assert_eq!(iso2.len(), 2);
+                assert_eq!(CountryZone::from_iso_alpha2(iso2), Some(*zone));
+                assert_eq!(
+                    CountryZone::from_iso_alpha2(&iso2.to_lowercase()),
+                    Some(*zone)
+                );
+            }
+            if let Some(iso3) = zone.iso_alpha3() {
+                assert_eq!(iso3.len(), 3);
+                assert_eq!(CountryZone::from_iso_alpha3(iso3), Some(*zone));
+            }
+            if let Some(numeric) = zone.iso_numeric() {
+                assert!(numeric < 1000);
+                assert_eq!(CountryZone::from_iso_numeric(numeric), Some(*zone));
+            }
+            assert_eq!(CountryZone::from_name(zone.name()), Some(*zone));
+            assert_eq!(zone.to_string(), zone.name());
+        }
+    }
+
+    #[test]
+    fn test_crosswalk_codes_are_consistent() {
+        // init_logger();
+
+        for zone in CountryZone::ALL {
+            if let Some(cameo) = zone.cameo_code() {
+                assert_eq!(cameo.len(), 3, "{:?}", zone);
+            }
+            if let Some(fips) = zone.fips_code() {
+                assert_eq!(fips.len(), 2, "{:?}", zone);
+            }
+            // ISO codes come as a set
+            assert_eq!(zone.iso_alpha2().is_some(), zone.iso_alpha3().is_some());
+            if let (Some(subregion), Some(region)) = (zone.m49_subregion(), zone.m49_region()) {
+                assert_eq!(subregion.region(), region, "{:?}", zone);
+            }
+            if zone.m49_subregion().is_some() {
+                assert!(zone.m49_region().is_some(), "{:?}", zone);
+            }
+            if zone.is_supranational() {
+                assert!(zone.cameo_code().is_some(), "{:?}", zone);
+                assert!(zone.fips_code().is_none(), "{:?}", zone);
+                assert!(zone.iso_alpha2().is_none(), "{:?}", zone);
+            }
+        }
+    }
+
+    #[test]
+    fn test_crosswalk_known_values() {
+        // init_logger();
+
+        let kenya = CountryZone::from_cameo("KEN").unwrap();
+        assert_eq!(kenya, CountryZone::Kenya);
+        assert_eq!(kenya.fips_code(), Some("KE"));
+        assert_eq!(kenya.iso_alpha2(), Some("KE"));
+        assert_eq!(kenya.iso_numeric(), Some(404));
+        assert_eq!(kenya.m49_subregion(), Some(M49SubRegion::EasternAfrica));
+        assert_eq!(kenya.m49_region(), Some(M49Region::Africa));
+
+        // FIPS and ISO disagree for a lot of African states
+        let south_africa = CountryZone::from_fips("SF").unwrap();
+        assert_eq!(south_africa.iso_alpha2(), Some("ZA"));
+        assert_eq!(south_africa.cameo_code(), Some("ZAF"));
+        assert_eq!(CountryZone::from_fips("NI"), Some(CountryZone::Nigeria));
+        assert_eq!(
+            CountryZone::from_iso_alpha2("NI"),
+            Some(CountryZone::Nicaragua)
+        );
+
+        assert_eq!(CountryZone::from_fips("GV"), Some(CountryZone::Guinea));
+        assert_eq!(
+            CountryZone::from_cameo("SSD"),
+            Some(CountryZone::SouthSudan)
+        );
+        assert_eq!(CountryZone::from_cameo("ROM"), Some(CountryZone::Romania));
+        assert_eq!(CountryZone::Romania.iso_alpha3(), Some("ROU"));
+
+        assert!(CountryZone::Africa.is_supranational());
+        assert!(!CountryZone::Ghana.is_supranational());
+        assert_eq!(CountryZone::from_cameo("AFR"), Some(CountryZone::Africa));
+        assert!(CountryZone::Africa.to_fips().is_none());
+        assert_eq!(
+            CountryZone::from_name("united states"),
+            Some(CountryZone::UnitedStates)
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_renamed_variants_still_resolve() {
+        // init_logger();
+
+        assert_eq!(CountryZone::CotedIvoire, CountryZone::IvoryCoast);
+        assert!(matches!(
+            CountryZone::from_fips("IV"),
+            Some(CountryZone::CotedIvoire)
+        ));
+
+        let zone: CountryZone = serde_json::from_str("\"Congo\"").unwrap();
+        assert_eq!(zone, CountryZone::PeoplesRepublicoftheCongo);
+        assert_eq!(
+            serde_json::to_string(&CountryZone::Columbia).unwrap(),
+            "\"Colombia\""
+        );
+        info!("Old variant names map onto {:?}", zone);
+    }
 }
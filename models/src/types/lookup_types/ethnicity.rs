@@ -1296,6 +1296,7 @@ mod tests {
     use super::*;
     use log::info;
 
+    #[allow(dead_code)]
     fn init_logger() {
         static INIT: std::sync::Once = std::sync::Once::new();
         INIT.call_once(|| {
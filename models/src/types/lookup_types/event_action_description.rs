@@ -1,5 +1,7 @@
 use crate::types::event_table::event_action::CAMEOEventCode;
 use anyhow::anyhow;
+use std::fmt;
+use std::sync::OnceLock;
 use subcategories::{
     AdministrativeSanctions, Aid, ArialWeapons, Assault, Bombing, Change, Coercion, Consultation,
     Cooperation, DiplomaticCooperation, Disapproval, Fight, ForcePosture, InternationalInvolvement,
@@ -661,9 +663,14 @@ impl TryFrom<Option<CAMEOEventCode>> for EventActionDescription {
                         ),
                     )),
                     Some('2') => Ok(EventActionDescription::Yield(
-                        Yieldable::AdministrativeSanctions(AdministrativeSanctions::Curfew),
+                        Yieldable::AdministrativeSanctions(
+                            AdministrativeSanctions::BanPoliticalPartiesOrPoliticians,
+                        ),
                     )),
                     Some('3') => Ok(EventActionDescription::Yield(
+                        Yieldable::AdministrativeSanctions(AdministrativeSanctions::Curfew),
+                    )),
+                    Some('4') => Ok(EventActionDescription::Yield(
                         Yieldable::AdministrativeSanctions(
                             AdministrativeSanctions::StateOfEmergencyOrMartialLaw,
                         ),
@@ -901,23 +908,19 @@ impl TryFrom<Option<CAMEOEventCode>> for EventActionDescription {
                 },
                 Some('2') => match str_value.chars().nth(3) {
                     Some('1') => Ok(EventActionDescription::Reject(Rejection::Cooperation(
-                        Cooperation::MaterialCooperation(MaterialCooperation::Economic),
+                        Cooperation::Aid(Aid::Economic),
                     ))),
                     Some('2') => Ok(EventActionDescription::Reject(Rejection::Cooperation(
-                        Cooperation::MaterialCooperation(MaterialCooperation::Military),
+                        Cooperation::Aid(Aid::Military),
                     ))),
                     Some('3') => Ok(EventActionDescription::Reject(Rejection::Cooperation(
-                        Cooperation::MaterialCooperation(MaterialCooperation::Aid(
-                            Aid::Humanitarian,
-                        )),
+                        Cooperation::Aid(Aid::Humanitarian),
                     ))),
                     Some('4') => Ok(EventActionDescription::Reject(Rejection::Cooperation(
-                        Cooperation::MaterialCooperation(MaterialCooperation::Aid(
-                            Aid::MilitaryProtectionOrPeaceKeeping,
-                        )),
+                        Cooperation::Aid(Aid::MilitaryProtectionOrPeaceKeeping),
                     ))),
                     None | Some(_) => Ok(EventActionDescription::Reject(Rejection::Cooperation(
-                        Cooperation::MaterialCooperation(MaterialCooperation::Unspecified),
+                        Cooperation::Aid(Aid::Unspecified),
                     ))),
                 },
                 Some('3') => match str_value.chars().nth(3) {
@@ -1148,6 +1151,9 @@ impl TryFrom<Option<CAMEOEventCode>> for EventActionDescription {
                 Some('4') => Ok(EventActionDescription::ExhibitForcePosture(
                     ForcePosture::MobilizeOrIncreaseArmedForces,
                 )),
+                Some('5') => Ok(EventActionDescription::ExhibitForcePosture(
+                    ForcePosture::MobilizeOrIncreaseCyberForces,
+                )),
                 None | Some(_) => Ok(EventActionDescription::ExhibitForcePosture(
                     ForcePosture::Unspecified,
                 )),
@@ -1237,6 +1243,7 @@ impl TryFrom<Option<CAMEOEventCode>> for EventActionDescription {
                 )),
                 Some('4') => Ok(EventActionDescription::Coerce(Coercion::ExpelDeport)),
                 Some('5') => Ok(EventActionDescription::Coerce(Coercion::ViolentRepression)),
+                Some('6') => Ok(EventActionDescription::Coerce(Coercion::CyberneticAttack)),
                 None | Some(_) => Ok(EventActionDescription::Coerce(Coercion::Unspecified)),
             },
             "18" => match str_value.chars().nth(2) {
@@ -1267,6 +1274,9 @@ impl TryFrom<Option<CAMEOEventCode>> for EventActionDescription {
                     Some('3') => Ok(EventActionDescription::Assault(Assault::Bombing(
                         Bombing::Roadside,
                     ))),
+                    Some('4') => Ok(EventActionDescription::Assault(Assault::Bombing(
+                        Bombing::Location,
+                    ))),
                     None | Some(_) => Ok(EventActionDescription::Assault(Assault::Bombing(
                         Bombing::Unspecified,
                     ))),
@@ -1283,9 +1293,17 @@ impl TryFrom<Option<CAMEOEventCode>> for EventActionDescription {
                 Some('2') => Ok(EventActionDescription::Fight(Fight::OccupyTerritory)),
                 Some('3') => Ok(EventActionDescription::Fight(Fight::SmallArmsLightWeapons)),
                 Some('4') => Ok(EventActionDescription::Fight(Fight::ArtilleryAndTanks)),
-                Some('5') => Ok(EventActionDescription::Fight(Fight::Arial(
-                    ArialWeapons::Unspecified,
-                ))),
+                Some('5') => match str_value.chars().nth(3) {
+                    Some('1') => Ok(EventActionDescription::Fight(Fight::Arial(
+                        ArialWeapons::PrecisionGuided,
+                    ))),
+                    Some('2') => Ok(EventActionDescription::Fight(Fight::Arial(
+                        ArialWeapons::RemotelyPiloted,
+                    ))),
+                    None | Some(_) => Ok(EventActionDescription::Fight(Fight::Arial(
+                        ArialWeapons::Unspecified,
+                    ))),
+                },
                 Some('6') => Ok(EventActionDescription::Fight(Fight::ViolateCeasefire)),
                 None | Some(_) => Ok(EventActionDescription::Fight(Fight::Unspecified)),
             },
@@ -1322,11 +1340,750 @@ impl TryFrom<Option<CAMEOEventCode>> for EventActionDescription {
     }
 }
 
+/// One entry of the CAMEO event codebook, with the default Goldstein score GDELT assigns it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CAMEOEventEntry {
+    pub code: &'static str,
+    pub label: &'static str,
+    pub goldstein_scale: f64,
+}
+
+impl CAMEOEventEntry {
+    const fn new(code: &'static str, label: &'static str, goldstein_scale: f64) -> Self {
+        Self {
+            code,
+            label,
+            goldstein_scale,
+        }
+    }
+
+    /// Look up a two, three or four digit code
+    pub fn lookup(code: &str) -> Option<&'static CAMEOEventEntry> {
+        let code = code.trim();
+        CAMEO_EVENT_CODES.iter().find(|entry| entry.code == code)
+    }
+
+    /// Every code in the codebook, roots included, in codebook order
+    pub fn all() -> impl Iterator<Item = &'static CAMEOEventEntry> {
+        CAMEO_EVENT_CODES.iter()
+    }
+
+    /// Every code under a root, e.g. `"14"` for all Protest codes
+    pub fn in_root(root: &str) -> impl Iterator<Item = &'static CAMEOEventEntry> + '_ {
+        CAMEO_EVENT_CODES
+            .iter()
+            .filter(move |entry| entry.root_code() == root)
+    }
+
+    pub fn is_root(&self) -> bool {
+        self.code.len() == 2
+    }
+
+    pub fn root_code(&self) -> &str {
+        &self.code[..2]
+    }
+
+    pub fn base_code(&self) -> &str {
+        &self.code[..self.code.len().min(3)]
+    }
+
+    pub fn root(&self) -> Option<&'static CAMEOEventEntry> {
+        Self::lookup(self.root_code())
+    }
+
+    pub fn base(&self) -> Option<&'static CAMEOEventEntry> {
+        if self.is_root() {
+            return None;
+        }
+        Self::lookup(self.base_code())
+    }
+
+    /// The next level up: four digit codes point to their base, base codes to their root
+    pub fn parent(&self) -> Option<&'static CAMEOEventEntry> {
+        match self.code.len() {
+            4 => self.base(),
+            3 => self.root(),
+            _ => None,
+        }
+    }
+
+    /// Codes exactly one level below this one
+    pub fn children(&self) -> impl Iterator<Item = &'static CAMEOEventEntry> + '_ {
+        CAMEO_EVENT_CODES.iter().filter(move |entry| {
+            entry.code.len() == self.code.len() + 1 && entry.code.starts_with(self.code)
+        })
+    }
+
+    pub fn description(&self) -> Option<EventActionDescription> {
+        EventActionDescription::try_from(Some(CAMEOEventCode(self.code.to_string()))).ok()
+    }
+}
+
+/// Descriptions paired with the most specific code that produces them.
+///
+/// Root codes are skipped since they parse to the same description as their
+/// "not specified below" code (e.g. `14` and `140`).
+fn description_index() -> &'static [(&'static CAMEOEventEntry, EventActionDescription)] {
+    static INDEX: OnceLock<Vec<(&'static CAMEOEventEntry, EventActionDescription)>> =
+        OnceLock::new();
+    INDEX.get_or_init(|| {
+        CAMEO_EVENT_CODES
+            .iter()
+            .filter(|entry| !entry.is_root())
+            .filter_map(|entry| entry.description().map(|description| (entry, description)))
+            .collect()
+    })
+}
+
+impl EventActionDescription {
+    /// The codebook entry for this description
+    pub fn entry(&self) -> Option<&'static CAMEOEventEntry> {
+        description_index()
+            .iter()
+            .find(|(_, description)| description == self)
+            .map(|(entry, _)| *entry)
+    }
+
+    pub fn code(&self) -> Option<&'static str> {
+        self.entry().map(|entry| entry.code)
+    }
+
+    pub fn label(&self) -> Option<&'static str> {
+        self.entry().map(|entry| entry.label)
+    }
+
+    /// Default Goldstein score for this kind of event
+    pub fn goldstein_scale(&self) -> Option<f64> {
+        self.entry().map(|entry| entry.goldstein_scale)
+    }
+
+    pub fn root_code(&self) -> Option<&'static str> {
+        self.entry().map(|entry| &entry.code[..2])
+    }
+
+    /// The root-level description, e.g. `Protest(Unspecified)` for any protest code
+    pub fn root(&self) -> Option<EventActionDescription> {
+        self.entry()?.root()?.description()
+    }
+
+    /// The three digit base description this falls under
+    pub fn base(&self) -> Option<EventActionDescription> {
+        self.entry()?.base()?.description()
+    }
+
+    pub fn is_in_root(&self, root: &str) -> bool {
+        self.root_code() == Some(root)
+    }
+
+    /// Every description in the codebook with its entry, from 010 through 2042
+    pub fn all() -> impl Iterator<Item = (&'static CAMEOEventEntry, EventActionDescription)> {
+        description_index()
+            .iter()
+            .map(|(entry, description)| (*entry, description.clone()))
+    }
+}
+
+impl fmt::Display for EventActionDescription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.label() {
+            Some(label) => write!(f, "{}", label),
+            None => write!(f, "Unspecified"),
+        }
+    }
+}
+
+/// CAMEO event codebook with the Goldstein scale values from GDELT's CAMEO.goldsteinscale.txt.
+/// Codes added to CAMEO after the Goldstein scores were assigned (155, 176, 1834, 1951, 1952)
+/// carry their parent's score.
+pub const CAMEO_EVENT_CODES: &[CAMEOEventEntry] = &[
+    // 01: MAKE PUBLIC STATEMENT
+    CAMEOEventEntry::new("01", "Make public statement", 0.0),
+    CAMEOEventEntry::new("010", "Make statement, not specified below", 0.0),
+    CAMEOEventEntry::new("011", "Decline comment", -0.1),
+    CAMEOEventEntry::new("012", "Make pessimistic comment", -0.4),
+    CAMEOEventEntry::new("013", "Make optimistic comment", 0.4),
+    CAMEOEventEntry::new("014", "Consider policy option", 0.0),
+    CAMEOEventEntry::new("015", "Acknowledge or claim responsibility", 0.0),
+    CAMEOEventEntry::new("016", "Deny responsibility", 3.4),
+    CAMEOEventEntry::new("017", "Engage in symbolic act", 0.0),
+    CAMEOEventEntry::new("018", "Make empathetic comment", 3.4),
+    CAMEOEventEntry::new("019", "Express accord", 3.4),
+    // 02: APPEAL
+    CAMEOEventEntry::new("02", "Appeal", 3.0),
+    CAMEOEventEntry::new("020", "Appeal, not specified below", 3.0),
+    CAMEOEventEntry::new(
+        "021",
+        "Appeal for material cooperation, not specified below",
+        3.4,
+    ),
+    CAMEOEventEntry::new("0211", "Appeal for economic cooperation", 3.4),
+    CAMEOEventEntry::new("0212", "Appeal for military cooperation", 3.4),
+    CAMEOEventEntry::new("0213", "Appeal for judicial cooperation", 3.4),
+    CAMEOEventEntry::new("0214", "Appeal for intelligence", 3.4),
+    CAMEOEventEntry::new(
+        "022",
+        "Appeal for diplomatic cooperation, such as policy support",
+        3.2,
+    ),
+    CAMEOEventEntry::new("023", "Appeal for aid, not specified below", 3.4),
+    CAMEOEventEntry::new("0231", "Appeal for economic aid", 3.4),
+    CAMEOEventEntry::new("0232", "Appeal for military aid", 3.4),
+    CAMEOEventEntry::new("0233", "Appeal for humanitarian aid", 3.4),
+    CAMEOEventEntry::new(
+        "0234",
+        "Appeal for military protection or peacekeeping",
+        3.4,
+    ),
+    CAMEOEventEntry::new(
+        "024",
+        "Appeal for political reform, not specified below",
+        -0.3,
+    ),
+    CAMEOEventEntry::new("0241", "Appeal for change in leadership", -0.3),
+    CAMEOEventEntry::new("0242", "Appeal for policy change", -0.3),
+    CAMEOEventEntry::new("0243", "Appeal for rights", -0.3),
+    CAMEOEventEntry::new("0244", "Appeal for change in institutions, regime", -0.3),
+    CAMEOEventEntry::new("025", "Appeal to yield, not specified below", -0.3),
+    CAMEOEventEntry::new(
+        "0251",
+        "Appeal for easing of administrative sanctions",
+        -0.3,
+    ),
+    CAMEOEventEntry::new("0252", "Appeal for easing of popular dissent", -0.3),
+    CAMEOEventEntry::new("0253", "Appeal for release of persons or property", -0.3),
+    CAMEOEventEntry::new(
+        "0254",
+        "Appeal for easing of economic sanctions, boycott, or embargo",
+        -0.3,
+    ),
+    CAMEOEventEntry::new(
+        "0255",
+        "Appeal for target to allow international involvement (non-mediation)",
+        -0.3,
+    ),
+    CAMEOEventEntry::new(
+        "0256",
+        "Appeal for de-escalation of military engagement",
+        -0.3,
+    ),
+    CAMEOEventEntry::new("026", "Appeal to others to meet or negotiate", 4.0),
+    CAMEOEventEntry::new("027", "Appeal to others to settle dispute", 4.0),
+    CAMEOEventEntry::new(
+        "028",
+        "Appeal to others to engage in or accept mediation",
+        4.0,
+    ),
+    // 03: EXPRESS INTENT TO COOPERATE
+    CAMEOEventEntry::new("03", "Express intent to cooperate", 4.0),
+    CAMEOEventEntry::new(
+        "030",
+        "Express intent to cooperate, not specified below",
+        4.0,
+    ),
+    CAMEOEventEntry::new(
+        "031",
+        "Express intent to engage in material cooperation, not specified below",
+        5.2,
+    ),
+    CAMEOEventEntry::new("0311", "Express intent to cooperate economically", 5.2),
+    CAMEOEventEntry::new("0312", "Express intent to cooperate militarily", 5.2),
+    CAMEOEventEntry::new(
+        "0313",
+        "Express intent to cooperate on judicial matters",
+        5.2,
+    ),
+    CAMEOEventEntry::new("0314", "Express intent to cooperate on intelligence", 5.2),
+    CAMEOEventEntry::new(
+        "032",
+        "Express intent to provide diplomatic cooperation such as policy support",
+        4.5,
+    ),
+    CAMEOEventEntry::new(
+        "033",
+        "Express intent to provide material aid, not specified below",
+        5.2,
+    ),
+    CAMEOEventEntry::new("0331", "Express intent to provide economic aid", 5.2),
+    CAMEOEventEntry::new("0332", "Express intent to provide military aid", 5.2),
+    CAMEOEventEntry::new("0333", "Express intent to provide humanitarian aid", 5.2),
+    CAMEOEventEntry::new(
+        "0334",
+        "Express intent to provide military protection or peacekeeping",
+        6.0,
+    ),
+    CAMEOEventEntry::new(
+        "034",
+        "Express intent to institute political reform, not specified below",
+        7.0,
+    ),
+    CAMEOEventEntry::new("0341", "Express intent to change leadership", 7.0),
+    CAMEOEventEntry::new("0342", "Express intent to change policy", 7.0),
+    CAMEOEventEntry::new("0343", "Express intent to provide rights", 7.0),
+    CAMEOEventEntry::new("0344", "Express intent to change institutions, regime", 7.0),
+    CAMEOEventEntry::new("035", "Express intent to yield, not specified below", 7.0),
+    CAMEOEventEntry::new(
+        "0351",
+        "Express intent to ease administrative sanctions",
+        7.0,
+    ),
+    CAMEOEventEntry::new("0352", "Express intent to ease popular dissent", 7.0),
+    CAMEOEventEntry::new("0353", "Express intent to release persons or property", 7.0),
+    CAMEOEventEntry::new(
+        "0354",
+        "Express intent to ease economic sanctions, boycott, or embargo",
+        7.0,
+    ),
+    CAMEOEventEntry::new(
+        "0355",
+        "Express intent to allow international involvement (non-mediation)",
+        7.0,
+    ),
+    CAMEOEventEntry::new(
+        "0356",
+        "Express intent to de-escalate military engagement",
+        7.0,
+    ),
+    CAMEOEventEntry::new("036", "Express intent to meet or negotiate", 4.0),
+    CAMEOEventEntry::new("037", "Express intent to settle dispute", 5.0),
+    CAMEOEventEntry::new("038", "Express intent to accept mediation", 7.0),
+    CAMEOEventEntry::new("039", "Express intent to mediate", 5.0),
+    // 04: CONSULT
+    CAMEOEventEntry::new("04", "Consult", 1.0),
+    CAMEOEventEntry::new("040", "Consult, not specified below", 1.0),
+    CAMEOEventEntry::new("041", "Discuss by telephone", 1.0),
+    CAMEOEventEntry::new("042", "Make a visit", 1.9),
+    CAMEOEventEntry::new("043", "Host a visit", 2.8),
+    CAMEOEventEntry::new("044", "Meet at a third location", 2.5),
+    CAMEOEventEntry::new("045", "Mediate", 5.0),
+    CAMEOEventEntry::new("046", "Engage in negotiation", 7.0),
+    // 05: ENGAGE IN DIPLOMATIC COOPERATION
+    CAMEOEventEntry::new("05", "Engage in diplomatic cooperation", 3.5),
+    CAMEOEventEntry::new(
+        "050",
+        "Engage in diplomatic cooperation, not specified below",
+        3.5,
+    ),
+    CAMEOEventEntry::new("051", "Praise or endorse", 3.4),
+    CAMEOEventEntry::new("052", "Defend verbally", 3.5),
+    CAMEOEventEntry::new("053", "Rally support on behalf of", 3.8),
+    CAMEOEventEntry::new("054", "Grant diplomatic recognition", 6.0),
+    CAMEOEventEntry::new("055", "Apologize", 7.0),
+    CAMEOEventEntry::new("056", "Forgive", 7.0),
+    CAMEOEventEntry::new("057", "Sign formal agreement", 8.0),
+    // 06: ENGAGE IN MATERIAL COOPERATION
+    CAMEOEventEntry::new("06", "Engage in material cooperation", 6.0),
+    CAMEOEventEntry::new(
+        "060",
+        "Engage in material cooperation, not specified below",
+        6.0,
+    ),
+    CAMEOEventEntry::new("061", "Cooperate economically", 6.4),
+    CAMEOEventEntry::new("062", "Cooperate militarily", 7.4),
+    CAMEOEventEntry::new("063", "Engage in judicial cooperation", 7.4),
+    CAMEOEventEntry::new("064", "Share intelligence or information", 7.0),
+    // 07: PROVIDE AID
+    CAMEOEventEntry::new("07", "Provide aid", 7.0),
+    CAMEOEventEntry::new("070", "Provide aid, not specified below", 7.0),
+    CAMEOEventEntry::new("071", "Provide economic aid", 7.4),
+    CAMEOEventEntry::new("072", "Provide military aid", 8.3),
+    CAMEOEventEntry::new("073", "Provide humanitarian aid", 7.4),
+    CAMEOEventEntry::new("074", "Provide military protection or peacekeeping", 8.5),
+    CAMEOEventEntry::new("075", "Grant asylum", 7.0),
+    // 08: YIELD
+    CAMEOEventEntry::new("08", "Yield", 5.0),
+    CAMEOEventEntry::new("080", "Yield, not specified below", 5.0),
+    CAMEOEventEntry::new(
+        "081",
+        "Ease administrative sanctions, not specified below",
+        5.0,
+    ),
+    CAMEOEventEntry::new("0811", "Ease restrictions on political freedoms", 5.0),
+    CAMEOEventEntry::new("0812", "Ease ban on political parties or politicians", 5.0),
+    CAMEOEventEntry::new("0813", "Ease curfew", 5.0),
+    CAMEOEventEntry::new("0814", "Ease state of emergency or martial law", 5.0),
+    CAMEOEventEntry::new("082", "Ease political dissent", 5.0),
+    CAMEOEventEntry::new(
+        "083",
+        "Accede to requests or demands for political reform, not specified below",
+        5.0,
+    ),
+    CAMEOEventEntry::new("0831", "Accede to demands for change in leadership", 5.0),
+    CAMEOEventEntry::new("0832", "Accede to demands for change in policy", 5.0),
+    CAMEOEventEntry::new("0833", "Accede to demands for rights", 5.0),
+    CAMEOEventEntry::new(
+        "0834",
+        "Accede to demands for change in institutions, regime",
+        5.0,
+    ),
+    CAMEOEventEntry::new("084", "Return, release, not specified below", 7.0),
+    CAMEOEventEntry::new("0841", "Return, release person(s)", 7.0),
+    CAMEOEventEntry::new("0842", "Return, release property", 7.0),
+    CAMEOEventEntry::new("085", "Ease economic sanctions, boycott, embargo", 7.0),
+    CAMEOEventEntry::new(
+        "086",
+        "Allow international involvement, not specified below",
+        9.0,
+    ),
+    CAMEOEventEntry::new("0861", "Receive deployment of peacekeepers", 9.0),
+    CAMEOEventEntry::new("0862", "Receive inspectors", 9.0),
+    CAMEOEventEntry::new("0863", "Allow delivery of humanitarian aid", 9.0),
+    CAMEOEventEntry::new("087", "De-escalate military engagement", 9.0),
+    CAMEOEventEntry::new("0871", "Declare truce, ceasefire", 9.0),
+    CAMEOEventEntry::new("0872", "Ease military blockade", 9.0),
+    CAMEOEventEntry::new("0873", "Demobilize armed forces", 9.0),
+    CAMEOEventEntry::new("0874", "Retreat or surrender militarily", 10.0),
+    // 09: INVESTIGATE
+    CAMEOEventEntry::new("09", "Investigate", -2.0),
+    CAMEOEventEntry::new("090", "Investigate, not specified below", -2.0),
+    CAMEOEventEntry::new("091", "Investigate crime, corruption", -2.0),
+    CAMEOEventEntry::new("092", "Investigate human rights abuses", -2.0),
+    CAMEOEventEntry::new("093", "Investigate military action", -2.0),
+    CAMEOEventEntry::new("094", "Investigate war crimes", -2.0),
+    // 10: DEMAND
+    CAMEOEventEntry::new("10", "Demand", -5.0),
+    CAMEOEventEntry::new("100", "Demand, not specified below", -5.0),
+    CAMEOEventEntry::new(
+        "101",
+        "Demand material cooperation, not specified below",
+        -5.0,
+    ),
+    CAMEOEventEntry::new("1011", "Demand economic cooperation", -5.0),
+    CAMEOEventEntry::new("1012", "Demand military cooperation", -5.0),
+    CAMEOEventEntry::new("1013", "Demand judicial cooperation", -5.0),
+    CAMEOEventEntry::new("1014", "Demand intelligence cooperation", -5.0),
+    CAMEOEventEntry::new("102", "Demand policy support", -5.0),
+    CAMEOEventEntry::new("103", "Demand aid, not specified below", -5.0),
+    CAMEOEventEntry::new("1031", "Demand economic aid", -5.0),
+    CAMEOEventEntry::new("1032", "Demand military aid", -5.0),
+    CAMEOEventEntry::new("1033", "Demand humanitarian aid", -5.0),
+    CAMEOEventEntry::new("1034", "Demand military protection or peacekeeping", -5.0),
+    CAMEOEventEntry::new("104", "Demand political reform, not specified below", -5.0),
+    CAMEOEventEntry::new("1041", "Demand change in leadership", -5.0),
+    CAMEOEventEntry::new("1042", "Demand policy change", -5.0),
+    CAMEOEventEntry::new("1043", "Demand rights", -5.0),
+    CAMEOEventEntry::new("1044", "Demand change in institutions, regime", -5.0),
+    CAMEOEventEntry::new(
+        "105",
+        "Demand that target yields, not specified below",
+        -5.0,
+    ),
+    CAMEOEventEntry::new("1051", "Demand easing of administrative sanctions", -5.0),
+    CAMEOEventEntry::new("1052", "Demand easing of political dissent", -5.0),
+    CAMEOEventEntry::new("1053", "Demand release of persons or property", -5.0),
+    CAMEOEventEntry::new(
+        "1054",
+        "Demand easing of economic sanctions, boycott, or embargo",
+        -5.0,
+    ),
+    CAMEOEventEntry::new(
+        "1055",
+        "Demand that target allows international involvement (non-mediation)",
+        -5.0,
+    ),
+    CAMEOEventEntry::new("1056", "Demand de-escalation of military engagement", -5.0),
+    CAMEOEventEntry::new("106", "Demand withdrawal", -5.0),
+    CAMEOEventEntry::new("107", "Demand ceasefire", -5.0),
+    CAMEOEventEntry::new("108", "Demand meeting, negotiation", -5.0),
+    // 11: DISAPPROVE
+    CAMEOEventEntry::new("11", "Disapprove", -2.0),
+    CAMEOEventEntry::new("110", "Disapprove, not specified below", -2.0),
+    CAMEOEventEntry::new("111", "Criticize or denounce", -2.0),
+    CAMEOEventEntry::new("112", "Accuse, not specified below", -2.0),
+    CAMEOEventEntry::new("1121", "Accuse of crime, corruption", -2.0),
+    CAMEOEventEntry::new("1122", "Accuse of human rights abuses", -2.0),
+    CAMEOEventEntry::new("1123", "Accuse of aggression", -2.0),
+    CAMEOEventEntry::new("1124", "Accuse of war crimes", -2.0),
+    CAMEOEventEntry::new("1125", "Accuse of espionage, treason", -2.0),
+    CAMEOEventEntry::new("113", "Rally opposition against", -2.0),
+    CAMEOEventEntry::new("114", "Complain officially", -2.0),
+    CAMEOEventEntry::new("115", "Bring lawsuit against", -2.0),
+    CAMEOEventEntry::new("116", "Find guilty or liable (legally)", -2.0),
+    // 12: REJECT
+    CAMEOEventEntry::new("12", "Reject", -4.0),
+    CAMEOEventEntry::new("120", "Reject, not specified below", -4.0),
+    CAMEOEventEntry::new("121", "Reject material cooperation", -4.0),
+    CAMEOEventEntry::new("1211", "Reject economic cooperation", -4.0),
+    CAMEOEventEntry::new("1212", "Reject military cooperation", -4.0),
+    CAMEOEventEntry::new(
+        "122",
+        "Reject request or demand for material aid, not specified below",
+        -4.0,
+    ),
+    CAMEOEventEntry::new("1221", "Reject request for economic aid", -4.0),
+    CAMEOEventEntry::new("1222", "Reject request for military aid", -4.0),
+    CAMEOEventEntry::new("1223", "Reject request for humanitarian aid", -4.0),
+    CAMEOEventEntry::new(
+        "1224",
+        "Reject request for military protection or peacekeeping",
+        -4.0,
+    ),
+    CAMEOEventEntry::new(
+        "123",
+        "Reject request or demand for political reform, not specified below",
+        -4.0,
+    ),
+    CAMEOEventEntry::new("1231", "Reject request for change in leadership", -4.0),
+    CAMEOEventEntry::new("1232", "Reject request for policy change", -4.0),
+    CAMEOEventEntry::new("1233", "Reject request for rights", -4.0),
+    CAMEOEventEntry::new(
+        "1234",
+        "Reject request for change in institutions, regime",
+        -4.0,
+    ),
+    CAMEOEventEntry::new("124", "Refuse to yield, not specified below", -5.0),
+    CAMEOEventEntry::new("1241", "Refuse to ease administrative sanctions", -5.0),
+    CAMEOEventEntry::new("1242", "Refuse to ease popular dissent", -5.0),
+    CAMEOEventEntry::new("1243", "Refuse to release persons or property", -5.0),
+    CAMEOEventEntry::new(
+        "1244",
+        "Refuse to ease economic sanctions, boycott, or embargo",
+        -5.0,
+    ),
+    CAMEOEventEntry::new(
+        "1245",
+        "Refuse to allow international involvement (non mediation)",
+        -5.0,
+    ),
+    CAMEOEventEntry::new("1246", "Refuse to de-escalate military engagement", -5.0),
+    CAMEOEventEntry::new(
+        "125",
+        "Reject proposal to meet, discuss, or negotiate",
+        -5.0,
+    ),
+    CAMEOEventEntry::new("126", "Reject mediation", -5.0),
+    CAMEOEventEntry::new("127", "Reject plan, agreement to settle dispute", -5.0),
+    CAMEOEventEntry::new("128", "Defy norms, law", -5.0),
+    CAMEOEventEntry::new("129", "Veto", -5.0),
+    // 13: THREATEN
+    CAMEOEventEntry::new("13", "Threaten", -6.0),
+    CAMEOEventEntry::new("130", "Threaten, not specified below", -4.4),
+    CAMEOEventEntry::new("131", "Threaten non-force, not specified below", -5.8),
+    CAMEOEventEntry::new("1311", "Threaten to reduce or stop aid", -5.8),
+    CAMEOEventEntry::new("1312", "Threaten to boycott, embargo, or sanction", -5.8),
+    CAMEOEventEntry::new("1313", "Threaten to reduce or break relations", -5.8),
+    CAMEOEventEntry::new(
+        "132",
+        "Threaten with administrative sanctions, not specified below",
+        -5.8,
+    ),
+    CAMEOEventEntry::new(
+        "1321",
+        "Threaten to impose restrictions on political freedoms",
+        -5.8,
+    ),
+    CAMEOEventEntry::new(
+        "1322",
+        "Threaten to ban political parties or politicians",
+        -5.8,
+    ),
+    CAMEOEventEntry::new("1323", "Threaten to impose curfew", -5.8),
+    CAMEOEventEntry::new(
+        "1324",
+        "Threaten to impose state of emergency or martial law",
+        -5.8,
+    ),
+    CAMEOEventEntry::new("133", "Threaten political dissent, protest", -5.8),
+    CAMEOEventEntry::new("134", "Threaten to halt negotiations", -5.8),
+    CAMEOEventEntry::new("135", "Threaten to halt mediation", -5.8),
+    CAMEOEventEntry::new(
+        "136",
+        "Threaten to halt international involvement (non-mediation)",
+        -7.0,
+    ),
+    CAMEOEventEntry::new("137", "Threaten with repression", -7.0),
+    CAMEOEventEntry::new(
+        "138",
+        "Threaten to use military force, not specified below",
+        -7.0,
+    ),
+    CAMEOEventEntry::new("1381", "Threaten blockade", -7.0),
+    CAMEOEventEntry::new("1382", "Threaten occupation", -7.0),
+    CAMEOEventEntry::new("1383", "Threaten unconventional violence", -7.0),
+    CAMEOEventEntry::new("1384", "Threaten conventional attack", -7.0),
+    CAMEOEventEntry::new("1385", "Threaten attack with WMD", -7.0),
+    CAMEOEventEntry::new("139", "Give ultimatum", -7.0),
+    // 14: PROTEST
+    CAMEOEventEntry::new("14", "Protest", -6.5),
+    CAMEOEventEntry::new(
+        "140",
+        "Engage in political dissent, not specified below",
+        -6.5,
+    ),
+    CAMEOEventEntry::new("141", "Demonstrate or rally, not specified below", -6.5),
+    CAMEOEventEntry::new("1411", "Demonstrate for leadership change", -6.5),
+    CAMEOEventEntry::new("1412", "Demonstrate for policy change", -6.5),
+    CAMEOEventEntry::new("1413", "Demonstrate for rights", -6.5),
+    CAMEOEventEntry::new(
+        "1414",
+        "Demonstrate for change in institutions, regime",
+        -6.5,
+    ),
+    CAMEOEventEntry::new("142", "Conduct hunger strike, not specified below", -6.5),
+    CAMEOEventEntry::new("1421", "Conduct hunger strike for leadership change", -6.5),
+    CAMEOEventEntry::new("1422", "Conduct hunger strike for policy change", -6.5),
+    CAMEOEventEntry::new("1423", "Conduct hunger strike for rights", -6.5),
+    CAMEOEventEntry::new(
+        "1424",
+        "Conduct hunger strike for change in institutions, regime",
+        -6.5,
+    ),
+    CAMEOEventEntry::new(
+        "143",
+        "Conduct strike or boycott, not specified below",
+        -6.5,
+    ),
+    CAMEOEventEntry::new(
+        "1431",
+        "Conduct strike or boycott for leadership change",
+        -6.5,
+    ),
+    CAMEOEventEntry::new("1432", "Conduct strike or boycott for policy change", -6.5),
+    CAMEOEventEntry::new("1433", "Conduct strike or boycott for rights", -6.5),
+    CAMEOEventEntry::new(
+        "1434",
+        "Conduct strike or boycott for change in institutions, regime",
+        -6.5,
+    ),
+    CAMEOEventEntry::new("144", "Obstruct passage, block, not specified below", -7.5),
+    CAMEOEventEntry::new("1441", "Obstruct passage to demand leadership change", -7.5),
+    CAMEOEventEntry::new("1442", "Obstruct passage to demand policy change", -7.5),
+    CAMEOEventEntry::new("1443", "Obstruct passage to demand rights", -7.5),
+    CAMEOEventEntry::new(
+        "1444",
+        "Obstruct passage to demand change in institutions, regime",
+        -7.5,
+    ),
+    CAMEOEventEntry::new("145", "Protest violently, riot, not specified below", -7.5),
+    CAMEOEventEntry::new(
+        "1451",
+        "Engage in violent protest for leadership change",
+        -7.5,
+    ),
+    CAMEOEventEntry::new("1452", "Engage in violent protest for policy change", -7.5),
+    CAMEOEventEntry::new("1453", "Engage in violent protest for rights", -7.5),
+    CAMEOEventEntry::new(
+        "1454",
+        "Engage in violent protest for change in institutions, regime",
+        -7.5,
+    ),
+    // 15: EXHIBIT FORCE POSTURE
+    CAMEOEventEntry::new("15", "Exhibit force posture", -7.2),
+    CAMEOEventEntry::new(
+        "150",
+        "Demonstrate military or police power, not specified below",
+        -7.2,
+    ),
+    CAMEOEventEntry::new("151", "Increase police alert status", -7.2),
+    CAMEOEventEntry::new("152", "Increase military alert status", -7.2),
+    CAMEOEventEntry::new("153", "Mobilize or increase police power", -7.2),
+    CAMEOEventEntry::new("154", "Mobilize or increase armed forces", -7.2),
+    CAMEOEventEntry::new("155", "Mobilize or increase cyber-forces", -7.2),
+    // 16: REDUCE RELATIONS
+    CAMEOEventEntry::new("16", "Reduce relations", -4.0),
+    CAMEOEventEntry::new("160", "Reduce relations, not specified below", -4.0),
+    CAMEOEventEntry::new("161", "Reduce or break diplomatic relations", -4.0),
+    CAMEOEventEntry::new(
+        "162",
+        "Reduce or stop material aid, not specified below",
+        -5.6,
+    ),
+    CAMEOEventEntry::new("1621", "Reduce or stop economic assistance", -5.6),
+    CAMEOEventEntry::new("1622", "Reduce or stop military assistance", -5.6),
+    CAMEOEventEntry::new("1623", "Reduce or stop humanitarian assistance", -5.6),
+    CAMEOEventEntry::new("163", "Impose embargo, boycott, or sanctions", -8.0),
+    CAMEOEventEntry::new("164", "Halt negotiations", -7.0),
+    CAMEOEventEntry::new("165", "Halt mediation", -7.0),
+    CAMEOEventEntry::new("166", "Expel or withdraw, not specified below", -7.0),
+    CAMEOEventEntry::new("1661", "Expel or withdraw peacekeepers", -7.0),
+    CAMEOEventEntry::new("1662", "Expel or withdraw inspectors, observers", -7.0),
+    CAMEOEventEntry::new("1663", "Expel or withdraw aid agencies", -7.0),
+    // 17: COERCE
+    CAMEOEventEntry::new("17", "Coerce", -7.0),
+    CAMEOEventEntry::new("170", "Coerce, not specified below", -7.0),
+    CAMEOEventEntry::new("171", "Seize or damage property, not specified below", -9.2),
+    CAMEOEventEntry::new("1711", "Confiscate property", -9.2),
+    CAMEOEventEntry::new("1712", "Destroy property", -9.2),
+    CAMEOEventEntry::new(
+        "172",
+        "Impose administrative sanctions, not specified below",
+        -5.0,
+    ),
+    CAMEOEventEntry::new("1721", "Impose restrictions on political freedoms", -5.0),
+    CAMEOEventEntry::new("1722", "Ban political parties or politicians", -5.0),
+    CAMEOEventEntry::new("1723", "Impose curfew", -5.0),
+    CAMEOEventEntry::new("1724", "Impose state of emergency or martial law", -5.0),
+    CAMEOEventEntry::new("173", "Arrest, detain, or charge with legal action", -5.0),
+    CAMEOEventEntry::new("174", "Expel or deport individuals", -5.0),
+    CAMEOEventEntry::new("175", "Use tactics of violent repression", -9.0),
+    CAMEOEventEntry::new("176", "Attack cybernetically", -7.0),
+    // 18: ASSAULT
+    CAMEOEventEntry::new("18", "Assault", -9.0),
+    CAMEOEventEntry::new(
+        "180",
+        "Use unconventional violence, not specified below",
+        -9.0,
+    ),
+    CAMEOEventEntry::new("181", "Abduct, hijack, or take hostage", -9.0),
+    CAMEOEventEntry::new("182", "Physically assault, not specified below", -9.5),
+    CAMEOEventEntry::new("1821", "Sexually assault", -9.0),
+    CAMEOEventEntry::new("1822", "Torture", -9.0),
+    CAMEOEventEntry::new("1823", "Kill by physical assault", -10.0),
+    CAMEOEventEntry::new(
+        "183",
+        "Conduct suicide, car, or other non-military bombing, not specified below",
+        -10.0,
+    ),
+    CAMEOEventEntry::new("1831", "Carry out suicide bombing", -10.0),
+    CAMEOEventEntry::new("1832", "Carry out vehicular bombing", -10.0),
+    CAMEOEventEntry::new("1833", "Carry out roadside bombing", -10.0),
+    CAMEOEventEntry::new("1834", "Carry out location bombing", -10.0),
+    CAMEOEventEntry::new("184", "Use as human shield", -8.0),
+    CAMEOEventEntry::new("185", "Attempt to assassinate", -8.0),
+    CAMEOEventEntry::new("186", "Assassinate", -10.0),
+    // 19: FIGHT
+    CAMEOEventEntry::new("19", "Fight", -10.0),
+    CAMEOEventEntry::new(
+        "190",
+        "Use conventional military force, not specified below",
+        -10.0,
+    ),
+    CAMEOEventEntry::new("191", "Impose blockade, restrict movement", -9.5),
+    CAMEOEventEntry::new("192", "Occupy territory", -9.5),
+    CAMEOEventEntry::new("193", "Fight with small arms and light weapons", -10.0),
+    CAMEOEventEntry::new("194", "Fight with artillery and tanks", -10.0),
+    CAMEOEventEntry::new("195", "Employ aerial weapons, not specified below", -10.0),
+    CAMEOEventEntry::new("1951", "Employ precision-guided aerial munitions", -10.0),
+    CAMEOEventEntry::new("1952", "Employ remotely piloted aerial munitions", -10.0),
+    CAMEOEventEntry::new("196", "Violate ceasefire", -9.5),
+    // 20: USE UNCONVENTIONAL MASS VIOLENCE
+    CAMEOEventEntry::new("20", "Use unconventional mass violence", -10.0),
+    CAMEOEventEntry::new(
+        "200",
+        "Use unconventional mass violence, not specified below",
+        -10.0,
+    ),
+    CAMEOEventEntry::new("201", "Engage in mass expulsion", -9.5),
+    CAMEOEventEntry::new("202", "Engage in mass killings", -10.0),
+    CAMEOEventEntry::new("203", "Engage in ethnic cleansing", -10.0),
+    CAMEOEventEntry::new(
+        "204",
+        "Use weapons of mass destruction, not specified below",
+        -10.0,
+    ),
+    CAMEOEventEntry::new(
+        "2041",
+        "Use chemical, biological, or radiological weapons",
+        -10.0,
+    ),
+    CAMEOEventEntry::new("2042", "Detonate nuclear weapons", -10.0),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use log::info;
 
+    #[allow(dead_code)]
     fn init_logger() {
         static INIT: std::sync::Once = std::sync::Once::new();
         INIT.call_once(|| {
@@ -1427,4 +2184,79 @@ mod tests {
         let short_result = CAMEOEventCode::try_from(short_code);
         assert!(short_result.is_ok());
     }
+
+    #[test]
+    fn test_every_code_round_trips() {
+        // init_logger();
+
+        for entry in CAMEO_EVENT_CODES.iter().filter(|entry| !entry.is_root()) {
+            let description = entry
+                .description()
+                .unwrap_or_else(|| panic!("{} does not parse", entry.code));
+            info!("{} -> {:?}", entry.code, description);
+            assert_eq!(description.code(), Some(entry.code), "{:?}", description);
+            assert_eq!(description.label(), Some(entry.label));
+        }
+    }
+
+    #[test]
+    fn test_hierarchy_navigation() {
+        // init_logger();
+
+        let entry = CAMEOEventEntry::lookup("1413").unwrap();
+        assert_eq!(entry.label, "Demonstrate for rights");
+        assert_eq!(entry.base().unwrap().code, "141");
+        assert_eq!(entry.root().unwrap().code, "14");
+        assert_eq!(entry.parent().unwrap().code, "141");
+        assert_eq!(CAMEOEventEntry::lookup("14").unwrap().children().count(), 6);
+
+        let description = entry.description().unwrap();
+        assert!(description.is_in_root("14"));
+        assert_eq!(
+            description.root(),
+            Some(EventActionDescription::Protest(Protest::Unspecified))
+        );
+        assert_eq!(
+            description.base(),
+            Some(EventActionDescription::Protest(
+                Protest::DemonstrateOrRally(Change::Unspecified)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_query_by_root() {
+        // init_logger();
+
+        let protest_codes: Vec<&str> = CAMEOEventEntry::in_root("14")
+            .map(|entry| entry.code)
+            .collect();
+        assert_eq!(protest_codes.len(), 27);
+        assert!(protest_codes.iter().all(|code| code.starts_with("14")));
+
+        let protests = EventActionDescription::all()
+            .filter(|(_, description)| description.is_in_root("14"))
+            .count();
+        assert_eq!(protests, 26);
+    }
+
+    #[test]
+    fn test_goldstein_defaults() {
+        // init_logger();
+
+        assert_eq!(
+            CAMEOEventEntry::lookup("0874").unwrap().goldstein_scale,
+            10.0
+        );
+        assert_eq!(
+            CAMEOEventEntry::lookup("193").unwrap().goldstein_scale,
+            -10.0
+        );
+        let description = CAMEOEventEntry::lookup("057")
+            .and_then(|entry| entry.description())
+            .unwrap();
+        assert_eq!(description.goldstein_scale(), Some(8.0));
+        assert_eq!(description.to_string(), "Sign formal agreement");
+        assert!(EventActionDescription::Unspecified.code().is_none());
+    }
 }
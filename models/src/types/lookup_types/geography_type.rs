@@ -23,11 +23,76 @@ impl TryFrom<Option<GeographyTypeCode>> for GeographyType {
         }
     }
 }
+
+/// GDELT location type code, keeping the US/world distinction `GeographyType` folds away
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LocationType {
+    Country = 1,
+    USState = 2,
+    USCity = 3,
+    WorldCity = 4,
+    WorldState = 5,
+}
+
+/// How precisely a location's coordinates pin it down, coarsest first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LocationPrecision {
+    /// Country centroid
+    Country,
+    /// State/ADM1 centroid
+    ADM1,
+    /// City or landmark
+    City,
+}
+
+impl LocationType {
+    pub fn code(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn is_us(&self) -> bool {
+        matches!(self, LocationType::USState | LocationType::USCity)
+    }
+
+    pub fn geography_type(&self) -> GeographyType {
+        match self {
+            LocationType::Country => GeographyType::Country,
+            LocationType::USState | LocationType::WorldState => GeographyType::State,
+            LocationType::USCity | LocationType::WorldCity => GeographyType::City,
+        }
+    }
+
+    pub fn precision(&self) -> LocationPrecision {
+        match self {
+            LocationType::Country => LocationPrecision::Country,
+            LocationType::USState | LocationType::WorldState => LocationPrecision::ADM1,
+            LocationType::USCity | LocationType::WorldCity => LocationPrecision::City,
+        }
+    }
+}
+
+impl TryFrom<Option<GeographyTypeCode>> for LocationType {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Option<GeographyTypeCode>) -> anyhow::Result<Self> {
+        let value = value.ok_or_else(|| anyhow!("GeographyTypeCode is None"))?;
+        match value.0 {
+            1 => Ok(Self::Country),
+            2 => Ok(Self::USState),
+            3 => Ok(Self::USCity),
+            4 => Ok(Self::WorldCity),
+            5 => Ok(Self::WorldState),
+            _ => Err(anyhow!("Invalid Geography Code")),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use log::info;
 
+    #[allow(dead_code)]
     fn init_logger() {
         static INIT: std::sync::Once = std::sync::Once::new();
         INIT.call_once(|| {
@@ -59,6 +124,24 @@ mod tests {
         assert_eq!(city.unwrap(), GeographyType::City);
     }
 
+    #[test]
+    fn test_location_type_keeps_us_distinction() {
+        // init_logger();
+
+        let us_city = LocationType::try_from(Some(GeographyTypeCode(3))).unwrap();
+        let world_city = LocationType::try_from(Some(GeographyTypeCode(4))).unwrap();
+        info!("Type 3 -> {:?}, type 4 -> {:?}", us_city, world_city);
+        assert!(us_city.is_us());
+        assert!(!world_city.is_us());
+        assert_eq!(us_city.geography_type(), world_city.geography_type());
+        assert_eq!(world_city.code(), 4);
+
+        let world_state = LocationType::try_from(Some(GeographyTypeCode(5))).unwrap();
+        assert_eq!(world_state.precision(), LocationPrecision::ADM1);
+        assert!(LocationPrecision::City > LocationPrecision::Country);
+        assert!(LocationType::try_from(Some(GeographyTypeCode(0))).is_err());
+    }
+
     #[test]
     fn test_geography_type_try_from_invalid_codes() {
         // init_logger();
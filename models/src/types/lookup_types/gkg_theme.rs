@@ -0,0 +1,343 @@
+//! GKG theme taxonomy
+//!
+//! Theme strings are embedded from GDELT's published theme list
+//! (`.reference_docs/LOOKUP-GKGTHEMES.TXT`, theme and document count per line).
+//! Family, hierarchical path and label are derived from the theme string itself, so they
+//! also work for themes GDELT adds after the list was taken.
+//!
+//! Paths split a theme into family, category and leaf:
+//! - `TAX_FNCACT_PRESIDENT` -> `["TAX", "FNCACT", "PRESIDENT"]`
+//! - `TAX_TERROR_GROUP_BOKO_HARAM` -> `["TAX", "TERROR_GROUP", "BOKO_HARAM"]`
+//! - `WB_2433_CONFLICT_AND_VIOLENCE` -> `["WB", "2433", "CONFLICT_AND_VIOLENCE"]`
+//! - `CRISISLEX_T03_DEAD` -> `["CRISISLEX", "T03", "DEAD"]`
+//! - `UNGP_CRIME_VIOLENCE` -> `["UNGP", "CRIME_VIOLENCE"]`
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::OnceLock;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ThemeFamily {
+    /// GDELT's own taxonomies (`TAX_FNCACT`, `TAX_ETHNICITY`, `TAX_DISEASE`, ...)
+    Taxonomy,
+    WorldBank,
+    CrisisLex,
+    UNGlobalPulse,
+    EconomicPolicyUncertainty,
+    Uspec,
+    Slfid,
+    Soc,
+    /// Unprefixed GDELT themes (`ECON_INFLATION`, `NATURAL_DISASTER_FLOODS`, ...)
+    Gdelt,
+}
+
+/// TAX categories that span two tokens
+const TAX_COMPOUND_CATEGORIES: &[&str] = &["TERROR_GROUP", "POLITICAL_PARTY", "MILITARY_TITLE"];
+
+impl ThemeFamily {
+    pub const ALL: &'static [ThemeFamily] = &[
+        ThemeFamily::Taxonomy,
+        ThemeFamily::WorldBank,
+        ThemeFamily::CrisisLex,
+        ThemeFamily::UNGlobalPulse,
+        ThemeFamily::EconomicPolicyUncertainty,
+        ThemeFamily::Uspec,
+        ThemeFamily::Slfid,
+        ThemeFamily::Soc,
+        ThemeFamily::Gdelt,
+    ];
+
+    /// Theme prefix, without the trailing underscore. Empty for `Gdelt`.
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            ThemeFamily::Taxonomy => "TAX",
+            ThemeFamily::WorldBank => "WB",
+            ThemeFamily::CrisisLex => "CRISISLEX",
+            ThemeFamily::UNGlobalPulse => "UNGP",
+            ThemeFamily::EconomicPolicyUncertainty => "EPU",
+            ThemeFamily::Uspec => "USPEC",
+            ThemeFamily::Slfid => "SLFID",
+            ThemeFamily::Soc => "SOC",
+            ThemeFamily::Gdelt => "",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ThemeFamily::Taxonomy => "GDELT Taxonomies",
+            ThemeFamily::WorldBank => "World Bank Topical Taxonomy",
+            ThemeFamily::CrisisLex => "CrisisLex",
+            ThemeFamily::UNGlobalPulse => "UN Global Pulse",
+            ThemeFamily::EconomicPolicyUncertainty => "Economic Policy Uncertainty",
+            ThemeFamily::Uspec => "USPEC",
+            ThemeFamily::Slfid => "SLFID",
+            ThemeFamily::Soc => "SOC",
+            ThemeFamily::Gdelt => "GDELT Themes",
+        }
+    }
+
+    /// Family a theme string belongs to
+    pub fn of(theme: &str) -> Self {
+        let prefix = theme.split('_').next().unwrap_or_default();
+        ThemeFamily::ALL
+            .iter()
+            .find(|family| **family != ThemeFamily::Gdelt && family.prefix() == prefix)
+            .copied()
+            .unwrap_or(ThemeFamily::Gdelt)
+    }
+}
+
+impl fmt::Display for ThemeFamily {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Split `rest` after its first `_` if the head satisfies `is_category`
+fn split_category(rest: &str, is_category: impl Fn(&str) -> bool) -> Vec<&str> {
+    match rest.split_once('_') {
+        Some((category, leaf)) if is_category(category) => vec![category, leaf],
+        _ => vec![rest],
+    }
+}
+
+/// Family, category and leaf of a theme string
+pub fn theme_path(theme: &str) -> Vec<&str> {
+    let family = ThemeFamily::of(theme);
+    let Some(rest) = theme
+        .strip_prefix(family.prefix())
+        .and_then(|rest| rest.strip_prefix('_'))
+    else {
+        return vec![theme];
+    };
+
+    let mut path = vec![family.prefix()];
+    match family {
+        ThemeFamily::Taxonomy => {
+            let compound = TAX_COMPOUND_CATEGORIES
+                .iter()
+                .find(|category| {
+                    rest.strip_prefix(**category)
+                        .is_some_and(|tail| tail.is_empty() || tail.starts_with('_'))
+                })
+                .map(|category| category.len());
+            match compound {
+                Some(len) if rest.len() > len => path.extend([&rest[..len], &rest[len + 1..]]),
+                Some(_) => path.push(rest),
+                _ => path.extend(split_category(rest, |_| true)),
+            }
+        }
+        ThemeFamily::WorldBank => path.extend(split_category(rest, |category| {
+            category.chars().all(|c| c.is_ascii_digit())
+        })),
+        ThemeFamily::CrisisLex => path.extend(split_category(rest, |category| {
+            category.len() == 3 && category[1..].chars().all(|c| c.is_ascii_digit())
+        })),
+        _ => path.push(rest),
+    }
+    path
+}
+
+/// Human label for the leaf of a theme: `CONFLICT_AND_VIOLENCE` -> "Conflict and Violence"
+pub fn theme_label(theme: &str) -> String {
+    let path = theme_path(theme);
+    let leaf = path.last().copied().unwrap_or(theme);
+    leaf.split('_')
+        .filter(|word| !word.is_empty())
+        .enumerate()
+        .map(|(i, word)| {
+            let lower = word.to_lowercase();
+            if i > 0 && matches!(lower.as_str(), "and" | "of" | "the" | "in" | "for" | "to") {
+                lower
+            } else {
+                let mut chars = lower.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars).collect(),
+                    None => String::new(),
+                }
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// One theme from GDELT's published theme list
+#[derive(Debug, Clone, PartialEq)]
+pub struct GKGThemeEntry {
+    pub theme: &'static str,
+    /// Number of documents GDELT reported for the theme when the list was published
+    pub document_count: u64,
+}
+
+static THEME_MAP: OnceLock<HashMap<&'static str, GKGThemeEntry>> = OnceLock::new();
+
+fn theme_map() -> &'static HashMap<&'static str, GKGThemeEntry> {
+    THEME_MAP.get_or_init(|| {
+        let content: &'static str =
+            include_str!("../../../../.reference_docs/LOOKUP-GKGTHEMES.TXT");
+        content
+            .lines()
+            .filter_map(|line| {
+                let (theme, count) = line.trim_end_matches('\r').split_once('\t')?;
+                let theme = theme.trim();
+                if theme.is_empty() {
+                    return None;
+                }
+                Some((
+                    theme,
+                    GKGThemeEntry {
+                        theme,
+                        document_count: count.trim().parse().unwrap_or(0),
+                    },
+                ))
+            })
+            .collect()
+    })
+}
+
+impl GKGThemeEntry {
+    pub fn lookup(theme: &str) -> Option<&'static GKGThemeEntry> {
+        theme_map().get(theme.trim())
+    }
+
+    pub fn all() -> impl Iterator<Item = &'static GKGThemeEntry> {
+        theme_map().values()
+    }
+
+    /// Every known theme in `family`, most common first
+    pub fn in_family(family: ThemeFamily) -> Vec<&'static GKGThemeEntry> {
+        let mut entries: Vec<_> = Self::all()
+            .filter(|entry| entry.family() == family)
+            .collect();
+        entries.sort_by_key(|entry| (std::cmp::Reverse(entry.document_count), entry.theme));
+        entries
+    }
+
+    /// Known themes in `family` whose label contains `keyword`, e.g. all World Bank
+    /// conflict themes with `search(ThemeFamily::WorldBank, "conflict")`
+    pub fn search(family: ThemeFamily, keyword: &str) -> Vec<&'static GKGThemeEntry> {
+        let keyword = keyword.to_lowercase();
+        Self::in_family(family)
+            .into_iter()
+            .filter(|entry| entry.label().to_lowercase().contains(&keyword))
+            .collect()
+    }
+
+    pub fn family(&self) -> ThemeFamily {
+        ThemeFamily::of(self.theme)
+    }
+
+    pub fn path(&self) -> Vec<&'static str> {
+        theme_path(self.theme)
+    }
+
+    pub fn label(&self) -> String {
+        theme_label(self.theme)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use log::info;
+
+    #[allow(dead_code)]
+    fn init_logger() {
+        static INIT: std::sync::Once = std::sync::Once::new();
+        INIT.call_once(|| {
+            env_logger::init();
+        });
+    }
+
+    #[test]
+    fn test_theme_families() {
+        // init_logger();
+
+        assert_eq!(
+            ThemeFamily::of("TAX_FNCACT_PRESIDENT"),
+            ThemeFamily::Taxonomy
+        );
+        assert_eq!(
+            ThemeFamily::of("WB_2433_CONFLICT_AND_VIOLENCE"),
+            ThemeFamily::WorldBank
+        );
+        assert_eq!(
+            ThemeFamily::of("CRISISLEX_T03_DEAD"),
+            ThemeFamily::CrisisLex
+        );
+        assert_eq!(
+            ThemeFamily::of("EPU_POLICY_GOVERNMENT"),
+            ThemeFamily::EconomicPolicyUncertainty
+        );
+        assert_eq!(ThemeFamily::of("ECON_INFLATION"), ThemeFamily::Gdelt);
+        assert_eq!(ThemeFamily::of("TAXATION"), ThemeFamily::Gdelt);
+    }
+
+    #[test]
+    fn test_theme_paths_and_labels() {
+        // init_logger();
+
+        assert_eq!(
+            theme_path("TAX_FNCACT_PRESIDENT"),
+            vec!["TAX", "FNCACT", "PRESIDENT"]
+        );
+        assert_eq!(
+            theme_path("TAX_TERROR_GROUP_BOKO_HARAM"),
+            vec!["TAX", "TERROR_GROUP", "BOKO_HARAM"]
+        );
+        assert_eq!(
+            theme_path("WB_2433_CONFLICT_AND_VIOLENCE"),
+            vec!["WB", "2433", "CONFLICT_AND_VIOLENCE"]
+        );
+        assert_eq!(
+            theme_path("CRISISLEX_T03_DEAD"),
+            vec!["CRISISLEX", "T03", "DEAD"]
+        );
+        assert_eq!(
+            theme_path("CRISISLEX_CRISISLEXREC"),
+            vec!["CRISISLEX", "CRISISLEXREC"]
+        );
+        assert_eq!(
+            theme_path("UNGP_CRIME_VIOLENCE"),
+            vec!["UNGP", "CRIME_VIOLENCE"]
+        );
+        assert_eq!(theme_path("ECON_INFLATION"), vec!["ECON_INFLATION"]);
+
+        assert_eq!(
+            theme_label("WB_2433_CONFLICT_AND_VIOLENCE"),
+            "Conflict and Violence"
+        );
+        assert_eq!(theme_label("TAX_FNCACT_PRESIDENT"), "President");
+        assert_eq!(theme_label("ECON_INFLATION"), "Econ Inflation");
+    }
+
+    #[test]
+    fn test_embedded_theme_lookup() {
+        // init_logger();
+
+        let entry = GKGThemeEntry::lookup("TAX_FNCACT").unwrap();
+        info!("TAX_FNCACT: {:?}", entry);
+        assert!(entry.document_count > 0);
+        assert!(GKGThemeEntry::lookup("NOT_A_THEME").is_none());
+        assert!(GKGThemeEntry::all().count() > 59000);
+
+        let conflict = GKGThemeEntry::search(ThemeFamily::WorldBank, "conflict");
+        info!("{} World Bank conflict themes", conflict.len());
+        assert!(
+            conflict
+                .iter()
+                .any(|entry| entry.theme == "WB_2433_CONFLICT_AND_VIOLENCE")
+        );
+        assert!(
+            conflict
+                .iter()
+                .all(|entry| entry.family() == ThemeFamily::WorldBank)
+        );
+        // Most common first
+        assert!(
+            conflict
+                .windows(2)
+                .all(|pair| pair[0].document_count >= pair[1].document_count)
+        );
+    }
+}
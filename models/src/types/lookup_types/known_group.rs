@@ -253,6 +253,7 @@ mod tests {
     use super::*;
     use log::info;
 
+    #[allow(dead_code)]
     fn init_logger() {
         static INIT: std::sync::Once = std::sync::Once::new();
         INIT.call_once(|| {
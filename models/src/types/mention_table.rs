@@ -1,7 +1,12 @@
 use chrono::{DateTime, TimeZone, Utc};
 use std::fmt;
 
-use super::{DatabaseTable, event_table::GlobalEventID, lookup_types::mention_type::MentionType};
+use super::{
+    DatabaseTable,
+    event_table::GlobalEventID,
+    extras_xml::{ExtrasBlock, ExtrasXml},
+    lookup_types::mention_type::MentionType,
+};
 
 #[derive(Debug)]
 pub struct MentionTypeCode(pub u8);
@@ -185,6 +190,55 @@ impl TryFrom<Option<&str>> for Engine {
     }
 }
 
+/// The mentions `Extras` column: raw text plus its parsed XML blocks
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MentionExtras {
+    pub raw: String,
+    pub xml: ExtrasXml,
+}
+
+impl MentionExtras {
+    pub fn parse(raw: &str) -> Self {
+        MentionExtras {
+            raw: raw.to_string(),
+            xml: ExtrasXml::parse(raw),
+        }
+    }
+
+    pub fn raw(&self) -> &str {
+        &self.raw
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.raw.trim().is_empty()
+    }
+
+    /// Text of the first block with this tag
+    pub fn get(&self, tag: &str) -> Option<&str> {
+        self.xml.get(tag)
+    }
+
+    /// First block with this tag, parsed as `T`
+    pub fn get_parsed<T: std::str::FromStr>(&self, tag: &str) -> Option<T> {
+        self.xml.get_parsed(tag)
+    }
+
+    pub fn blocks(&self) -> &[ExtrasBlock] {
+        self.xml.blocks()
+    }
+}
+
+impl TryFrom<Option<&str>> for MentionExtras {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Option<&str>) -> Result<Self, Self::Error> {
+        match value {
+            Some(s) => Ok(MentionExtras::parse(s)),
+            None => Err(anyhow::anyhow!("missing Extras")),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct MentionTable {
     pub global_event_id: GlobalEventID,
@@ -201,7 +255,7 @@ pub struct MentionTable {
     pub mention_doc_len: MentionDocLength,
     pub mention_doc_tone: MentionDocTone,
     pub mention_doc_translation_info: (Option<SourceLanguageCode>, Option<Engine>),
-    pub extras: String,
+    pub extras: MentionExtras,
 }
 
 impl fmt::Display for MentionTable {
@@ -240,7 +294,7 @@ impl fmt::Display for MentionTable {
         }
 
         if !self.extras.is_empty() {
-            writeln!(f, "  Extras: {}", self.extras)?;
+            writeln!(f, "  Extras: {}", self.extras.raw)?;
         }
 
         Ok(())
@@ -322,7 +376,7 @@ impl TryFrom<csv::StringRecord> for MentionTable {
             mention_doc_len: MentionDocLength::try_from(Some(fields[12]))?,
             mention_doc_tone: MentionDocTone::try_from(Some(fields[13]))?,
             mention_doc_translation_info: parse_translation_info(fields[14]),
-            extras: fields[15].to_string(),
+            extras: MentionExtras::try_from(Some(fields[15]))?,
         })
     }
 }
@@ -433,7 +487,19 @@ mod tests {
             "Should parse successfully with proper translation info format"
         );
         let mention = mention_table_result.unwrap();
-        assert_eq!(mention.extras, "Extras");
+        assert_eq!(mention.extras.raw(), "Extras");
+    }
+
+    #[test]
+    fn test_mention_table_extras_xml() {
+        init_logger();
+        let input = "1233696063\t20250322164500\t20250322180000\t1\texample.com\thttps://example.com/article.html\t1\t-1\t1562\t1620\t0\t20\t3569\t-7.27\tsrclc:eng; eng:TestEngine\t<PAGE_TITLE>Talks &amp; truce</PAGE_TITLE><NEW_TAG>3</NEW_TAG>";
+        let mention = MentionTable::try_from(make_record(input)).unwrap();
+        println!("Mention extras: {:?}", mention.extras);
+        assert_eq!(mention.extras.get("PAGE_TITLE"), Some("Talks & truce"));
+        assert_eq!(mention.extras.get_parsed::<u8>("NEW_TAG"), Some(3));
+        assert_eq!(mention.extras.blocks().len(), 2);
+        assert!(mention.extras.raw().starts_with("<PAGE_TITLE>Talks &amp;"));
     }
 
     #[test]
@@ -531,7 +597,7 @@ mod tests {
         assert_eq!(mention1.global_event_id.0, 1000000001);
         assert_eq!(mention1.mention_source_name.0, "example.com");
         assert_eq!(mention1.confidence.0, 5);
-        assert_eq!(mention1.extras, "Extras1");
+        assert_eq!(mention1.extras.raw(), "Extras1");
 
         let input2 = "1000000002\t20250322164600\t20250322180100\t2\ttest.org\thttps://test.org/article2\t2\t-1\t-1\t-1\t0\t200\t500\t25\tsrclc:spa; eng:AnotherEngine\tExtras2";
         let record2 = make_record(input2);
@@ -543,7 +609,7 @@ mod tests {
         let mention2 = result2.unwrap();
         assert_eq!(mention2.global_event_id.0, 1000000002);
         assert_eq!(mention2.confidence.0, 200);
-        assert_eq!(mention2.extras, "Extras2");
+        assert_eq!(mention2.extras.raw(), "Extras2");
     }
 
     #[test]
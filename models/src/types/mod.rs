@@ -1,4 +1,5 @@
 pub mod event_table;
+pub mod extras_xml;
 pub mod gkg_table;
 pub mod lookup_types;
 pub mod mention_table;
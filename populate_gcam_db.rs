@@ -1,10 +1,17 @@
-//! GCAM Database Verification Utility
+//! GCAM Database Population and Verification Utility
 //!
-//! This utility verifies the in-memory GCAM database that is embedded at compile time.
-//! The database is no longer populated from external files but is built into the binary.
+//! With a codebook path, writes that codebook to the database path (default
+//! `./gcam_db`), where `GCAMCodebookDatabase::new` and `verify_gcam_enrichment` load it:
+//!
+//! ```text
+//! cargo run --bin populate_gcam_db -- GCAM-MASTER-CODEBOOK.csv ./gcam_db
+//! ```
+//!
+//! Without arguments it verifies the codebook embedded at compile time.
 
 use anyhow::Result;
-use models::gcam::GCAMCodebookDatabase;
+use models::gcam::{GCAMCodebookDatabase, populate_gcam_database};
+use std::env;
 
 fn main() -> Result<()> {
     // Initialize logger for debug output
@@ -12,6 +19,22 @@ fn main() -> Result<()> {
         .filter_level(log::LevelFilter::Info)
         .init();
 
+    let args: Vec<String> = env::args().collect();
+    if let Some(csv_path) = args.get(1) {
+        let db_path = args.get(2).map_or("./gcam_db", String::as_str);
+        println!("=== GCAM Database Population ===\n");
+        let count = populate_gcam_database(csv_path, db_path)?;
+        println!(
+            "✅ Wrote {} entries from {} to {}",
+            count, csv_path, db_path
+        );
+        println!(
+            "   Check it with: cargo run --bin verify_gcam_enrichment -- {}",
+            db_path
+        );
+        return Ok(());
+    }
+
     println!("=== GCAM In-Memory Database Verification Utility ===\n");
     println!("Note: The GCAM database is now embedded at compile time.");
     println!(
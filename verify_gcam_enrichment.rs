@@ -102,7 +102,10 @@ fn main() -> Result<()> {
             eprintln!("1. Database doesn't exist at '{}'", db_path);
             eprintln!("2. Database is corrupted");
             eprintln!("3. Permission issues");
-            eprintln!("\nTry running: cargo run --bin populate_gcam_db");
+            eprintln!(
+                "\nTry running: cargo run --bin populate_gcam_db -- GCAM-MASTER-CODEBOOK.csv {}",
+                db_path
+            );
         }
     }
 